@@ -5,8 +5,9 @@ use std::error::Error;
 use emscripten_main_loop::MainLoopEvent;
 use emscripten_main_loop::MainLoopEvent::{Continue, Terminate};
 use log::debug;
-use sdl2::event::Event;
+use sdl2::event::{Event, WindowEvent};
 use sdl2::keyboard::Keycode;
+use sdl2::mouse::MouseWheelDirection;
 use sdl2::{init, EventPump, VideoSubsystem};
 
 use crate::engines::sdl::SDLWindow;
@@ -50,10 +51,13 @@ impl SDLEngine {
         &mut self,
         window_specs: WindowBuilder,
     ) -> Result<(), Box<dyn Error>> {
-        let sdl_window = self
-            .sdl_video
-            .window("Rusty-UI", window_specs.width(), window_specs.height())
-            .build()?;
+        let mut sdl_window_builder =
+            self.sdl_video
+                .window("Rusty-UI", window_specs.width(), window_specs.height());
+        if window_specs.is_resizable() {
+            sdl_window_builder.resizable();
+        }
+        let sdl_window = sdl_window_builder.build()?;
         let id = sdl_window.id();
         debug!("Created window {}", id);
         self.windows
@@ -79,20 +83,36 @@ impl SDLEngine {
                 }
             }
             match event {
-                Event::Quit { .. }
-                | Event::KeyDown {
+                Event::Quit { .. } => return MainLoopStatus::Terminate,
+                // Escape closes dialogs before it closes the application
+                Event::KeyDown {
+                    window_id,
                     keycode: Some(Keycode::Escape),
                     ..
-                } => return MainLoopStatus::Terminate,
+                } if !self
+                    .windows
+                    .get(&window_id)
+                    .is_some_and(|window| window.get_builder().has_modal()) =>
+                {
+                    return MainLoopStatus::Terminate
+                }
                 Event::KeyDown {
                     window_id,
                     keycode: Some(key),
+                    keymod,
                     ..
                 } => self
                     .windows
                     .get_mut(&window_id)
                     .expect("")
-                    .event_key_down(key),
+                    .event_key_down(key, keymod),
+                Event::TextInput {
+                    window_id, text, ..
+                } => {
+                    if let Some(window) = self.windows.get_mut(&window_id) {
+                        window.event_text_input(&text)
+                    }
+                }
                 Event::MouseButtonDown {
                     window_id,
                     mouse_btn,
@@ -104,6 +124,51 @@ impl SDLEngine {
                     .get_mut(&window_id)
                     .expect("")
                     .event_mouse_button_down(mouse_btn, x, y),
+                Event::MouseButtonUp {
+                    window_id,
+                    mouse_btn,
+                    x,
+                    y,
+                    ..
+                } => self
+                    .windows
+                    .get_mut(&window_id)
+                    .expect("")
+                    .event_mouse_button_up(mouse_btn, x, y),
+                Event::MouseMotion {
+                    window_id, x, y, ..
+                } => {
+                    if let Some(window) = self.windows.get_mut(&window_id) {
+                        window.event_mouse_motion(x, y)
+                    }
+                }
+                Event::MouseWheel {
+                    window_id,
+                    x,
+                    y,
+                    direction,
+                    mouse_x,
+                    mouse_y,
+                    ..
+                } => {
+                    let flip = if direction == MouseWheelDirection::Flipped {
+                        -1
+                    } else {
+                        1
+                    };
+                    if let Some(window) = self.windows.get_mut(&window_id) {
+                        window.event_mouse_wheel(mouse_x, mouse_y, x * flip, y * flip)
+                    }
+                }
+                Event::Window {
+                    window_id,
+                    win_event: WindowEvent::SizeChanged(width, height),
+                    ..
+                } => {
+                    if let Some(window) = self.windows.get_mut(&window_id) {
+                        window.event_resized(width.max(0) as u32, height.max(0) as u32)
+                    }
+                }
                 _ => {}
             }
         }
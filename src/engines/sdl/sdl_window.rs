@@ -1,9 +1,9 @@
 use std::error::Error;
 
-use sdl2::keyboard::Keycode;
+use sdl2::keyboard::{Keycode, Mod};
 use sdl2::mouse::MouseButton;
 use sdl2::pixels::Color;
-use sdl2::render::Canvas;
+use sdl2::render::{BlendMode, Canvas};
 
 use crate::window::{Window, WindowBuilder};
 
@@ -17,7 +17,9 @@ impl SDLWindow {
         window_builder: WindowBuilder,
         sdl_window: sdl2::video::Window,
     ) -> Result<SDLWindow, Box<dyn Error>> {
-        let canvas = sdl_window.into_canvas().build()?;
+        let mut canvas = sdl_window.into_canvas().build()?;
+        // So shapes with alpha, like the dim under dialogs, blend with what is below
+        canvas.set_blend_mode(BlendMode::Blend);
         Ok(SDLWindow {
             window_builder,
             canvas,
@@ -29,12 +31,27 @@ impl Window for SDLWindow {
     fn get_builder(&self) -> &WindowBuilder {
         &self.window_builder
     }
-    fn event_key_down(&mut self, key: Keycode) {
-        self.window_builder.event_key_down(key)
+    fn event_key_down(&mut self, key: Keycode, keymod: Mod) {
+        self.window_builder.event_key_down(key, keymod)
+    }
+    fn event_text_input(&mut self, text: &str) {
+        self.window_builder.event_text_input(text)
     }
     fn event_mouse_button_down(&mut self, mouse_btn: MouseButton, x: i32, y: i32) {
         self.window_builder.event_mouse_button_down(mouse_btn, x, y)
     }
+    fn event_mouse_button_up(&mut self, mouse_btn: MouseButton, x: i32, y: i32) {
+        self.window_builder.event_mouse_button_up(mouse_btn, x, y)
+    }
+    fn event_mouse_motion(&mut self, x: i32, y: i32) {
+        self.window_builder.event_mouse_motion(x, y)
+    }
+    fn event_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) {
+        self.window_builder.event_mouse_wheel(x, y, dx, dy)
+    }
+    fn event_resized(&mut self, width: u32, height: u32) {
+        self.window_builder.resize(width, height)
+    }
     fn build_geometry(&mut self) -> Result<(), Box<dyn Error>> {
         self.window_builder.build_geometry()
     }
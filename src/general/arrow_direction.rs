@@ -0,0 +1,8 @@
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ArrowDirection {
+    Up,
+    #[default]
+    Down,
+    Left,
+    Right,
+}
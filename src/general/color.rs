@@ -1,4 +1,4 @@
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Color {
     r: u8,
     g: u8,
@@ -26,6 +26,64 @@ impl Color {
     pub fn a(&self) -> u8 {
         self.a
     }
+    /// Hue in degrees from 0 to 360, saturation and value from 0 to 1.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32, a: u8) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let (saturation, value) = (saturation.clamp(0.0, 1.0), value.clamp(0.0, 1.0));
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = value - chroma;
+        let to_u8 = |channel: f32| ((channel + m) * 255.0).round() as u8;
+        Self::new(to_u8(r), to_u8(g), to_u8(b), a)
+    }
+    /// (hue, saturation, value) as from_hsv() takes them, the hue is 0 for greys.
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (r, g, b) = (
+            self.r as f32 / 255.0,
+            self.g as f32 / 255.0,
+            self.b as f32 / 255.0,
+        );
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+        (hue, saturation, max)
+    }
+    /// "#RRGGBB", or "#RRGGBBAA" when it isn't opaque.
+    pub fn to_hex(&self) -> String {
+        if self.a == 255 {
+            format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+        } else {
+            format!("#{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
+        }
+    }
+    /// Takes what to_hex() gives, the # is optional.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !(hex.len() == 6 || hex.len() == 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+        let a = if hex.len() == 8 { channel(6)? } else { 255 };
+        Some(Self::new(channel(0)?, channel(2)?, channel(4)?, a))
+    }
 }
 
 impl From<&(u8, u8, u8, u8)> for Color {
@@ -33,3 +91,94 @@ impl From<&(u8, u8, u8, u8)> for Color {
         Color::new(v.0, v.1, v.2, v.3)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(color: &Color) -> Color {
+        let (hue, saturation, value) = color.to_hsv();
+        Color::from_hsv(hue, saturation, value, color.a())
+    }
+
+    #[test]
+    fn hue_wraps_around() {
+        let red = Color::new_opaque(255, 0, 0);
+        assert_eq!(Color::from_hsv(0.0, 1.0, 1.0, 255), red);
+        assert_eq!(Color::from_hsv(360.0, 1.0, 1.0, 255), red);
+        assert_eq!(Color::from_hsv(720.0, 1.0, 1.0, 255), red);
+        assert_eq!(
+            Color::from_hsv(-120.0, 1.0, 1.0, 255),
+            Color::new_opaque(0, 0, 255)
+        );
+        assert_eq!(red.to_hsv(), (0.0, 1.0, 1.0));
+        // Just below red, the hue is close to 360 and not negative
+        let (hue, _, _) = Color::new_opaque(255, 0, 1).to_hsv();
+        assert!(hue > 359.0 && hue < 360.0);
+    }
+
+    #[test]
+    fn hsv_round_trips() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = Color::new(r, g, b, 128);
+                    assert_eq!(round_trip(&color), color);
+                }
+            }
+        }
+        for color in [
+            Color::new_opaque(255, 0, 1),
+            Color::new_opaque(255, 1, 0),
+            Color::new_opaque(128, 128, 128),
+            Color::new_opaque(0, 0, 0),
+        ] {
+            assert_eq!(round_trip(&color), color);
+        }
+    }
+
+    #[test]
+    fn greys_have_no_hue() {
+        assert_eq!(Color::new_opaque(0, 0, 0).to_hsv(), (0.0, 0.0, 0.0));
+        let (hue, saturation, _) = Color::new_opaque(200, 200, 200).to_hsv();
+        assert_eq!((hue, saturation), (0.0, 0.0));
+    }
+
+    #[test]
+    fn parses_hex_with_and_without_alpha() {
+        assert_eq!(
+            Color::from_hex("#FF8000"),
+            Some(Color::new_opaque(255, 128, 0))
+        );
+        assert_eq!(
+            Color::from_hex("ff800080"),
+            Some(Color::new(255, 128, 0, 128))
+        );
+        assert_eq!(
+            Color::from_hex("  #0a0B0c "),
+            Some(Color::new_opaque(10, 11, 12))
+        );
+        let color = Color::new(1, 2, 3, 4);
+        assert_eq!(color.to_hex(), "#01020304");
+        assert_eq!(Color::from_hex(&color.to_hex()), Some(color));
+        assert_eq!(Color::new_opaque(1, 2, 3).to_hex(), "#010203");
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        for hex in [
+            "",
+            "#",
+            "#12345",
+            "#1234567",
+            "#123456789",
+            "#GG0000",
+            "#+1+2+3",
+            "##123456",
+            "12 345",
+            "ÿÿÿ",
+        ] {
+            assert_eq!(Color::from_hex(hex), None, "{hex}");
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt::{Display, Formatter};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+    /// 0 for Monday.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+    /// Wraps around, Sunday plus one is Monday.
+    pub fn after(self, days: usize) -> Weekday {
+        Self::ALL[(self.index() + days) % 7]
+    }
+    pub fn name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+}
+
+/// A day in the proleptic Gregorian calendar, without time or timezone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    // Field order matters, it's what Ord compares
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// None if the day doesn't exist, e.g. February 30.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Date> {
+        if !(1..=12).contains(&month) || day < 1 || day > Self::days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+    /// In UTC, from the system clock.
+    pub fn today() -> Date {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        Self::from_days((seconds / 86_400) as i64)
+    }
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+    /// 1 for January, None for anything that isn't a month.
+    pub fn month_name(month: u32) -> Option<&'static str> {
+        MONTH_NAMES.get((month as usize).checked_sub(1)?).copied()
+    }
+    /// The name of the date's own month, it's always a valid one.
+    pub fn name_of_month(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+    pub fn first_of_month(&self) -> Date {
+        Date { day: 1, ..*self }
+    }
+    pub fn last_of_month(&self) -> Date {
+        Date {
+            day: Self::days_in_month(self.year, self.month),
+            ..*self
+        }
+    }
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::Thursday.after(self.to_days().rem_euclid(7) as usize)
+    }
+    pub fn add_days(&self, days: i64) -> Date {
+        Self::from_days(self.to_days() + days)
+    }
+    /// Keeps the day when it can, otherwise it goes to the last day of the month.
+    pub fn add_months(&self, months: i32) -> Date {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u32 + 1);
+        Date {
+            year,
+            month,
+            day: self.day.min(Self::days_in_month(year, month)),
+        }
+    }
+    /// Spelled out for people, e.g. "Friday, March 15, 2024".
+    pub fn describe(&self) -> String {
+        format!(
+            "{}, {} {}, {}",
+            self.weekday().name(),
+            self.name_of_month(),
+            self.day,
+            self.year
+        )
+    }
+    /// Days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    pub fn to_days(&self) -> i64 {
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+    pub fn from_days(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + (month <= 2) as i64) as i32;
+        Date { year, month, day }
+    }
+}
+
+/// ISO 8601, e.g. "2024-03-15".
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(year, month, day).expect("date:tests:date invalid date")
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(!Date::is_leap_year(1900));
+        assert!(Date::is_leap_year(2000));
+        assert!(Date::is_leap_year(2024));
+        assert!(!Date::is_leap_year(2023));
+        assert!(Date::is_leap_year(0));
+        assert!(Date::is_leap_year(-4));
+        assert_eq!(Date::days_in_month(1900, 2), 28);
+        assert_eq!(Date::days_in_month(2000, 2), 29);
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2024, 13, 1).is_none());
+    }
+
+    #[test]
+    fn days_since_the_epoch() {
+        assert_eq!(date(1970, 1, 1).to_days(), 0);
+        assert_eq!(date(1969, 12, 31).to_days(), -1);
+        assert_eq!(date(2000, 3, 1).to_days(), 11_017);
+        assert_eq!(date(2024, 2, 29).to_days(), 19_782);
+        assert_eq!(date(1, 1, 1).to_days(), -719_162);
+        assert_eq!(date(0, 1, 1).to_days(), -719_528);
+        assert_eq!(date(-1, 1, 1).to_days(), -719_893);
+    }
+
+    #[test]
+    fn round_trips_through_days() {
+        // Every 7th day from before year 0 to well after the epoch
+        for days in (-800_000..200_000).step_by(7).chain(-2..2) {
+            let from_days = Date::from_days(days);
+            let (year, month, day) = (from_days.year(), from_days.month(), from_days.day());
+            assert_eq!(Date::new(year, month, day), Some(from_days));
+            assert_eq!(from_days.to_days(), days);
+        }
+        assert_eq!(Date::from_days(-1), date(1969, 12, 31));
+        assert_eq!(date(-1, 12, 31).add_days(1), date(0, 1, 1));
+    }
+
+    #[test]
+    fn add_months_clamps_the_day() {
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 3, 31).add_months(-1), date(2024, 2, 29));
+        assert_eq!(date(2023, 12, 15).add_months(1), date(2024, 1, 15));
+        assert_eq!(date(2024, 1, 15).add_months(-13), date(2022, 12, 15));
+        assert_eq!(date(0, 1, 1).add_months(-1), date(-1, 12, 1));
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(1969, 12, 31).weekday(), Weekday::Wednesday);
+        assert_eq!(date(2000, 1, 1).weekday(), Weekday::Saturday);
+        assert_eq!(date(2024, 3, 15).weekday(), Weekday::Friday);
+        assert_eq!(Weekday::Sunday.after(1), Weekday::Monday);
+    }
+}
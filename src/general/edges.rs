@@ -0,0 +1,46 @@
+/// Sizes for each side of a widget, e.g. its padding.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Edges {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Edges {
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+    pub fn all(size: f32) -> Self {
+        Self::new(size, size, size, size)
+    }
+    pub fn symmetric(vertical: f32, horizontal: f32) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+    /// Left plus right.
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+    /// Top plus bottom.
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
+impl std::ops::Add for Edges {
+    type Output = Edges;
+
+    fn add(self, other: Edges) -> Edges {
+        Edges::new(
+            self.top + other.top,
+            self.right + other.right,
+            self.bottom + other.bottom,
+            self.left + other.left,
+        )
+    }
+}
@@ -4,7 +4,7 @@ use std::sync::{Arc, Mutex};
 use sdl2::render::{TextureCreator, WindowCanvas};
 use sdl2::video::WindowContext;
 
-use crate::general::{Polygon, TexturedPolygon, Vector2D};
+use crate::general::{Polygon, Rect, TexturedPolygon, Vector2D};
 use crate::texture::SoftTexture;
 use crate::texture::TextureManager;
 use crate::themes::PrimitivesManagerForThemes;
@@ -33,6 +33,7 @@ impl Geometry {
             polygons: vec![TexturedPolygon {
                 poly,
                 tex: Some(tex),
+                clip: None,
             }],
         }
     }
@@ -46,11 +47,20 @@ impl Geometry {
     pub fn new_from_prim_man(class: &str, prim_man: &mut PrimitivesManagerForThemes) -> Geometry {
         let mut geometries = Vec::with_capacity(prim_man.len());
         for (_, primitive) in prim_man.iter_mut() {
-            if primitive.needs_update() {
-                primitive.update_geometry();
-            }
-            geometries.push(primitive.clone_geometry());
+            // Themes can place primitives inside the widget, so their translated geometry is used
+            geometries.push(primitive.build_geometry());
+        }
+        let mut geometry = Geometry::new_from_geometries(class, geometries);
+        if let Some(size) = prim_man.clip_size() {
+            geometry.clip(&Rect::new(0.0, 0.0, size.x(), size.y()));
         }
+        geometry.translate(&prim_man.content_offset());
+        // The frame is drawn behind the content
+        let mut geometries: Vec<Geometry> = prim_man
+            .frame_iter_mut()
+            .map(|primitive| primitive.build_geometry())
+            .collect();
+        geometries.push(geometry);
         Geometry::new_from_geometries(class, geometries)
     }
     pub fn render(
@@ -59,8 +69,16 @@ impl Geometry {
         tex_creator: &TextureCreator<WindowContext>,
         tex_man: &mut TextureManager,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut current_clip = None;
         for tex_poly in &mut self.polygons {
             // info!("{:?}", tex_poly);
+            if tex_poly.clip != current_clip {
+                current_clip = tex_poly.clip;
+                canvas.set_clip_rect(current_clip.as_ref().map(sdl2::rect::Rect::from));
+            }
+            if current_clip.is_some_and(|clip| clip.width() <= 0.0 || clip.height() <= 0.0) {
+                continue;
+            }
             if let Some(arc_tex) = &mut tex_poly.tex {
                 let mut guard = arc_tex.lock().expect("geometry:Geometry:render");
                 // info!("{:?}", guard);
@@ -73,6 +91,9 @@ impl Geometry {
                 canvas.render_geometry(&tex_poly.poly.vers, None, &tex_poly.poly.inds)?;
             }
         }
+        if current_clip.is_some() {
+            canvas.set_clip_rect(None);
+        }
         Ok(())
     }
     pub fn translate(&mut self, position: &Vector2D<f32>) {
@@ -81,6 +102,26 @@ impl Geometry {
                 ver.position.x += position.x();
                 ver.position.y += position.y();
             }
+            if let Some(clip) = &mut tex_poly.clip {
+                *clip = Rect::new(
+                    clip.x() + position.x(),
+                    clip.y() + position.y(),
+                    clip.width(),
+                    clip.height(),
+                );
+            }
+        }
+    }
+    /// Restricts rendering to the rect, polygons that were already clipped keep the intersection.
+    pub fn clip(&mut self, rect: &Rect<f32>) {
+        for tex_poly in &mut self.polygons {
+            let clip = match &tex_poly.clip {
+                None => *rect,
+                Some(clip) => clip
+                    .intersection(rect)
+                    .unwrap_or_else(|| Rect::new(rect.x(), rect.y(), 0.0, 0.0)),
+            };
+            tex_poly.clip = Some(clip);
         }
     }
 }
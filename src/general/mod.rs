@@ -1,6 +1,15 @@
+mod arrow_direction;
+pub use arrow_direction::ArrowDirection;
+
 mod color;
 pub use color::Color;
 
+mod date;
+pub use date::{Date, Weekday};
+
+mod edges;
+pub use edges::Edges;
+
 mod geometry;
 pub use geometry::Geometry;
 
@@ -13,6 +22,12 @@ pub use rect::Rect;
 mod size2d;
 pub use size2d::Size2D;
 
+mod size_constraints;
+pub use size_constraints::SizeConstraints;
+
+mod text_align;
+pub use text_align::TextAlign;
+
 mod textured_polygon;
 pub use textured_polygon::TexturedPolygon;
 
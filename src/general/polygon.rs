@@ -2,7 +2,7 @@ use std::fmt::{Debug, Formatter};
 
 use sdl2::{rect::FPoint, render::Vertex};
 
-use crate::general::{Color, Rect, Vector2D};
+use crate::general::{ArrowDirection, Color, Rect, Vector2D};
 
 #[derive(Clone)]
 pub struct Polygon {
@@ -93,6 +93,38 @@ impl Polygon {
         }
     }
 
+    /// A triangle that fills the given size and points to one of its sides.
+    pub fn new_arrow(size: Vector2D<f32>, direction: ArrowDirection, color: Color) -> Polygon {
+        let (w, h) = size.unpack();
+        let points = match direction {
+            ArrowDirection::Up => [(0.0, h), (w, h), (w / 2.0, 0.0)],
+            ArrowDirection::Down => [(0.0, 0.0), (w / 2.0, h), (w, 0.0)],
+            ArrowDirection::Left => [(w, 0.0), (0.0, h / 2.0), (w, h)],
+            ArrowDirection::Right => [(0.0, 0.0), (0.0, h), (w, h / 2.0)],
+        };
+        let vers = points
+            .iter()
+            .map(|point| Self::new_vertex(point.into(), color.clone(), Default::default()))
+            .collect();
+        Polygon {
+            vers,
+            inds: vec![0, 1, 2],
+        }
+    }
+
+    /// Filled with triangles from the first point, so it has to be convex.
+    pub fn new_convex(points: &[Vector2D<f32>], color: Color) -> Polygon {
+        let vers = points
+            .iter()
+            .map(|point| Self::new_vertex(point.clone(), color.clone(), Default::default()))
+            .collect();
+        let mut inds = Vec::with_capacity(points.len().saturating_sub(2) * 3);
+        for i in 1..points.len().saturating_sub(1) as i32 {
+            inds.extend([0, i, i + 1]);
+        }
+        Polygon { vers, inds }
+    }
+
     pub fn new_reg_poly(size: Vector2D<f32>, sides: u32, rotate: f32) -> Polygon {
         let max = 2.0 * std::f32::consts::PI;
         let step = max / sides as f32;
@@ -128,11 +160,7 @@ impl Polygon {
         Polygon { vers, inds }
     }
 
-    fn new_vertex(
-        position: Vector2D<f32>,
-        color: Color,
-        tex_coord: Vector2D<f32>,
-    ) -> Vertex {
+    fn new_vertex(position: Vector2D<f32>, color: Color, tex_coord: Vector2D<f32>) -> Vertex {
         Vertex {
             position: FPoint::new(position.x(), position.y()),
             color: sdl2::pixels::Color::RGBA(color.r(), color.g(), color.b(), color.a()),
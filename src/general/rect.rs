@@ -1,6 +1,6 @@
 use crate::general::Vector2D;
 
-#[derive(Clone, Debug, Copy)]
+#[derive(Clone, Debug, Copy, PartialEq)]
 pub struct Rect<T> {
     x: T,
     y: T,
@@ -58,6 +58,38 @@ impl<T: std::ops::Add<Output = T> + PartialOrd + Copy + Default> Rect<T> {
     }
 }
 
+impl<T> Rect<T>
+where
+    T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + PartialOrd + Copy + Default,
+{
+    /// The area shared by both rects, None if they don't overlap.
+    pub fn intersection(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let max = |a: T, b: T| if a > b { a } else { b };
+        let min = |a: T, b: T| if a < b { a } else { b };
+        let x = max(self.x(), other.x());
+        let y = max(self.y(), other.y());
+        let right = min(self.right(), other.right());
+        let bottom = min(self.bottom(), other.bottom());
+        if right <= x || bottom <= y {
+            return None;
+        }
+        Some(Rect::new(x, y, right - x, bottom - y))
+    }
+}
+
+impl From<&Rect<f32>> for sdl2::rect::Rect {
+    fn from(val: &Rect<f32>) -> Self {
+        let x = val.x().floor();
+        let y = val.y().floor();
+        sdl2::rect::Rect::new(
+            x as i32,
+            y as i32,
+            (val.right().ceil() - x) as u32,
+            (val.bottom().ceil() - y) as u32,
+        )
+    }
+}
+
 impl From<Rect<u32>> for Option<sdl2::rect::Rect> {
     fn from(val: Rect<u32>) -> Self {
         Some(sdl2::rect::Rect::new(
@@ -0,0 +1,146 @@
+use crate::general::Vector2D;
+
+/// Limits to the size of a widget, None leaves that side to the widget.
+/// A fixed size is a min and a max that are the same, e.g. for buttons of the same width.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SizeConstraints {
+    pub min_width: Option<f32>,
+    pub min_height: Option<f32>,
+    pub max_width: Option<f32>,
+    pub max_height: Option<f32>,
+}
+
+impl SizeConstraints {
+    pub fn fixed(width: f32, height: f32) -> Self {
+        Self::fixed_width(width).with_height(height, height)
+    }
+    pub fn fixed_width(width: f32) -> Self {
+        Self::default().with_width(width, width)
+    }
+    pub fn fixed_height(height: f32) -> Self {
+        Self::default().with_height(height, height)
+    }
+    pub fn with_width(mut self, min_width: f32, max_width: f32) -> Self {
+        self.min_width = Some(min_width);
+        self.max_width = Some(max_width);
+        self
+    }
+    pub fn with_height(mut self, min_height: f32, max_height: f32) -> Self {
+        self.min_height = Some(min_height);
+        self.max_height = Some(max_height);
+        self
+    }
+    pub fn with_min(mut self, min_size: Vector2D<f32>) -> Self {
+        self.min_width = Some(min_size.x());
+        self.min_height = Some(min_size.y());
+        self
+    }
+    pub fn with_max(mut self, max_size: Vector2D<f32>) -> Self {
+        self.max_width = Some(max_size.x());
+        self.max_height = Some(max_size.y());
+        self
+    }
+    pub fn is_none(&self) -> bool {
+        *self == Self::default()
+    }
+    /// 0 for the sides without a min.
+    pub fn min_size(&self) -> Vector2D<f32> {
+        Vector2D::new(
+            self.min_width.unwrap_or(0.0),
+            self.min_height.unwrap_or(0.0),
+        )
+    }
+    /// Infinite for the sides without a max, never smaller than the min.
+    pub fn max_size(&self) -> Vector2D<f32> {
+        let (min_width, min_height) = self.min_size().unpack();
+        Vector2D::new(
+            self.max_width.unwrap_or(f32::INFINITY).max(min_width),
+            self.max_height.unwrap_or(f32::INFINITY).max(min_height),
+        )
+    }
+    /// The min wins when it's bigger than the max.
+    pub fn clamp(&self, size: &Vector2D<f32>) -> Vector2D<f32> {
+        let (min_width, min_height) = self.min_size().unpack();
+        let (max_width, max_height) = self.max_size().unpack();
+        Vector2D::new(
+            size.x().clamp(min_width, max_width),
+            size.y().clamp(min_height, max_height),
+        )
+    }
+    /// For widgets that ask their theme for their primitives. build gets the size_for_clipping,
+    /// it's called without one first and again with the size the widget must have if it
+    /// isn't the one it wants. given is the size a layout asked for, if any.
+    pub fn fit<F>(&self, given: Option<&Vector2D<f32>>, mut build: F) -> Vector2D<f32>
+    where
+        F: FnMut(Option<Vector2D<f32>>) -> Vector2D<f32>,
+    {
+        let natural = build(None);
+        let size = self.clamp(given.unwrap_or(&natural));
+        if size.unpack() == natural.unpack() {
+            return natural;
+        }
+        self.clamp(&build(Some(size)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_wins_over_a_smaller_max() {
+        let constraints = SizeConstraints::default().with_width(50.0, 20.0);
+        assert_eq!(constraints.max_size().x(), 50.0);
+        assert_eq!(
+            constraints.clamp(&Vector2D::new(10.0, 10.0)).unpack(),
+            (50.0, 10.0)
+        );
+        assert_eq!(
+            constraints.clamp(&Vector2D::new(90.0, 10.0)).unpack(),
+            (50.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn no_constraints_keep_the_size() {
+        let constraints = SizeConstraints::default();
+        assert!(constraints.is_none());
+        assert_eq!(
+            constraints.clamp(&Vector2D::new(0.0, 1e6)).unpack(),
+            (0.0, 1e6)
+        );
+    }
+
+    #[test]
+    fn fit_builds_once_when_the_natural_size_fits() {
+        let mut calls = Vec::new();
+        let size = SizeConstraints::default()
+            .with_width(0.0, 100.0)
+            .fit(None, |clip| {
+                calls.push(clip.map(|clip| clip.unpack()));
+                Vector2D::new(40.0, 10.0)
+            });
+        assert_eq!(size.unpack(), (40.0, 10.0));
+        assert_eq!(calls, vec![None]);
+    }
+
+    #[test]
+    fn fit_rebuilds_with_the_clamped_size() {
+        let mut calls = Vec::new();
+        let size = SizeConstraints::fixed_width(30.0).fit(None, |clip| {
+            calls.push(clip.map(|clip| clip.unpack()));
+            // The build may ignore the clip, the result is clamped anyway
+            Vector2D::new(40.0, 10.0)
+        });
+        assert_eq!(size.unpack(), (30.0, 10.0));
+        assert_eq!(calls, vec![None, Some((30.0, 10.0))]);
+    }
+
+    #[test]
+    fn fit_uses_the_given_size() {
+        let given = Vector2D::new(70.0, 500.0);
+        let constraints = SizeConstraints::default().with_height(0.0, 200.0);
+        let size = constraints.fit(Some(&given), |clip| clip.unwrap_or(Vector2D::new(1.0, 1.0)));
+        assert_eq!(size.unpack(), (70.0, 200.0));
+    }
+}
@@ -0,0 +1,10 @@
+/// How the lines of a text sit in the width they have.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+    /// Stretches the spaces so every line but the last of a paragraph fills the width.
+    Justify,
+}
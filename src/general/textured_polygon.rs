@@ -1,6 +1,6 @@
 use std::sync::{Arc, Mutex};
 
-use crate::general::Polygon;
+use crate::general::{Polygon, Rect};
 use crate::texture::SoftTexture;
 
 /// A representation of SDL's geometry as defined in SDL_RenderGeometry
@@ -8,4 +8,6 @@ use crate::texture::SoftTexture;
 pub struct TexturedPolygon {
     pub(crate) poly: Polygon,
     pub(crate) tex: Option<Arc<Mutex<dyn SoftTexture>>>,
+    /// Only the part of the polygon inside this rect gets rendered, in window coordinates.
+    pub(crate) clip: Option<Rect<f32>>,
 }
@@ -1,9 +1,11 @@
 pub use alpha_soft_texture::AlphaSoftTexture;
 pub use ram_soft_texture::RAMSoftTexture;
 pub use soft_texture::SoftTexture;
+pub use streaming_soft_texture::{FramePixels, StreamingSoftTexture};
 pub use texture_manager::TextureManager;
 
 mod alpha_soft_texture;
 mod ram_soft_texture;
 mod soft_texture;
+mod streaming_soft_texture;
 mod texture_manager;
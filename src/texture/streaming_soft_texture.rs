@@ -0,0 +1,186 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use log::debug;
+use sdl2::pixels::PixelFormatEnum;
+use sdl2::render::{Texture, TextureCreator};
+use sdl2::video::WindowContext;
+
+use crate::general::{Color, Polygon, Rect};
+use crate::texture::{SoftTexture, TextureManager};
+
+const BYTES_PER_PIXEL: usize = 4;
+
+/// The pixels of a StreamingSoftTexture, they can be set from any thread.
+/// Kept around for the next frame, they are only uploaded when they changed.
+pub struct FramePixels {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    dirty: bool,
+}
+
+impl FramePixels {
+    /// Starts transparent.
+    pub fn new(width: u32, height: u32) -> FramePixels {
+        if width == 0 || height == 0 {
+            panic!("Texture dimensions cannot be zero")
+        }
+        FramePixels {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * BYTES_PER_PIXEL],
+            dirty: true,
+        }
+    }
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+    /// Four bytes per pixel, in R, G, B, A order, row after row without padding.
+    pub fn set_rgba(&mut self, pixels: &[u8]) -> Result<(), Box<dyn Error>> {
+        if pixels.len() != self.pixels.len() {
+            return Err(format!(
+                "set_rgba() expected {} bytes for {}x{}, got {}",
+                self.pixels.len(),
+                self.width,
+                self.height,
+                pixels.len()
+            )
+            .into());
+        }
+        self.pixels.copy_from_slice(pixels);
+        self.dirty = true;
+        Ok(())
+    }
+    /// One byte per pixel, an index in the palette. Indices past the palette are transparent.
+    pub fn set_indexed(&mut self, indices: &[u8], palette: &[Color]) -> Result<(), Box<dyn Error>> {
+        if indices.len() * BYTES_PER_PIXEL != self.pixels.len() {
+            return Err(format!(
+                "set_indexed() expected {} bytes for {}x{}, got {}",
+                self.pixels.len() / BYTES_PER_PIXEL,
+                self.width,
+                self.height,
+                indices.len()
+            )
+            .into());
+        }
+        for (pixel, index) in self.pixels.chunks_exact_mut(BYTES_PER_PIXEL).zip(indices) {
+            let rgba = palette
+                .get(*index as usize)
+                .map_or([0; 4], |color| [color.r(), color.g(), color.b(), color.a()]);
+            pixel.copy_from_slice(&rgba);
+        }
+        self.dirty = true;
+        Ok(())
+    }
+}
+
+/// Pixels that change every frame, like an emulator's screen or a video.
+/// Unlike RAMSoftTexture, the SDL texture is created once and then updated in place on each
+/// render() after new pixels were set. The TextureManager keeps it as long as the pixels exist,
+/// so only the pixels are shared and nothing of SDL leaves the render thread.
+pub struct StreamingSoftTexture {
+    id: Option<usize>,
+    pixels: Arc<Mutex<FramePixels>>,
+    width: u32,
+    height: u32,
+    poly: Polygon,
+}
+
+impl StreamingSoftTexture {
+    pub fn new(pixels: Arc<Mutex<FramePixels>>) -> StreamingSoftTexture {
+        let (width, height) = {
+            let pixels = pixels
+                .lock()
+                .expect("streaming_soft_texture:StreamingSoftTexture:new");
+            (pixels.width, pixels.height)
+        };
+        StreamingSoftTexture {
+            id: None,
+            pixels,
+            width,
+            height,
+            poly: Polygon::new_rect_for_texture(Rect::new(0, 0, width, height), 255),
+        }
+    }
+}
+
+impl SoftTexture for StreamingSoftTexture {
+    fn id(&self) -> usize {
+        self.id.unwrap_or_default()
+    }
+    fn render(
+        &mut self,
+        tex_creator: &TextureCreator<WindowContext>,
+        tex_man: &mut TextureManager,
+    ) -> Result<Rc<RefCell<Texture>>, Box<dyn Error>> {
+        let mut pixels = self
+            .pixels
+            .lock()
+            .expect("streaming_soft_texture:StreamingSoftTexture:render");
+        let tex = match self.id.and_then(|id| tex_man.get(id)) {
+            Some(tex) => tex,
+            None => {
+                debug!("{}", self.class());
+                let (rc_tex, id) = tex_man.reserve_streaming(
+                    tex_creator,
+                    self.width,
+                    self.height,
+                    PixelFormatEnum::RGBA32,
+                    &self.pixels,
+                )?;
+                self.id = Some(id);
+                pixels.dirty = true;
+                rc_tex
+            }
+        };
+        if pixels.dirty {
+            let row_len = self.width as usize * BYTES_PER_PIXEL;
+            let source = &pixels.pixels;
+            // The texture's rows can be longer than ours, so they are copied one by one
+            tex.borrow_mut()
+                .with_lock(None, |buffer: &mut [u8], pitch: usize| {
+                    for (row, source) in source.chunks_exact(row_len).enumerate() {
+                        buffer[row * pitch..row * pitch + row_len].copy_from_slice(source);
+                    }
+                })?;
+            pixels.dirty = false;
+        }
+        Ok(tex)
+    }
+    fn class(&self) -> &str {
+        stringify!(StreamingSoftTexture)
+    }
+    fn width(&self) -> u32 {
+        self.width
+    }
+    fn height(&self) -> u32 {
+        self.height
+    }
+    fn poly(&self) -> &Polygon {
+        &self.poly
+    }
+}
+
+impl Debug for FramePixels {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Without the pixels, they are too many to print
+        f.debug_struct("FramePixels")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("dirty", &self.dirty)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Debug for StreamingSoftTexture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.fmt_dyn(f)
+    }
+}
@@ -2,14 +2,19 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::error::Error;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex, Weak};
 
 use log::debug;
 use sdl2::pixels::PixelFormatEnum;
 use sdl2::render::{Texture, TextureCreator};
 use sdl2::video::WindowContext;
 
+use crate::texture::FramePixels;
+
 pub struct TextureManager {
     textures: HashMap<usize, Rc<RefCell<Texture>>>,
+    // Streaming textures are kept while the pixels they show exist
+    owners: HashMap<usize, Weak<Mutex<FramePixels>>>,
     last_id: usize,
 }
 
@@ -20,6 +25,7 @@ impl TextureManager {
     pub fn new() -> TextureManager {
         TextureManager {
             textures: Default::default(),
+            owners: Default::default(),
             last_id: 0,
         }
     }
@@ -47,15 +53,39 @@ impl TextureManager {
         let id = self.push(&rc);
         Ok((rc, id))
     }
+    /// For textures whose pixels change often, see StreamingSoftTexture.
+    /// It isn't collected until the pixels are dropped.
+    pub fn reserve_streaming(
+        &mut self,
+        tex_creator: &TextureCreator<WindowContext>,
+        width: u32,
+        height: u32,
+        format: PixelFormatEnum,
+        pixels: &Arc<Mutex<FramePixels>>,
+    ) -> Result<RcTexAndId, Box<dyn Error>> {
+        let tex = tex_creator.create_texture_streaming(format, width, height)?;
+        let rc = Rc::new(RefCell::new(tex));
+        let id = self.push(&rc);
+        self.owners.insert(id, Arc::downgrade(pixels));
+        Ok((rc, id))
+    }
+    pub fn get(&self, id: usize) -> Option<Rc<RefCell<Texture>>> {
+        self.textures.get(&id).cloned()
+    }
     pub fn garbage_collect(&mut self, _tex_creator: TextureCreator<WindowContext>) {
         let mut garbage = vec![];
         for (id, tex) in &self.textures {
-            if Rc::strong_count(tex) == 1 {
+            let owned = self
+                .owners
+                .get(id)
+                .is_some_and(|pixels| pixels.strong_count() > 0);
+            if Rc::strong_count(tex) == 1 && !owned {
                 garbage.push(*id);
             }
         }
         for id in garbage {
             debug!("Killing tex: {}", id);
+            self.owners.remove(&id);
             let tex = self
                 .textures
                 .remove(&id)
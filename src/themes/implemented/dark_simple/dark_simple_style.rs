@@ -1,10 +1,22 @@
 use std::any::TypeId;
 
-use crate::general::Color;
+use crate::general::{Color, Edges};
 use crate::themes::property::ApplyTo::Type;
 use crate::themes::ExtraStyleEnum::BackgroundColorGradient;
-use crate::themes::{ButtonCompleteStyle, GeneralStyle, Style, TextBoxCompleteStyle, ThemeStyle};
-use crate::widgets::{Button, Image, TextBox};
+use crate::themes::{
+    AccordionCompleteStyle, ButtonCompleteStyle, CalendarCompleteStyle, CanvasCompleteStyle,
+    ColorPickerCompleteStyle, DatePickerCompleteStyle, DialogCompleteStyle,
+    FramebufferCompleteStyle, GeneralStyle, GroupBoxCompleteStyle, LabelCompleteStyle,
+    MenuBarCompleteStyle, MenuCompleteStyle, ScrollViewCompleteStyle, SpinBoxCompleteStyle,
+    SplitterCompleteStyle, StatusBarCompleteStyle, Style, TabViewCompleteStyle,
+    TextAreaCompleteStyle, TextBoxCompleteStyle, ThemeStyle, ToastCompleteStyle,
+    ToggleCompleteStyle, TooltipCompleteStyle, TreeViewCompleteStyle,
+};
+use crate::widgets::{
+    Accordion, Button, Calendar, Canvas, ColorPicker, DatePicker, Dialog, Framebuffer, GroupBox,
+    Image, Label, Menu, MenuBar, ScrollView, SpinBox, Splitter, StatusBar, TabView, TextArea,
+    TextBox, Toast, Toggle, Tooltip, TreeView,
+};
 
 pub struct DarkSimpleStyle;
 
@@ -17,6 +29,11 @@ impl ThemeStyle for DarkSimpleStyle {
                 background_color: (128, 128, 128, 255),
                 font: "Nouveau_IBM".to_string(),
                 font_size: 32.0,
+                spacing: 8.0,
+                padding: Edges::symmetric(4.0, 12.0),
+                margin: Edges::all(4.0),
+                border_width: Edges::all(2.0),
+                border_color: (192, 192, 192, 255),
                 extra: vec![(BackgroundColorGradient, Color::new_opaque(0, 255, 0).into())],
                 ..Default::default()
             }),
@@ -32,6 +49,210 @@ impl ThemeStyle for DarkSimpleStyle {
                 apply_to: Type(TypeId::of::<Image>()),
                 ..Default::default()
             }),
+            Box::new(TreeViewCompleteStyle {
+                apply_to: Type(TypeId::of::<TreeView>()),
+                color: (255, 255, 255, 255),
+                background_color: (32, 32, 32, 255),
+                selection_color: (0, 96, 192, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 24.0,
+                row_height: 28.0,
+                indent: 20.0,
+                ..Default::default()
+            }),
+            Box::new(TabViewCompleteStyle {
+                apply_to: Type(TypeId::of::<TabView>()),
+                color: (255, 255, 255, 255),
+                background_color: (64, 64, 64, 255),
+                selection_color: (0, 96, 192, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 24.0,
+                spacing: 8.0,
+                ..Default::default()
+            }),
+            Box::new(ScrollViewCompleteStyle {
+                apply_to: Type(TypeId::of::<ScrollView>()),
+                color: (160, 160, 160, 255),
+                background_color: (48, 48, 48, 255),
+                thickness: 12.0,
+                ..Default::default()
+            }),
+            Box::new(DialogCompleteStyle {
+                apply_to: Type(TypeId::of::<Dialog>()),
+                color: (255, 255, 255, 255),
+                background_color: (48, 48, 48, 255),
+                button_color: (96, 96, 96, 255),
+                selection_color: (0, 96, 192, 255),
+                overlay_color: (0, 0, 0, 160),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 24.0,
+                spacing: 8.0,
+                ..Default::default()
+            }),
+            Box::new(TooltipCompleteStyle {
+                apply_to: Type(TypeId::of::<Tooltip>()),
+                color: (0, 0, 0, 255),
+                background_color: (255, 255, 192, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 4.0,
+                ..Default::default()
+            }),
+            Box::new(ToastCompleteStyle {
+                apply_to: Type(TypeId::of::<Toast>()),
+                color: (255, 255, 255, 255),
+                info_color: (48, 80, 128, 255),
+                success_color: (32, 112, 48, 255),
+                warning_color: (160, 112, 0, 255),
+                error_color: (160, 32, 32, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 8.0,
+                ..Default::default()
+            }),
+            Box::new(MenuCompleteStyle {
+                apply_to: Type(TypeId::of::<Menu>()),
+                color: (255, 255, 255, 255),
+                background_color: (40, 40, 40, 255),
+                selection_color: (0, 96, 192, 255),
+                disabled_color: (128, 128, 128, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 6.0,
+                ..Default::default()
+            }),
+            Box::new(MenuBarCompleteStyle {
+                apply_to: Type(TypeId::of::<MenuBar>()),
+                color: (255, 255, 255, 255),
+                background_color: (56, 56, 56, 255),
+                selection_color: (0, 96, 192, 255),
+                disabled_color: (128, 128, 128, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 8.0,
+                ..Default::default()
+            }),
+            Box::new(StatusBarCompleteStyle {
+                apply_to: Type(TypeId::of::<StatusBar>()),
+                color: (224, 224, 224, 255),
+                background_color: (0, 72, 144, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 18.0,
+                spacing: 6.0,
+                ..Default::default()
+            }),
+            Box::new(SpinBoxCompleteStyle {
+                apply_to: Type(TypeId::of::<SpinBox>()),
+                color: (255, 255, 255, 255),
+                background_color: (0, 0, 255, 255),
+                selection_color: (64, 64, 192, 255),
+                error_color: (255, 96, 96, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 24.0,
+                spacing: 6.0,
+                ..Default::default()
+            }),
+            Box::new(CalendarCompleteStyle {
+                apply_to: Type(TypeId::of::<Calendar>()),
+                color: (224, 224, 224, 255),
+                background_color: (48, 48, 48, 255),
+                selection_color: (0, 96, 192, 255),
+                disabled_color: (112, 112, 112, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 18.0,
+                spacing: 6.0,
+                ..Default::default()
+            }),
+            Box::new(DatePickerCompleteStyle {
+                apply_to: Type(TypeId::of::<DatePicker>()),
+                color: (255, 255, 255, 255),
+                background_color: (0, 0, 255, 255),
+                selection_color: (64, 64, 192, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 24.0,
+                spacing: 6.0,
+                ..Default::default()
+            }),
+            Box::new(TextAreaCompleteStyle {
+                apply_to: Type(TypeId::of::<TextArea>()),
+                color: (224, 224, 224, 255),
+                background_color: (32, 32, 48, 255),
+                selection_color: (0, 96, 192, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 6.0,
+                thickness: 8.0,
+                ..Default::default()
+            }),
+            Box::new(LabelCompleteStyle {
+                apply_to: Type(TypeId::of::<Label>()),
+                color: (224, 224, 224, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 2.0,
+                ..Default::default()
+            }),
+            Box::new(CanvasCompleteStyle {
+                apply_to: Type(TypeId::of::<Canvas>()),
+                background_color: (16, 16, 32, 255),
+                font: "Nouveau_IBM".to_string(),
+                ..Default::default()
+            }),
+            Box::new(FramebufferCompleteStyle {
+                apply_to: Type(TypeId::of::<Framebuffer>()),
+                background_color: (0, 0, 0, 255),
+                ..Default::default()
+            }),
+            Box::new(SplitterCompleteStyle {
+                apply_to: Type(TypeId::of::<Splitter>()),
+                color: (160, 160, 160, 255),
+                background_color: (48, 48, 64, 255),
+                selection_color: (0, 96, 192, 255),
+                thickness: 6.0,
+                ..Default::default()
+            }),
+            Box::new(ColorPickerCompleteStyle {
+                apply_to: Type(TypeId::of::<ColorPicker>()),
+                color: (224, 224, 224, 255),
+                background_color: (40, 40, 48, 255),
+                selection_color: (0, 96, 192, 255),
+                error_color: (255, 96, 96, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 6.0,
+                size: 160.0,
+                thickness: 18.0,
+                ..Default::default()
+            }),
+            Box::new(ToggleCompleteStyle {
+                apply_to: Type(TypeId::of::<Toggle>()),
+                color: (240, 240, 240, 255),
+                background_color: (80, 80, 96, 255),
+                selection_color: (0, 160, 96, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 24.0,
+                spacing: 8.0,
+                ..Default::default()
+            }),
+            Box::new(GroupBoxCompleteStyle {
+                apply_to: Type(TypeId::of::<GroupBox>()),
+                color: (128, 128, 160, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 8.0,
+                thickness: 2.0,
+                ..Default::default()
+            }),
+            Box::new(AccordionCompleteStyle {
+                apply_to: Type(TypeId::of::<Accordion>()),
+                color: (240, 240, 240, 255),
+                background_color: (64, 64, 80, 255),
+                selection_color: (0, 96, 192, 255),
+                font: "Nouveau_IBM".to_string(),
+                font_size: 20.0,
+                spacing: 8.0,
+                ..Default::default()
+            }),
         ]
     }
 }
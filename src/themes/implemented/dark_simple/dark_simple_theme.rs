@@ -5,14 +5,36 @@ use crosstrait::entry;
 use crate::{
     themes::{
         ArcFnNewStyleForWidgetWrap, CrossTraitEntry, Style, StyleForWidget, ThemeEngine,
-        ThemeForButton, ThemeForImage, ThemeForTextBox, ThemeForWidget, ThemeStyle,
-        ThemeStyleForButton, ThemeStyleForImage, ThemeStyleForTextBox,
+        ThemeForAccordion, ThemeForButton, ThemeForCalendar, ThemeForCanvas, ThemeForColorPicker,
+        ThemeForDatePicker, ThemeForDialog, ThemeForFramebuffer, ThemeForGroupBox, ThemeForImage,
+        ThemeForLabel, ThemeForMenu, ThemeForMenuBar, ThemeForScrollView, ThemeForSpinBox,
+        ThemeForSplitter, ThemeForStatusBar, ThemeForTabView, ThemeForTextArea, ThemeForTextBox,
+        ThemeForToast, ThemeForToggle, ThemeForTooltip, ThemeForTreeView, ThemeForWidget,
+        ThemeStyle, ThemeStyleForAccordion, ThemeStyleForButton, ThemeStyleForCalendar,
+        ThemeStyleForCanvas, ThemeStyleForColorPicker, ThemeStyleForDatePicker,
+        ThemeStyleForDialog, ThemeStyleForFramebuffer, ThemeStyleForGroupBox, ThemeStyleForImage,
+        ThemeStyleForLabel, ThemeStyleForMenu, ThemeStyleForMenuBar, ThemeStyleForScrollView,
+        ThemeStyleForSpinBox, ThemeStyleForSplitter, ThemeStyleForStatusBar, ThemeStyleForTabView,
+        ThemeStyleForTextArea, ThemeStyleForTextBox, ThemeStyleForToast, ThemeStyleForToggle,
+        ThemeStyleForTooltip, ThemeStyleForTreeView,
+    },
+    widgets::{
+        Accordion, Calendar, Canvas, ColorPicker, DatePicker, Dialog, Framebuffer, GroupBox, Image,
+        Label, Menu, MenuBar, ScrollView, SpinBox, Splitter, StatusBar, TabView, TextArea, TextBox,
+        Toast, Toggle, Tooltip, TreeView,
     },
-    widgets::{Image, TextBox},
 };
 
 use super::{
-    DarkSimpleStyle, DarkSimpleThemeForButton, DarkSimpleThemeForImage, DarkSimpleThemeForTextBox,
+    DarkSimpleStyle, DarkSimpleThemeForAccordion, DarkSimpleThemeForButton,
+    DarkSimpleThemeForCalendar, DarkSimpleThemeForCanvas, DarkSimpleThemeForColorPicker,
+    DarkSimpleThemeForDatePicker, DarkSimpleThemeForDialog, DarkSimpleThemeForFramebuffer,
+    DarkSimpleThemeForGroupBox, DarkSimpleThemeForImage, DarkSimpleThemeForLabel,
+    DarkSimpleThemeForMenu, DarkSimpleThemeForMenuBar, DarkSimpleThemeForScrollView,
+    DarkSimpleThemeForSpinBox, DarkSimpleThemeForSplitter, DarkSimpleThemeForStatusBar,
+    DarkSimpleThemeForTabView, DarkSimpleThemeForTextArea, DarkSimpleThemeForTextBox,
+    DarkSimpleThemeForToast, DarkSimpleThemeForToggle, DarkSimpleThemeForTooltip,
+    DarkSimpleThemeForTreeView,
 };
 
 use crate::widgets::Button;
@@ -32,6 +54,27 @@ impl ThemeEngine for DarkSimpleTheme {
             ),
             (TypeId::of::<TextBox>(), &DarkSimpleThemeForTextBox),
             (TypeId::of::<Image>(), &DarkSimpleThemeForImage),
+            (TypeId::of::<TreeView>(), &DarkSimpleThemeForTreeView),
+            (TypeId::of::<TabView>(), &DarkSimpleThemeForTabView),
+            (TypeId::of::<ScrollView>(), &DarkSimpleThemeForScrollView),
+            (TypeId::of::<Dialog>(), &DarkSimpleThemeForDialog),
+            (TypeId::of::<Tooltip>(), &DarkSimpleThemeForTooltip),
+            (TypeId::of::<Toast>(), &DarkSimpleThemeForToast),
+            (TypeId::of::<Menu>(), &DarkSimpleThemeForMenu),
+            (TypeId::of::<MenuBar>(), &DarkSimpleThemeForMenuBar),
+            (TypeId::of::<StatusBar>(), &DarkSimpleThemeForStatusBar),
+            (TypeId::of::<SpinBox>(), &DarkSimpleThemeForSpinBox),
+            (TypeId::of::<Calendar>(), &DarkSimpleThemeForCalendar),
+            (TypeId::of::<DatePicker>(), &DarkSimpleThemeForDatePicker),
+            (TypeId::of::<TextArea>(), &DarkSimpleThemeForTextArea),
+            (TypeId::of::<Label>(), &DarkSimpleThemeForLabel),
+            (TypeId::of::<Canvas>(), &DarkSimpleThemeForCanvas),
+            (TypeId::of::<Framebuffer>(), &DarkSimpleThemeForFramebuffer),
+            (TypeId::of::<Splitter>(), &DarkSimpleThemeForSplitter),
+            (TypeId::of::<ColorPicker>(), &DarkSimpleThemeForColorPicker),
+            (TypeId::of::<Toggle>(), &DarkSimpleThemeForToggle),
+            (TypeId::of::<GroupBox>(), &DarkSimpleThemeForGroupBox),
+            (TypeId::of::<Accordion>(), &DarkSimpleThemeForAccordion),
         ])
     }
     fn get_crosstrait_registry(&self) -> &'static [CrossTraitEntry] {
@@ -39,6 +82,27 @@ impl ThemeEngine for DarkSimpleTheme {
             entry!(DarkSimpleThemeForButton => dyn ThemeForButton),
             entry!(DarkSimpleThemeForImage => dyn ThemeForImage),
             entry!(DarkSimpleThemeForTextBox => dyn ThemeForTextBox),
+            entry!(DarkSimpleThemeForTreeView => dyn ThemeForTreeView),
+            entry!(DarkSimpleThemeForTabView => dyn ThemeForTabView),
+            entry!(DarkSimpleThemeForScrollView => dyn ThemeForScrollView),
+            entry!(DarkSimpleThemeForDialog => dyn ThemeForDialog),
+            entry!(DarkSimpleThemeForTooltip => dyn ThemeForTooltip),
+            entry!(DarkSimpleThemeForToast => dyn ThemeForToast),
+            entry!(DarkSimpleThemeForMenu => dyn ThemeForMenu),
+            entry!(DarkSimpleThemeForMenuBar => dyn ThemeForMenuBar),
+            entry!(DarkSimpleThemeForStatusBar => dyn ThemeForStatusBar),
+            entry!(DarkSimpleThemeForSpinBox => dyn ThemeForSpinBox),
+            entry!(DarkSimpleThemeForCalendar => dyn ThemeForCalendar),
+            entry!(DarkSimpleThemeForDatePicker => dyn ThemeForDatePicker),
+            entry!(DarkSimpleThemeForTextArea => dyn ThemeForTextArea),
+            entry!(DarkSimpleThemeForLabel => dyn ThemeForLabel),
+            entry!(DarkSimpleThemeForCanvas => dyn ThemeForCanvas),
+            entry!(DarkSimpleThemeForFramebuffer => dyn ThemeForFramebuffer),
+            entry!(DarkSimpleThemeForSplitter => dyn ThemeForSplitter),
+            entry!(DarkSimpleThemeForColorPicker => dyn ThemeForColorPicker),
+            entry!(DarkSimpleThemeForToggle => dyn ThemeForToggle),
+            entry!(DarkSimpleThemeForGroupBox => dyn ThemeForGroupBox),
+            entry!(DarkSimpleThemeForAccordion => dyn ThemeForAccordion),
         ]
     }
     fn get_style_for_widget_mapping(&self) -> HashMap<TypeId, ArcFnNewStyleForWidgetWrap> {
@@ -55,6 +119,90 @@ impl ThemeEngine for DarkSimpleTheme {
                 TypeId::of::<TextBox>(),
                 ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForTextBox::new)),
             ),
+            (
+                TypeId::of::<TreeView>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForTreeView::new)),
+            ),
+            (
+                TypeId::of::<TabView>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForTabView::new)),
+            ),
+            (
+                TypeId::of::<ScrollView>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForScrollView::new)),
+            ),
+            (
+                TypeId::of::<Dialog>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForDialog::new)),
+            ),
+            (
+                TypeId::of::<Tooltip>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForTooltip::new)),
+            ),
+            (
+                TypeId::of::<Toast>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForToast::new)),
+            ),
+            (
+                TypeId::of::<Menu>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForMenu::new)),
+            ),
+            (
+                TypeId::of::<MenuBar>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForMenuBar::new)),
+            ),
+            (
+                TypeId::of::<StatusBar>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForStatusBar::new)),
+            ),
+            (
+                TypeId::of::<SpinBox>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForSpinBox::new)),
+            ),
+            (
+                TypeId::of::<Calendar>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForCalendar::new)),
+            ),
+            (
+                TypeId::of::<DatePicker>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForDatePicker::new)),
+            ),
+            (
+                TypeId::of::<TextArea>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForTextArea::new)),
+            ),
+            (
+                TypeId::of::<Label>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForLabel::new)),
+            ),
+            (
+                TypeId::of::<Canvas>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForCanvas::new)),
+            ),
+            (
+                TypeId::of::<Framebuffer>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForFramebuffer::new)),
+            ),
+            (
+                TypeId::of::<Splitter>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForSplitter::new)),
+            ),
+            (
+                TypeId::of::<ColorPicker>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForColorPicker::new)),
+            ),
+            (
+                TypeId::of::<Toggle>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForToggle::new)),
+            ),
+            (
+                TypeId::of::<GroupBox>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForGroupBox::new)),
+            ),
+            (
+                TypeId::of::<Accordion>(),
+                ArcFnNewStyleForWidgetWrap(Arc::new(ThemeStyleForAccordion::new)),
+            ),
         ]) as HashMap<TypeId, ArcFnNewStyleForWidgetWrap>
     }
 }
@@ -0,0 +1,139 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForAccordion, ThemeForWidget,
+    ThemeStyleForAccordion,
+};
+use crate::{
+    general::{ArrowDirection, Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{AccordionLayout, AccordionSectionFrame},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum AccordionPrimEnum {
+    Header(usize),
+    Arrow(usize),
+    Title(usize),
+}
+
+impl PrimEnum for AccordionPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            AccordionPrimEnum::Header(index) => index * 3,
+            AccordionPrimEnum::Arrow(index) => index * 3 + 1,
+            AccordionPrimEnum::Title(index) => index * 3 + 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForAccordion;
+
+impl ThemeForAccordion for DarkSimpleThemeForAccordion {
+    fn new_accordion(
+        &self,
+        sections: &[AccordionSectionFrame],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForAccordion>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> AccordionLayout {
+        self.set_accordion(sections, size_for_clipping, style, prim_man)
+    }
+    fn set_accordion(
+        &self,
+        sections: &[AccordionSectionFrame],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForAccordion>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> AccordionLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let spacing = style.spacing;
+        let line_height = Text::line_height(style.font_size, style.font.clone());
+        let header_height = line_height + spacing;
+        let arrow_size = line_height / 2.0;
+        let title_x = spacing + arrow_size + spacing;
+        let width_of = |text: &str| -> f32 {
+            Text::char_widths(text, style.font_size, style.font.clone())
+                .iter()
+                .sum()
+        };
+        // As wide as the widest title or content, even closed ones so it doesn't jump
+        let width = sections
+            .iter()
+            .map(|section| {
+                (title_x + width_of(&section.title) + spacing).max(section.content_size.x())
+            })
+            .fold(0.0, f32::max);
+
+        let mut headers = Vec::with_capacity(sections.len());
+        let mut contents = Vec::with_capacity(sections.len());
+        let mut y = 0.0;
+        for (index, section) in sections.iter().enumerate() {
+            let header = Rect::new(0.0, y, width, header_height);
+            // 1 pixel between headers
+            let mut background = Shape::new_square(
+                0,
+                Vector2D::new(width, header_height - 1.0),
+                0,
+                if section.openness > 0.0 {
+                    style.selection_color.clone()
+                } else {
+                    style.background_color.clone()
+                },
+            );
+            background.set_position(Vector2D::new(0.0, y));
+            let header_enum = AccordionPrimEnum::Header(index);
+            prim_man.insert(header_enum, background, header_enum.to_prim_id() as isize);
+
+            let direction = if section.openness > 0.5 {
+                ArrowDirection::Down
+            } else {
+                ArrowDirection::Right
+            };
+            let mut arrow = Shape::new_arrow(
+                0,
+                Vector2D::new(arrow_size, arrow_size),
+                direction,
+                style.color.clone(),
+            );
+            arrow.set_position(Vector2D::new(
+                spacing,
+                y + (header_height - arrow_size) / 2.0,
+            ));
+            let arrow_enum = AccordionPrimEnum::Arrow(index);
+            prim_man.insert(arrow_enum, arrow, arrow_enum.to_prim_id() as isize);
+
+            if !section.title.is_empty() {
+                let mut title = Text::new(
+                    0,
+                    &section.title,
+                    style.font_size,
+                    style.font.clone(),
+                    style.color.clone(),
+                );
+                title.set_position(Vector2D::new(title_x, y + spacing / 2.0));
+                let title_enum = AccordionPrimEnum::Title(index);
+                prim_man.insert(title_enum, title, title_enum.to_prim_id() as isize);
+            }
+
+            y += header_height;
+            let content_height = section.content_size.y() * section.openness.clamp(0.0, 1.0);
+            contents.push(Rect::new(0.0, y, width, content_height));
+            headers.push(header);
+            y += content_height;
+        }
+        AccordionLayout {
+            size: prim_man.frame(
+                Vector2D::new(width, y),
+                &style.box_model,
+                Some(&style.background_color),
+            ),
+            headers,
+            contents,
+        }
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForAccordion {}
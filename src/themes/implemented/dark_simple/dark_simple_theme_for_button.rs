@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
 
 use log::info;
 
@@ -8,15 +9,16 @@ use crate::themes::{
 };
 use crate::{
     general::Vector2D,
-    utils::Downcast,
-    widgets::primitives::{Primitive, Shape, Text},
+    texture::SoftTexture,
+    widgets::primitives::{Bitmap, Primitive, Text},
+    widgets::IconPlacement,
 };
 
 #[repr(usize)]
 #[derive(Clone, Copy, Debug)]
 enum ButtonPrimEnum {
     Text,
-    Square,
+    Icon,
 }
 
 impl PrimEnum for ButtonPrimEnum {
@@ -28,6 +30,42 @@ impl PrimEnum for ButtonPrimEnum {
 #[derive(Debug)]
 pub struct DarkSimpleThemeForButton;
 
+impl DarkSimpleThemeForButton {
+    /// Where the content goes to be centered in the given size, or the content size.
+    fn center(
+        content_size: &Vector2D<f32>,
+        size_for_clipping: Option<&Vector2D<f32>>,
+    ) -> (Vector2D<f32>, Vector2D<f32>) {
+        let (content_width, content_height) = content_size.unpack();
+        let (width, height) = size_for_clipping.map_or(content_size.unpack(), |size| size.unpack());
+        (
+            Vector2D::new(width, height),
+            Vector2D::new(
+                (width - content_width) / 2.0,
+                (height - content_height) / 2.0,
+            ),
+        )
+    }
+    /// The text as is, or with an ellipsis when it's wider than max_width.
+    fn text_prim(text: &str, max_width: f32, style: &ThemeStyleForButton) -> Text {
+        let width: f32 = Text::char_widths(text, style.font_size, style.font.clone())
+            .iter()
+            .sum();
+        let text = if width > max_width {
+            Text::ellipsize(text, max_width, style.font_size, style.font.clone())
+        } else {
+            text.to_string()
+        };
+        Text::new(
+            0,
+            &text,
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        )
+    }
+}
+
 impl ThemeForButton for DarkSimpleThemeForButton {
     fn new_button(
         &self,
@@ -36,19 +74,7 @@ impl ThemeForButton for DarkSimpleThemeForButton {
         style: Box<ThemeStyleForButton>,
         prim_man: &mut PrimitivesManagerForThemes,
     ) -> Vector2D<f32> {
-        assert!(
-            size_for_clipping.is_none(),
-            "theme_for_button:ThemeForButton:new size_for_clipping not supported yet."
-        );
-        let mut text_prim = Text::new(0, text, style.font_size, style.font, style.color);
-        let text_size = text_prim.size().clone();
-        prim_man.insert(ButtonPrimEnum::Text, text_prim, 1);
-        prim_man.insert(
-            ButtonPrimEnum::Square,
-            Shape::new_square(0, text_size.clone(), 0, style.background_color),
-            0,
-        );
-        text_size
+        self.set_text(text, size_for_clipping, style, prim_man)
     }
     fn set_text(
         &self,
@@ -57,28 +83,104 @@ impl ThemeForButton for DarkSimpleThemeForButton {
         style: Box<ThemeStyleForButton>,
         prim_man: &mut PrimitivesManagerForThemes,
     ) -> Vector2D<f32> {
-        assert!(
-            size_for_clipping.is_none(),
-            "theme_for_button:ThemeForButton:new"
-        );
         info!("DaskSimpleThemeForButton:set_text {}", text);
-        let prim_text = prim_man
-            .get_mut(ButtonPrimEnum::Text)
-            .expect("DarkSimpleThemeForButton:set_text get_mut");
-        let text_prim = (**prim_text)
-            .downcast_mut::<Text>()
-            .expect("DarkSimpleThemeForButton:set_text downcast_mut");
-        text_prim.set_text(text);
-        let text_size = text_prim.size().clone();
-        prim_man
-            .remove(ButtonPrimEnum::Square)
-            .expect("DarkSimpleThemeForButton:set_text remove");
-        prim_man.insert(
-            ButtonPrimEnum::Square,
-            Shape::new_square(0, text_size.clone(), 0, style.background_color),
-            0,
-        );
-        text_size
+        let size_for_clipping = prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let max_width = size_for_clipping
+            .as_ref()
+            .map_or(f32::INFINITY, |size| size.x());
+        let mut text_prim = Self::text_prim(text, max_width, &style);
+        let (size, content_position) =
+            Self::center(&text_prim.size().clone(), size_for_clipping.as_ref());
+        text_prim.set_position(content_position);
+        prim_man.insert(ButtonPrimEnum::Text, text_prim, 1);
+        prim_man.frame(size, &style.box_model, Some(&style.background_color))
+    }
+    fn new_icon_button(
+        &self,
+        icon: Arc<Mutex<dyn SoftTexture>>,
+        text: &str,
+        placement: IconPlacement,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForButton>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_icon_button(icon, text, placement, size_for_clipping, style, prim_man)
+    }
+    fn set_icon_button(
+        &self,
+        icon: Arc<Mutex<dyn SoftTexture>>,
+        text: &str,
+        placement: IconPlacement,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForButton>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        let size_for_clipping = prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let mut icon = Bitmap::from_texture(0, icon);
+        let (icon_width, icon_height) = icon.size().unpack();
+        let inner_width = size_for_clipping
+            .as_ref()
+            .map_or(f32::INFINITY, |size| size.x());
+        // The text gets what the icon leaves
+        let max_text_width = match placement {
+            IconPlacement::Left | IconPlacement::Right => inner_width - icon_width - style.spacing,
+            IconPlacement::Top | IconPlacement::Bottom => inner_width,
+        };
+        let mut text_prim =
+            (!text.is_empty()).then(|| Self::text_prim(text, max_text_width, &style));
+        let (text_width, text_height) = text_prim
+            .as_mut()
+            .map_or((0.0, 0.0), |text_prim| text_prim.size().unpack());
+        let gap = if text_prim.is_some() {
+            style.spacing
+        } else {
+            0.0
+        };
+        let size = match placement {
+            IconPlacement::Left | IconPlacement::Right => {
+                Vector2D::new(icon_width + gap + text_width, icon_height.max(text_height))
+            }
+            IconPlacement::Top | IconPlacement::Bottom => {
+                Vector2D::new(icon_width.max(text_width), icon_height + gap + text_height)
+            }
+        };
+        let (width, height) = size.unpack();
+        // Centered across the placement, one after the other along it
+        let (icon_position, text_position) = match placement {
+            IconPlacement::Left => (
+                (0.0, (height - icon_height) / 2.0),
+                (icon_width + gap, (height - text_height) / 2.0),
+            ),
+            IconPlacement::Right => (
+                (text_width + gap, (height - icon_height) / 2.0),
+                (0.0, (height - text_height) / 2.0),
+            ),
+            IconPlacement::Top => (
+                ((width - icon_width) / 2.0, 0.0),
+                ((width - text_width) / 2.0, icon_height + gap),
+            ),
+            IconPlacement::Bottom => (
+                ((width - icon_width) / 2.0, text_height + gap),
+                ((width - text_width) / 2.0, 0.0),
+            ),
+        };
+        let (size, content_position) = Self::center(&size, size_for_clipping.as_ref());
+        let (content_x, content_y) = content_position.unpack();
+        icon.set_position(Vector2D::new(
+            content_x + icon_position.0,
+            content_y + icon_position.1,
+        ));
+        prim_man.insert(ButtonPrimEnum::Icon, icon, 2);
+        if let Some(mut text_prim) = text_prim {
+            text_prim.set_position(Vector2D::new(
+                content_x + text_position.0,
+                content_y + text_position.1,
+            ));
+            prim_man.insert(ButtonPrimEnum::Text, text_prim, 1);
+        }
+        prim_man.frame(size, &style.box_model, Some(&style.background_color))
     }
 }
 
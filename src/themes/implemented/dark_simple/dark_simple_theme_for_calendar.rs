@@ -0,0 +1,196 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForCalendar, ThemeForWidget,
+    ThemeStyleForCalendar,
+};
+use crate::{
+    general::{ArrowDirection, Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{CalendarFrame, CalendarLayout},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum CalendarPrimEnum {
+    Background,
+    Previous,
+    Next,
+    Title,
+    Weekday(usize),
+    Cell(usize),
+    Day(usize),
+    Today(usize),
+}
+
+impl PrimEnum for CalendarPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            CalendarPrimEnum::Background => 0,
+            CalendarPrimEnum::Previous => 1,
+            CalendarPrimEnum::Next => 2,
+            CalendarPrimEnum::Title => 3,
+            CalendarPrimEnum::Weekday(index) => 4 + index,
+            CalendarPrimEnum::Cell(index) => 11 + index * 3,
+            CalendarPrimEnum::Day(index) => 12 + index * 3,
+            CalendarPrimEnum::Today(index) => 13 + index * 3,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForCalendar;
+
+impl ThemeForCalendar for DarkSimpleThemeForCalendar {
+    fn new_calendar(
+        &self,
+        frame: &CalendarFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForCalendar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> CalendarLayout {
+        self.set_calendar(frame, size_for_clipping, style, prim_man)
+    }
+    fn set_calendar(
+        &self,
+        frame: &CalendarFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForCalendar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> CalendarLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let spacing = style.spacing;
+        let new_text =
+            |text: &str, color| Text::new(0, text, style.font_size, style.font.clone(), color);
+        let text_height = new_text("0", style.color.clone()).size().y();
+        let row_height = text_height + spacing;
+        // Wide enough for two digits or two letters of the weekday
+        let cell_width = new_text("00", style.color.clone())
+            .size()
+            .x()
+            .max(new_text("Mo", style.color.clone()).size().x())
+            + spacing * 2.0;
+        let width = cell_width * 7.0;
+        let center_in_cell = |text: &mut Text, cell: &Rect<f32>| {
+            let (text_width, text_height) = text.size().unpack();
+            text.set_position(Vector2D::new(
+                cell.x() + (cell.width() - text_width) / 2.0,
+                cell.y() + (cell.height() - text_height) / 2.0,
+            ));
+        };
+
+        // Header, the month and the arrows to change it
+        let previous = Rect::new(0.0, 0.0, cell_width, row_height);
+        let next = Rect::new(width - cell_width, 0.0, cell_width, row_height);
+        let arrows = [
+            (
+                previous,
+                frame.can_go_previous,
+                ArrowDirection::Left,
+                CalendarPrimEnum::Previous,
+            ),
+            (
+                next,
+                frame.can_go_next,
+                ArrowDirection::Right,
+                CalendarPrimEnum::Next,
+            ),
+        ];
+        for (rect, enabled, direction, prim_enum) in arrows {
+            let color = if enabled {
+                style.color.clone()
+            } else {
+                style.disabled_color.clone()
+            };
+            let arrow_size = text_height / 2.0;
+            let mut arrow =
+                Shape::new_arrow(0, Vector2D::new(arrow_size, arrow_size), direction, color);
+            arrow.set_position(Vector2D::new(
+                rect.x() + (rect.width() - arrow_size) / 2.0,
+                rect.y() + (rect.height() - arrow_size) / 2.0,
+            ));
+            prim_man.insert(prim_enum, arrow, prim_enum.to_prim_id() as isize);
+        }
+        let mut title = new_text(&frame.title, style.color.clone());
+        center_in_cell(&mut title, &Rect::new(0.0, 0.0, width, row_height));
+        prim_man.insert(CalendarPrimEnum::Title, title, 3);
+
+        for (index, weekday) in frame.weekdays.iter().enumerate() {
+            let short: String = weekday.name().chars().take(2).collect();
+            let mut text = new_text(&short, style.disabled_color.clone());
+            let cell = Rect::new(
+                cell_width * index as f32,
+                row_height,
+                cell_width,
+                row_height,
+            );
+            center_in_cell(&mut text, &cell);
+            prim_man.insert(CalendarPrimEnum::Weekday(index), text, 4 + index as isize);
+        }
+
+        let grid_y = row_height * 2.0;
+        let mut days = Vec::with_capacity(frame.days.len());
+        for (index, day) in frame.days.iter().enumerate() {
+            let render_id = 11 + index as isize * 3;
+            let cell = Rect::new(
+                cell_width * (index % 7) as f32,
+                grid_y + row_height * (index / 7) as f32,
+                cell_width,
+                row_height,
+            );
+            if day.selected {
+                let mut selection = Shape::new_square(
+                    0,
+                    Vector2D::new(cell.width(), cell.height()),
+                    0,
+                    style.selection_color.clone(),
+                );
+                selection.set_position(Vector2D::new(cell.x(), cell.y()));
+                prim_man.insert(CalendarPrimEnum::Cell(index), selection, render_id);
+            }
+            let color = if day.in_month && day.enabled {
+                style.color.clone()
+            } else {
+                style.disabled_color.clone()
+            };
+            let mut text = new_text(&day.date.day().to_string(), color.clone());
+            center_in_cell(&mut text, &cell);
+            prim_man.insert(CalendarPrimEnum::Day(index), text, render_id + 1);
+            if day.today {
+                let mut underline = Shape::new_square(
+                    0,
+                    Vector2D::new(cell.width() - spacing * 2.0, 2.0),
+                    0,
+                    color,
+                );
+                underline.set_position(Vector2D::new(
+                    cell.x() + spacing,
+                    cell.y() + cell.height() - 3.0,
+                ));
+                prim_man.insert(CalendarPrimEnum::Today(index), underline, render_id + 2);
+            }
+            days.push(cell);
+        }
+        let rows = frame.days.len().div_ceil(7) as f32;
+        let height = grid_y + row_height * rows + spacing / 2.0;
+        let background = Shape::new_square(
+            0,
+            Vector2D::new(width, height),
+            0,
+            style.background_color.clone(),
+        );
+        prim_man.insert(CalendarPrimEnum::Background, background, 0);
+        CalendarLayout {
+            size: prim_man.frame(
+                Vector2D::new(width, height),
+                &style.box_model,
+                Some(&style.background_color),
+            ),
+            previous,
+            next,
+            days,
+        }
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForCalendar {}
@@ -0,0 +1,51 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForCanvas, ThemeForWidget,
+    ThemeStyleForCanvas,
+};
+use crate::{general::Vector2D, widgets::primitives::Shape};
+
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum CanvasPrimEnum {
+    Background,
+}
+
+impl PrimEnum for CanvasPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        self as PrimId
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForCanvas;
+
+impl ThemeForCanvas for DarkSimpleThemeForCanvas {
+    fn new_canvas(
+        &self,
+        size: Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForCanvas>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_canvas(size, size_for_clipping, style, prim_man)
+    }
+    fn set_canvas(
+        &self,
+        size: Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForCanvas>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        // Its size is the widget's, the border and padding included
+        let size = prim_man.clipped_size(style.box_model.content_size(&size));
+        let background = Shape::new_square(0, size.clone(), 0, style.background_color.clone());
+        prim_man.insert(CanvasPrimEnum::Background, background, 0);
+        prim_man.frame(size, &style.box_model, Some(&style.background_color))
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForCanvas {}
@@ -0,0 +1,359 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForColorPicker, ThemeForWidget,
+    ThemeStyleForColorPicker,
+};
+use crate::{
+    general::{Color, Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{ColorPickerFrame, ColorPickerLayout},
+};
+
+// Wide enough for any of the texts
+const FIELD_HINT: &str = "#00000000 ";
+
+#[derive(Clone, Copy, Debug)]
+enum ColorPickerPrimEnum {
+    Background,
+    SquareHue,
+    SquareShade,
+    SquareMarker,
+    SquareMarkerInner,
+    HueSegment(usize),
+    HueMarker,
+    AlphaBackground,
+    AlphaGradient,
+    AlphaMarker,
+    Preview,
+    FieldLabel(usize),
+    FieldBorder(usize),
+    FieldBox(usize),
+    FieldText(usize),
+    FieldCaret(usize),
+    Swatch(usize),
+}
+
+impl PrimEnum for ColorPickerPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        use ColorPickerPrimEnum::*;
+        match self {
+            Background => 0,
+            SquareHue => 1,
+            SquareShade => 2,
+            SquareMarker => 3,
+            SquareMarkerInner => 4,
+            HueSegment(index) => 5 + index,
+            HueMarker => 11,
+            AlphaBackground => 12,
+            AlphaGradient => 13,
+            AlphaMarker => 14,
+            Preview => 15,
+            FieldLabel(index) => 16 + index * 5,
+            FieldBorder(index) => 17 + index * 5,
+            FieldBox(index) => 18 + index * 5,
+            FieldText(index) => 19 + index * 5,
+            FieldCaret(index) => 20 + index * 5,
+            Swatch(index) => 100 + index,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForColorPicker;
+
+impl DarkSimpleThemeForColorPicker {
+    fn width_of(text: &str, style: &ThemeStyleForColorPicker) -> f32 {
+        Text::char_widths(text, style.font_size, style.font.clone())
+            .iter()
+            .sum()
+    }
+    /// The render id is the prim id, they are already in drawing order.
+    fn insert<P: Primitive>(
+        prim_man: &mut PrimitivesManagerForThemes,
+        prim_enum: ColorPickerPrimEnum,
+        mut prim: P,
+        rect: Rect<f32>,
+    ) {
+        prim.set_position(Vector2D::new(rect.x(), rect.y()));
+        prim_man.insert(prim_enum, prim, prim_enum.to_prim_id() as isize);
+    }
+    fn square(
+        prim_man: &mut PrimitivesManagerForThemes,
+        prim_enum: ColorPickerPrimEnum,
+        rect: Rect<f32>,
+        color: Color,
+    ) {
+        let shape = Shape::new_square(0, Vector2D::new(rect.width(), rect.height()), 0, color);
+        Self::insert(prim_man, prim_enum, shape, rect);
+    }
+    /// Colors go top left, bottom left, bottom right and top right.
+    fn gradient(
+        prim_man: &mut PrimitivesManagerForThemes,
+        prim_enum: ColorPickerPrimEnum,
+        rect: Rect<f32>,
+        colors: (Color, Color, Color, Color),
+    ) {
+        let shape =
+            Shape::new_square_with_colors(0, Vector2D::new(rect.width(), rect.height()), colors);
+        Self::insert(prim_man, prim_enum, shape, rect);
+    }
+}
+
+impl ThemeForColorPicker for DarkSimpleThemeForColorPicker {
+    fn new_color_picker(
+        &self,
+        frame: &ColorPickerFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForColorPicker>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> ColorPickerLayout {
+        self.set_color_picker(frame, size_for_clipping, style, prim_man)
+    }
+    fn set_color_picker(
+        &self,
+        frame: &ColorPickerFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForColorPicker>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> ColorPickerLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        use ColorPickerPrimEnum::*;
+        let (spacing, side, thickness) = (style.spacing, style.size, style.thickness);
+        let line_height = Text::line_height(style.font_size, style.font.clone());
+        let row_height = line_height + spacing;
+
+        let square = Rect::new(spacing, spacing, side, side);
+        let hue = Rect::new(square.right() + spacing, spacing, thickness, side);
+        let alpha = Rect::new(
+            spacing,
+            square.bottom() + spacing,
+            hue.right() - spacing,
+            thickness,
+        );
+        let labels_width = frame
+            .fields
+            .iter()
+            .map(|field| Self::width_of(&field.label, &style))
+            .fold(0.0, f32::max);
+        let column_x = hue.right() + spacing;
+        let box_x = column_x + labels_width + spacing;
+        let box_width = Self::width_of(FIELD_HINT, &style) + spacing;
+        let column_right = box_x + box_width;
+        let preview = Rect::new(column_x, spacing, column_right - column_x, row_height);
+        let fields: Vec<Rect<f32>> = (0..frame.fields.len())
+            .map(|index| {
+                let y = preview.bottom() + spacing + (row_height + spacing) * index as f32;
+                Rect::new(box_x, y, box_width, row_height)
+            })
+            .collect();
+        let width = column_right + spacing;
+        let per_row = (((width - spacing) / (thickness + spacing / 2.0)).floor() as usize).max(1);
+        let swatches: Vec<Rect<f32>> = (0..frame.swatches.len())
+            .map(|index| {
+                let (column, row) = (index % per_row, index / per_row);
+                Rect::new(
+                    spacing + (thickness + spacing / 2.0) * column as f32,
+                    alpha.bottom() + spacing + (thickness + spacing / 2.0) * row as f32,
+                    thickness,
+                    thickness,
+                )
+            })
+            .collect();
+        let bottom = swatches
+            .last()
+            .map_or(alpha.bottom(), |swatch| swatch.bottom())
+            .max(
+                fields
+                    .last()
+                    .map_or(preview.bottom(), |field| field.bottom()),
+            );
+        let size = prim_man.clipped_size(Vector2D::new(width, bottom + spacing));
+
+        Self::square(
+            prim_man,
+            Background,
+            Rect::new(0.0, 0.0, size.x(), size.y()),
+            style.background_color.clone(),
+        );
+
+        // White to the pure hue, then transparent to black on top of it
+        let pure = Color::from_hsv(frame.hue, 1.0, 1.0, 255);
+        let white = Color::new(255, 255, 255, 255);
+        let (clear, black) = (Color::new(0, 0, 0, 0), Color::new(0, 0, 0, 255));
+        Self::gradient(
+            prim_man,
+            SquareHue,
+            square,
+            (white.clone(), white, pure.clone(), pure),
+        );
+        Self::gradient(
+            prim_man,
+            SquareShade,
+            square,
+            (clear.clone(), black.clone(), black, clear),
+        );
+        let opaque = Color::from_hsv(frame.hue, frame.saturation, frame.value, 255);
+        let marker = Vector2D::new(
+            square.x() + square.width() * frame.saturation,
+            square.y() + square.height() * (1.0 - frame.value),
+        );
+        let marker_color = if frame.value > 0.5 {
+            Color::new(0, 0, 0, 255)
+        } else {
+            Color::new(255, 255, 255, 255)
+        };
+        Self::square(
+            prim_man,
+            SquareMarker,
+            Rect::new(marker.x() - 5.0, marker.y() - 5.0, 10.0, 10.0),
+            marker_color,
+        );
+        Self::square(
+            prim_man,
+            SquareMarkerInner,
+            Rect::new(marker.x() - 3.0, marker.y() - 3.0, 6.0, 6.0),
+            opaque.clone(),
+        );
+
+        // Red, yellow, green, cyan, blue, magenta and back to red
+        let segment_height = hue.height() / 6.0;
+        for segment in 0..6 {
+            let top = Color::from_hsv(segment as f32 * 60.0, 1.0, 1.0, 255);
+            let bottom = Color::from_hsv((segment + 1) as f32 * 60.0, 1.0, 1.0, 255);
+            Self::gradient(
+                prim_man,
+                HueSegment(segment),
+                Rect::new(
+                    hue.x(),
+                    hue.y() + segment_height * segment as f32,
+                    hue.width(),
+                    segment_height,
+                ),
+                (top.clone(), bottom.clone(), bottom, top),
+            );
+        }
+        let hue_y = hue.y() + hue.height() * frame.hue / 360.0;
+        Self::square(
+            prim_man,
+            HueMarker,
+            Rect::new(hue.x() - 2.0, hue_y - 1.5, hue.width() + 4.0, 3.0),
+            style.color.clone(),
+        );
+
+        Self::square(
+            prim_man,
+            AlphaBackground,
+            alpha,
+            Color::new(128, 128, 128, 255),
+        );
+        let transparent = Color::new(opaque.r(), opaque.g(), opaque.b(), 0);
+        Self::gradient(
+            prim_man,
+            AlphaGradient,
+            alpha,
+            (transparent.clone(), transparent, opaque.clone(), opaque),
+        );
+        let alpha_x = alpha.x() + alpha.width() * frame.alpha as f32 / 255.0;
+        Self::square(
+            prim_man,
+            AlphaMarker,
+            Rect::new(alpha_x - 1.5, alpha.y() - 2.0, 3.0, alpha.height() + 4.0),
+            style.color.clone(),
+        );
+
+        Self::square(
+            prim_man,
+            Preview,
+            preview,
+            Color::from_hsv(frame.hue, frame.saturation, frame.value, frame.alpha),
+        );
+
+        for (index, (field, rect)) in frame.fields.iter().zip(&fields).enumerate() {
+            let text_y = rect.y() + spacing / 2.0;
+            let label = Text::new(
+                0,
+                &field.label,
+                style.font_size,
+                style.font.clone(),
+                style.color.clone(),
+            );
+            Self::insert(
+                prim_man,
+                FieldLabel(index),
+                label,
+                Rect::new(column_x, text_y, 0.0, 0.0),
+            );
+            if field.caret.is_some() {
+                Self::square(
+                    prim_man,
+                    FieldBorder(index),
+                    *rect,
+                    style.selection_color.clone(),
+                );
+            }
+            Self::square(
+                prim_man,
+                FieldBox(index),
+                Rect::new(
+                    rect.x() + 1.0,
+                    rect.y() + 1.0,
+                    rect.width() - 2.0,
+                    rect.height() - 2.0,
+                ),
+                Color::new(0, 0, 0, 255),
+            );
+            let color = if field.valid {
+                style.color.clone()
+            } else {
+                style.error_color.clone()
+            };
+            let text_x = rect.x() + spacing / 2.0;
+            if !field.text.is_empty() {
+                let text = Text::new(
+                    0,
+                    &field.text,
+                    style.font_size,
+                    style.font.clone(),
+                    color.clone(),
+                );
+                Self::insert(
+                    prim_man,
+                    FieldText(index),
+                    text,
+                    Rect::new(text_x, text_y, 0.0, 0.0),
+                );
+            }
+            if let Some(caret) = field.caret {
+                let before: String = field.text.chars().take(caret).collect();
+                Self::square(
+                    prim_man,
+                    FieldCaret(index),
+                    Rect::new(
+                        text_x + Self::width_of(&before, &style),
+                        text_y,
+                        2.0,
+                        line_height,
+                    ),
+                    color,
+                );
+            }
+        }
+
+        for (index, (color, rect)) in frame.swatches.iter().zip(&swatches).enumerate() {
+            Self::square(prim_man, Swatch(index), *rect, color.clone());
+        }
+
+        ColorPickerLayout {
+            size: prim_man.frame(size, &style.box_model, Some(&style.background_color)),
+            square,
+            hue,
+            alpha,
+            fields,
+            swatches,
+        }
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForColorPicker {}
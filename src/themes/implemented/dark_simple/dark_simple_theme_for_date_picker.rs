@@ -0,0 +1,119 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForDatePicker, ThemeForWidget,
+    ThemeStyleForDatePicker,
+};
+use crate::{
+    general::{ArrowDirection, Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::DatePickerLayout,
+};
+
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum DatePickerPrimEnum {
+    Background,
+    Text,
+    Button,
+    Arrow,
+}
+
+impl PrimEnum for DatePickerPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        self as PrimId
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForDatePicker;
+
+impl ThemeForDatePicker for DarkSimpleThemeForDatePicker {
+    fn new_date_picker(
+        &self,
+        text: &str,
+        open: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForDatePicker>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> DatePickerLayout {
+        self.set_date_picker(text, open, size_for_clipping, style, prim_man)
+    }
+    fn set_date_picker(
+        &self,
+        text: &str,
+        open: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForDatePicker>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> DatePickerLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let spacing = style.spacing;
+        // Every date is as wide as this one with a monospaced font, and about it otherwise
+        let text_size = Text::new(
+            0,
+            "0000-00-00",
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        )
+        .size()
+        .clone();
+        let height = text_size.y() + spacing;
+        let button = Rect::new(spacing + text_size.x() + spacing, 0.0, height, height);
+        let width = button.x() + button.width();
+
+        let background = Shape::new_square(
+            0,
+            Vector2D::new(width, height),
+            0,
+            style.background_color.clone(),
+        );
+        prim_man.insert(DatePickerPrimEnum::Background, background, 0);
+        let mut text_prim = Text::new(
+            0,
+            text,
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        );
+        text_prim.set_position(Vector2D::new(spacing, spacing / 2.0));
+        prim_man.insert(DatePickerPrimEnum::Text, text_prim, 1);
+        let mut button_shape = Shape::new_square(
+            0,
+            Vector2D::new(button.width() - 2.0, button.height() - 2.0),
+            0,
+            style.selection_color.clone(),
+        );
+        button_shape.set_position(Vector2D::new(button.x() + 1.0, button.y() + 1.0));
+        prim_man.insert(DatePickerPrimEnum::Button, button_shape, 2);
+        let arrow_size = height / 3.0;
+        let direction = if open {
+            ArrowDirection::Up
+        } else {
+            ArrowDirection::Down
+        };
+        let mut arrow = Shape::new_arrow(
+            0,
+            Vector2D::new(arrow_size, arrow_size),
+            direction,
+            style.color.clone(),
+        );
+        arrow.set_position(Vector2D::new(
+            button.x() + (button.width() - arrow_size) / 2.0,
+            button.y() + (button.height() - arrow_size) / 2.0,
+        ));
+        prim_man.insert(DatePickerPrimEnum::Arrow, arrow, 3);
+        DatePickerLayout {
+            size: prim_man.frame(
+                Vector2D::new(width, height),
+                &style.box_model,
+                Some(&style.background_color),
+            ),
+            button,
+        }
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForDatePicker {}
@@ -0,0 +1,146 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForDialog, ThemeForWidget,
+    ThemeStyleForDialog,
+};
+use crate::{
+    general::{Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{DialogFrame, DialogLayout},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum DialogPrimEnum {
+    Overlay,
+    Panel,
+    Title,
+    Button(usize),
+    ButtonText(usize),
+}
+
+impl PrimEnum for DialogPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            DialogPrimEnum::Overlay => 0,
+            DialogPrimEnum::Panel => 1,
+            DialogPrimEnum::Title => 2,
+            DialogPrimEnum::Button(index) => 3 + index * 2,
+            DialogPrimEnum::ButtonText(index) => 4 + index * 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForDialog;
+
+impl ThemeForDialog for DarkSimpleThemeForDialog {
+    fn new_dialog(
+        &self,
+        frame: &DialogFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForDialog>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> DialogLayout {
+        self.set_dialog(frame, size_for_clipping, style, prim_man)
+    }
+    fn set_dialog(
+        &self,
+        frame: &DialogFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForDialog>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> DialogLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let spacing = style.spacing;
+        let mut title = Text::new(
+            0,
+            &frame.title,
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        );
+        let title_size = title.size().clone();
+        title.set_position(Vector2D::new(spacing, spacing));
+        let content_position = Vector2D::new(spacing, title_size.y() + spacing * 2.0);
+
+        let mut texts = Vec::with_capacity(frame.buttons.len());
+        let (mut buttons_width, mut buttons_height) = (0.0_f32, 0.0_f32);
+        for button in &frame.buttons {
+            let mut text = Text::new(
+                0,
+                &button.text,
+                style.font_size,
+                style.font.clone(),
+                style.color.clone(),
+            );
+            let text_size = text.size().clone();
+            buttons_width += text_size.x() + spacing * 3.0;
+            buttons_height = buttons_height.max(text_size.y() + spacing);
+            texts.push(text);
+        }
+        let width = (title_size.x() + spacing * 2.0)
+            .max(frame.content_size.x() + spacing * 2.0)
+            .max(buttons_width + spacing);
+        let buttons_y = content_position.y() + frame.content_size.y() + spacing;
+        let height = buttons_y + buttons_height + spacing;
+
+        let mut overlay = Shape::new_square(
+            0,
+            Vector2D::new(frame.dim_area.width(), frame.dim_area.height()),
+            0,
+            style.overlay_color.clone(),
+        );
+        // The content is moved inside the border and padding, the overlay stays where it is
+        let offset = prim_man.content_offset();
+        overlay.set_position(Vector2D::new(
+            frame.dim_area.x() - offset.x(),
+            frame.dim_area.y() - offset.y(),
+        ));
+        prim_man.insert(DialogPrimEnum::Overlay, overlay, 0);
+        let panel = Shape::new_square(
+            0,
+            Vector2D::new(width, height),
+            0,
+            style.background_color.clone(),
+        );
+        prim_man.insert(DialogPrimEnum::Panel, panel, 1);
+        prim_man.insert(DialogPrimEnum::Title, title, 2);
+
+        // Buttons are aligned to the right, like most desktop dialogs
+        let mut layout = DialogLayout {
+            size: prim_man.frame(
+                Vector2D::new(width, height),
+                &style.box_model,
+                Some(&style.background_color),
+            ),
+            content_position,
+            buttons: Vec::with_capacity(texts.len()),
+        };
+        let mut x = width - buttons_width;
+        for (index, mut text) in texts.into_iter().enumerate() {
+            let render_id = 3 + index as isize * 2;
+            let text_size = text.size().clone();
+            let button_width = text_size.x() + spacing * 2.0;
+            let color = if frame.buttons[index].focused {
+                style.selection_color.clone()
+            } else {
+                style.button_color.clone()
+            };
+            let mut button =
+                Shape::new_square(0, Vector2D::new(button_width, buttons_height), 0, color);
+            button.set_position(Vector2D::new(x, buttons_y));
+            prim_man.insert(DialogPrimEnum::Button(index), button, render_id);
+            text.set_position(Vector2D::new(x + spacing, buttons_y + spacing / 2.0));
+            prim_man.insert(DialogPrimEnum::ButtonText(index), text, render_id + 1);
+            layout
+                .buttons
+                .push(Rect::new(x, buttons_y, button_width, buttons_height));
+            x += button_width + spacing;
+        }
+        layout
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForDialog {}
@@ -0,0 +1,51 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForFramebuffer, ThemeForWidget,
+    ThemeStyleForFramebuffer,
+};
+use crate::{general::Vector2D, widgets::primitives::Shape};
+
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum FramebufferPrimEnum {
+    Background,
+}
+
+impl PrimEnum for FramebufferPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        self as PrimId
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForFramebuffer;
+
+impl ThemeForFramebuffer for DarkSimpleThemeForFramebuffer {
+    fn new_framebuffer(
+        &self,
+        size: Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForFramebuffer>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_framebuffer(size, size_for_clipping, style, prim_man)
+    }
+    fn set_framebuffer(
+        &self,
+        size: Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForFramebuffer>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        // Its size is the widget's, the border and padding included
+        let size = prim_man.clipped_size(style.box_model.content_size(&size));
+        let background = Shape::new_square(0, size.clone(), 0, style.background_color.clone());
+        prim_man.insert(FramebufferPrimEnum::Background, background, 0);
+        prim_man.frame(size, &style.box_model, Some(&style.background_color))
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForFramebuffer {}
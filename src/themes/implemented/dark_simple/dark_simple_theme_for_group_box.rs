@@ -0,0 +1,131 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForGroupBox, ThemeForWidget,
+    ThemeStyleForGroupBox,
+};
+use crate::{
+    general::{Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::GroupBoxLayout,
+};
+
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum GroupBoxPrimEnum {
+    TopBeforeTitle,
+    TopAfterTitle,
+    Left,
+    Right,
+    Bottom,
+    Title,
+}
+
+impl PrimEnum for GroupBoxPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        self as PrimId
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForGroupBox;
+
+impl ThemeForGroupBox for DarkSimpleThemeForGroupBox {
+    fn new_group_box(
+        &self,
+        title: &str,
+        content_size: &Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForGroupBox>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> GroupBoxLayout {
+        self.set_group_box(title, content_size, size_for_clipping, style, prim_man)
+    }
+    fn set_group_box(
+        &self,
+        title: &str,
+        content_size: &Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForGroupBox>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> GroupBoxLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let (spacing, thickness) = (style.spacing, style.thickness);
+        let title_height = Text::line_height(style.font_size, style.font.clone());
+        let title_width: f32 = Text::char_widths(title, style.font_size, style.font.clone())
+            .iter()
+            .sum();
+        // The title sits on the top border, a bit in from the left corner
+        let title_x = thickness + spacing * 2.0;
+        let border_y = (title_height - thickness) / 2.0;
+        let content = Rect::new(
+            thickness + spacing,
+            title_height + spacing,
+            content_size.x(),
+            content_size.y(),
+        );
+        let width = (content.right() + spacing + thickness).max(title_x + title_width + title_x);
+        let height = content.bottom() + spacing + thickness;
+
+        let title_gap = if title.is_empty() { 0.0 } else { spacing / 2.0 };
+        let sides = [
+            (
+                GroupBoxPrimEnum::TopBeforeTitle,
+                Rect::new(0.0, border_y, title_x - title_gap, thickness),
+            ),
+            (
+                GroupBoxPrimEnum::TopAfterTitle,
+                Rect::new(
+                    title_x + title_width + title_gap,
+                    border_y,
+                    width - (title_x + title_width + title_gap),
+                    thickness,
+                ),
+            ),
+            (
+                GroupBoxPrimEnum::Left,
+                Rect::new(0.0, border_y, thickness, height - border_y),
+            ),
+            (
+                GroupBoxPrimEnum::Right,
+                Rect::new(width - thickness, border_y, thickness, height - border_y),
+            ),
+            (
+                GroupBoxPrimEnum::Bottom,
+                Rect::new(0.0, height - thickness, width, thickness),
+            ),
+        ];
+        for (prim_enum, rect) in sides {
+            let mut side = Shape::new_square(
+                0,
+                Vector2D::new(rect.width(), rect.height()),
+                0,
+                style.color.clone(),
+            );
+            side.set_position(Vector2D::new(rect.x(), rect.y()));
+            prim_man.insert(prim_enum, side, prim_enum.to_prim_id() as isize);
+        }
+        if !title.is_empty() {
+            let mut text = Text::new(
+                0,
+                title,
+                style.font_size,
+                style.font.clone(),
+                style.color.clone(),
+            );
+            text.set_position(Vector2D::new(title_x, 0.0));
+            prim_man.insert(
+                GroupBoxPrimEnum::Title,
+                text,
+                GroupBoxPrimEnum::Title.to_prim_id() as isize,
+            );
+        }
+        GroupBoxLayout {
+            size: prim_man.frame(Vector2D::new(width, height), &style.box_model, None),
+            content,
+        }
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForGroupBox {}
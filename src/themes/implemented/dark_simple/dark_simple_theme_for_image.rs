@@ -29,17 +29,14 @@ impl ThemeForImage for DarkSimpleThemeForImage {
         &self,
         path: Box<Path>,
         size_for_clipping: Option<Vector2D<f32>>,
-        _style: Box<ThemeStyleForImage>,
+        style: Box<ThemeStyleForImage>,
         prim_man: &mut PrimitivesManagerForThemes,
     ) -> Vector2D<f32> {
-        assert!(
-            size_for_clipping.is_none(),
-            "theme_for_button:ThemeForImage:new size_for_clipping not supported yet."
-        );
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
         let mut bitmap = Bitmap::from_bmp(0, path).expect(stringify!(ThemeForImage));
-        let size = bitmap.size().clone();
+        let size = prim_man.clipped_size(bitmap.size().clone());
         prim_man.insert(ImagePrimEnum::Image, bitmap, 0);
-        size
+        prim_man.frame(size, &style.box_model, None)
     }
 }
 
@@ -0,0 +1,184 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForLabel, ThemeForWidget, ThemeStyleForLabel,
+};
+use crate::{
+    general::{TextAlign, Vector2D},
+    widgets::primitives::{Primitive, Text},
+};
+
+const ELLIPSIS: &str = Text::ELLIPSIS;
+
+#[derive(Clone, Copy, Debug)]
+enum LabelPrimEnum {
+    // A whole line, or a word of it when justified
+    Piece(usize),
+}
+
+impl PrimEnum for LabelPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            LabelPrimEnum::Piece(index) => index,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LabelLine {
+    text: String,
+    width: f32,
+    // Justify leaves the last line of a paragraph and cut lines alone
+    justify: bool,
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForLabel;
+
+impl DarkSimpleThemeForLabel {
+    fn width_of(text: &str, style: &ThemeStyleForLabel) -> f32 {
+        Text::char_widths(text, style.font_size, style.font.clone())
+            .iter()
+            .sum()
+    }
+    fn ellipsize(text: &str, max_width: f32, style: &ThemeStyleForLabel) -> String {
+        Text::ellipsize(text, max_width, style.font_size, style.font.clone())
+    }
+    fn lines(
+        text: &str,
+        wrap: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: &ThemeStyleForLabel,
+    ) -> Vec<LabelLine> {
+        let max_width = size_for_clipping.as_ref().map(|size| size.x());
+        let mut lines = vec![];
+        for paragraph in text.split('\n') {
+            let widths = Text::char_widths(paragraph, style.font_size, style.font.clone());
+            let ranges = match max_width {
+                Some(max_width) if wrap => Text::wrap(paragraph, &widths, max_width),
+                _ => std::iter::once(0..widths.len()).collect(),
+            };
+            let last = ranges.len() - 1;
+            for (index, range) in ranges.into_iter().enumerate() {
+                let line: String = paragraph
+                    .chars()
+                    .skip(range.start)
+                    .take(range.len())
+                    .collect();
+                let line = line.trim_end().to_string();
+                let mut width = Self::width_of(&line, style);
+                let mut justify = index != last;
+                let line = match max_width {
+                    Some(max_width) if width > max_width => {
+                        let cut = Self::ellipsize(&line, max_width, style);
+                        width = Self::width_of(&cut, style);
+                        justify = false;
+                        cut
+                    }
+                    _ => line,
+                };
+                lines.push(LabelLine {
+                    text: line,
+                    width,
+                    justify,
+                });
+            }
+        }
+        let line_height = Text::line_height(style.font_size, style.font.clone());
+        if let Some(size) = &size_for_clipping {
+            // At least one line is shown, even if it doesn't fit
+            let max_lines = (((size.y() + style.spacing) / (line_height + style.spacing)).floor()
+                as usize)
+                .max(1);
+            if lines.len() > max_lines {
+                lines.truncate(max_lines);
+                let last = &mut lines[max_lines - 1];
+                let with_ellipsis = format!("{}{}", last.text, ELLIPSIS);
+                last.text = if Self::width_of(&with_ellipsis, style) <= size.x() {
+                    with_ellipsis
+                } else {
+                    Self::ellipsize(&last.text, size.x(), style)
+                };
+                last.width = Self::width_of(&last.text, style);
+                last.justify = false;
+            }
+        }
+        lines
+    }
+}
+
+impl ThemeForLabel for DarkSimpleThemeForLabel {
+    fn new_label(
+        &self,
+        text: &str,
+        align: TextAlign,
+        wrap: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForLabel>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_label(text, align, wrap, size_for_clipping, style, prim_man)
+    }
+    fn set_label(
+        &self,
+        text: &str,
+        align: TextAlign,
+        wrap: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForLabel>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        let size_for_clipping = prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let lines = Self::lines(text, wrap, size_for_clipping.clone(), &style);
+        let line_height = Text::line_height(style.font_size, style.font.clone());
+        let width = size_for_clipping.map_or_else(
+            || lines.iter().map(|line| line.width).fold(0.0, f32::max),
+            |size| size.x(),
+        );
+        let mut pieces = 0;
+        let mut add_piece = |text: &str, x: f32, y: f32| {
+            let mut text = Text::new(
+                0,
+                text,
+                style.font_size,
+                style.font.clone(),
+                style.color.clone(),
+            );
+            text.set_position(Vector2D::new(x, y));
+            prim_man.insert(LabelPrimEnum::Piece(pieces), text, pieces as isize);
+            pieces += 1;
+        };
+        for (index, line) in lines.iter().enumerate() {
+            if line.text.is_empty() {
+                continue;
+            }
+            let y = (line_height + style.spacing) * index as f32;
+            let words: Vec<&str> = line.text.split_whitespace().collect();
+            if align == TextAlign::Justify && line.justify && words.len() > 1 {
+                let words_width: f32 = words.iter().map(|word| Self::width_of(word, &style)).sum();
+                let gap = (width - words_width) / (words.len() - 1) as f32;
+                let mut x = 0.0;
+                for word in words {
+                    add_piece(word, x, y);
+                    x += Self::width_of(word, &style) + gap;
+                }
+                continue;
+            }
+            let x = match align {
+                TextAlign::Left | TextAlign::Justify => 0.0,
+                TextAlign::Center => ((width - line.width) / 2.0).max(0.0),
+                TextAlign::Right => (width - line.width).max(0.0),
+            };
+            add_piece(&line.text, x, y);
+        }
+        let height = lines.len() as f32 * (line_height + style.spacing) - style.spacing;
+        prim_man.frame(
+            Vector2D::new(width, height.max(0.0)),
+            &style.box_model,
+            None,
+        )
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForLabel {}
@@ -0,0 +1,241 @@
+use std::fmt::Debug;
+
+use glyph_brush::ab_glyph::FontArc;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForMenu, ThemeForWidget, ThemeStyleForMenu,
+};
+use crate::{
+    general::{ArrowDirection, Color, Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{MenuLayout, MenuRow},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum MenuPrimEnum {
+    Background,
+    Highlight,
+    Check(usize),
+    Label(usize),
+    Mnemonic(usize),
+    Accelerator(usize),
+    Arrow(usize),
+    Separator(usize),
+}
+
+impl PrimEnum for MenuPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            MenuPrimEnum::Background => 0,
+            MenuPrimEnum::Highlight => 1,
+            MenuPrimEnum::Check(row) => 2 + row * 6,
+            MenuPrimEnum::Label(row) => 3 + row * 6,
+            MenuPrimEnum::Mnemonic(row) => 4 + row * 6,
+            MenuPrimEnum::Accelerator(row) => 5 + row * 6,
+            MenuPrimEnum::Arrow(row) => 6 + row * 6,
+            MenuPrimEnum::Separator(row) => 7 + row * 6,
+        }
+    }
+}
+
+/// Where the mnemonic letter is within the text, as x and width.
+pub(super) fn mnemonic_span(
+    text: &str,
+    mnemonic: usize,
+    font_size: f32,
+    font: FontArc,
+    color: Color,
+) -> Option<(f32, f32)> {
+    let width_of = |chars: usize| -> f32 {
+        let prefix: String = text.chars().take(chars).collect();
+        if prefix.is_empty() {
+            return 0.0;
+        }
+        Text::new(0, &prefix, font_size, font.clone(), color.clone())
+            .size()
+            .x()
+    };
+    if mnemonic >= text.chars().count() {
+        return None;
+    }
+    let x = width_of(mnemonic);
+    Some((x, width_of(mnemonic + 1) - x))
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForMenu;
+
+impl ThemeForMenu for DarkSimpleThemeForMenu {
+    fn new_menu(
+        &self,
+        rows: &[MenuRow],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForMenu>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> MenuLayout {
+        self.set_menu(rows, size_for_clipping, style, prim_man)
+    }
+    fn set_menu(
+        &self,
+        rows: &[MenuRow],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForMenu>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> MenuLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let spacing = style.spacing;
+        let text_height = Text::new(
+            0,
+            "M",
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        )
+        .size()
+        .y();
+        let row_height = text_height + spacing;
+        // Columns: check mark, label, accelerator and submenu arrow
+        let check_width = text_height;
+        let arrow_width = text_height / 2.0;
+        let mut labels = Vec::with_capacity(rows.len());
+        let mut accelerators = Vec::with_capacity(rows.len());
+        let (mut label_width, mut accelerator_width) = (0.0_f32, 0.0_f32);
+        for row in rows {
+            let color = if row.enabled {
+                style.color.clone()
+            } else {
+                style.disabled_color.clone()
+            };
+            let mut label = (!row.separator && !row.text.is_empty()).then(|| {
+                Text::new(
+                    0,
+                    &row.text,
+                    style.font_size,
+                    style.font.clone(),
+                    color.clone(),
+                )
+            });
+            let mut accelerator = row
+                .accelerator
+                .as_ref()
+                .filter(|accelerator| !accelerator.is_empty())
+                .map(|accelerator| {
+                    Text::new(0, accelerator, style.font_size, style.font.clone(), color)
+                });
+            if let Some(label) = &mut label {
+                label_width = label_width.max(label.size().x());
+            }
+            if let Some(accelerator) = &mut accelerator {
+                accelerator_width = accelerator_width.max(accelerator.size().x());
+            }
+            labels.push(label);
+            accelerators.push(accelerator);
+        }
+        let label_x = spacing + check_width + spacing;
+        let accelerator_x = label_x + label_width + spacing * 4.0;
+        let width = accelerator_x + accelerator_width + spacing + arrow_width + spacing;
+
+        let mut layout = MenuLayout {
+            size: Vector2D::new(0.0, 0.0),
+            rows: Vec::with_capacity(rows.len()),
+        };
+        let mut y = spacing / 2.0;
+        for (index, row) in rows.iter().enumerate() {
+            let render_id = 2 + index as isize * 6;
+            if row.separator {
+                let mut line = Shape::new_square(
+                    0,
+                    Vector2D::new(width - spacing * 2.0, 1.0),
+                    0,
+                    style.disabled_color.clone(),
+                );
+                line.set_position(Vector2D::new(spacing, y + spacing / 2.0));
+                prim_man.insert(MenuPrimEnum::Separator(index), line, render_id + 5);
+                layout.rows.push(Rect::new(0.0, y, width, spacing));
+                y += spacing;
+                continue;
+            }
+            if row.highlighted {
+                let mut highlight = Shape::new_square(
+                    0,
+                    Vector2D::new(width, row_height),
+                    0,
+                    style.selection_color.clone(),
+                );
+                highlight.set_position(Vector2D::new(0.0, y));
+                prim_man.insert(MenuPrimEnum::Highlight, highlight, 1);
+            }
+            let color = if row.enabled {
+                style.color.clone()
+            } else {
+                style.disabled_color.clone()
+            };
+            let text_y = y + spacing / 2.0;
+            if row.checked == Some(true) {
+                let mark_size = check_width / 2.0;
+                let mut mark =
+                    Shape::new_square(0, Vector2D::new(mark_size, mark_size), 0, color.clone());
+                mark.set_position(Vector2D::new(
+                    spacing + mark_size / 2.0,
+                    text_y + (text_height - mark_size) / 2.0,
+                ));
+                prim_man.insert(MenuPrimEnum::Check(index), mark, render_id);
+            }
+            if let Some(mut label) = labels[index].take() {
+                label.set_position(Vector2D::new(label_x, text_y));
+                prim_man.insert(MenuPrimEnum::Label(index), label, render_id + 1);
+            }
+            let span = row.mnemonic.and_then(|mnemonic| {
+                mnemonic_span(
+                    &row.text,
+                    mnemonic,
+                    style.font_size,
+                    style.font.clone(),
+                    color.clone(),
+                )
+            });
+            if let Some((x, underline_width)) = span {
+                let mut underline =
+                    Shape::new_square(0, Vector2D::new(underline_width, 1.0), 0, color.clone());
+                underline.set_position(Vector2D::new(label_x + x, text_y + text_height - 2.0));
+                prim_man.insert(MenuPrimEnum::Mnemonic(index), underline, render_id + 2);
+            }
+            if let Some(mut accelerator) = accelerators[index].take() {
+                accelerator.set_position(Vector2D::new(accelerator_x, text_y));
+                prim_man.insert(MenuPrimEnum::Accelerator(index), accelerator, render_id + 3);
+            }
+            if row.submenu {
+                let mut arrow = Shape::new_arrow(
+                    0,
+                    Vector2D::new(arrow_width, arrow_width),
+                    ArrowDirection::Right,
+                    color,
+                );
+                arrow.set_position(Vector2D::new(
+                    width - spacing - arrow_width,
+                    text_y + (text_height - arrow_width) / 2.0,
+                ));
+                prim_man.insert(MenuPrimEnum::Arrow(index), arrow, render_id + 4);
+            }
+            layout.rows.push(Rect::new(0.0, y, width, row_height));
+            y += row_height;
+        }
+        let height = y + spacing / 2.0;
+        let background = Shape::new_square(
+            0,
+            Vector2D::new(width, height),
+            0,
+            style.background_color.clone(),
+        );
+        prim_man.insert(MenuPrimEnum::Background, background, 0);
+        layout.size = prim_man.frame(
+            Vector2D::new(width, height),
+            &style.box_model,
+            Some(&style.background_color),
+        );
+        layout
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForMenu {}
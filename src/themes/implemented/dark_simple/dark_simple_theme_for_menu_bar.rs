@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForMenuBar, ThemeForWidget,
+    ThemeStyleForMenuBar,
+};
+use crate::{
+    general::{Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{MenuBarLayout, MenuBarTitle},
+};
+
+use super::dark_simple_theme_for_menu::mnemonic_span;
+
+#[derive(Clone, Copy, Debug)]
+enum MenuBarPrimEnum {
+    Background,
+    Open,
+    Title(usize),
+    Mnemonic(usize),
+}
+
+impl PrimEnum for MenuBarPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            MenuBarPrimEnum::Background => 0,
+            MenuBarPrimEnum::Open => 1,
+            MenuBarPrimEnum::Title(index) => 2 + index * 2,
+            MenuBarPrimEnum::Mnemonic(index) => 3 + index * 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForMenuBar;
+
+impl ThemeForMenuBar for DarkSimpleThemeForMenuBar {
+    fn new_menu_bar(
+        &self,
+        titles: &[MenuBarTitle],
+        width: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForMenuBar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> MenuBarLayout {
+        self.set_menu_bar(titles, width, size_for_clipping, style, prim_man)
+    }
+    fn set_menu_bar(
+        &self,
+        titles: &[MenuBarTitle],
+        width: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForMenuBar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> MenuBarLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let spacing = style.spacing;
+        let text_height = Text::new(
+            0,
+            "M",
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        )
+        .size()
+        .y();
+        let height = text_height + spacing;
+        let mut layout = MenuBarLayout {
+            size: Vector2D::new(0.0, height),
+            titles: Vec::with_capacity(titles.len()),
+        };
+        let mut x = 0.0;
+        for (index, title) in titles.iter().enumerate() {
+            let render_id = 2 + index as isize * 2;
+            let color = if title.enabled {
+                style.color.clone()
+            } else {
+                style.disabled_color.clone()
+            };
+            let mut text = Text::new(
+                0,
+                &title.text,
+                style.font_size,
+                style.font.clone(),
+                color.clone(),
+            );
+            let title_width = text.size().x() + spacing * 2.0;
+            if title.open {
+                let mut open = Shape::new_square(
+                    0,
+                    Vector2D::new(title_width, height),
+                    0,
+                    style.selection_color.clone(),
+                );
+                open.set_position(Vector2D::new(x, 0.0));
+                prim_man.insert(MenuBarPrimEnum::Open, open, 1);
+            }
+            text.set_position(Vector2D::new(x + spacing, spacing / 2.0));
+            prim_man.insert(MenuBarPrimEnum::Title(index), text, render_id);
+            let span = title.mnemonic.and_then(|mnemonic| {
+                mnemonic_span(
+                    &title.text,
+                    mnemonic,
+                    style.font_size,
+                    style.font.clone(),
+                    color.clone(),
+                )
+            });
+            if let Some((mnemonic_x, mnemonic_width)) = span {
+                let mut underline =
+                    Shape::new_square(0, Vector2D::new(mnemonic_width, 1.0), 0, color);
+                underline.set_position(Vector2D::new(
+                    x + spacing + mnemonic_x,
+                    spacing / 2.0 + text_height - 2.0,
+                ));
+                prim_man.insert(MenuBarPrimEnum::Mnemonic(index), underline, render_id + 1);
+            }
+            layout.titles.push(Rect::new(x, 0.0, title_width, height));
+            x += title_width;
+        }
+        // As wide as the window, or as the titles before it's placed in one
+        let width = width.max(x);
+        let background = Shape::new_square(
+            0,
+            Vector2D::new(width, height),
+            0,
+            style.background_color.clone(),
+        );
+        prim_man.insert(MenuBarPrimEnum::Background, background, 0);
+        layout.size = prim_man.frame(
+            Vector2D::new(width, height),
+            &style.box_model,
+            Some(&style.background_color),
+        );
+        layout
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForMenuBar {}
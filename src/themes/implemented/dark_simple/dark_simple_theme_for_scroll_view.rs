@@ -0,0 +1,75 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForScrollView, ThemeForWidget,
+    ThemeStyleForScrollView,
+};
+use crate::{
+    general::Vector2D,
+    widgets::primitives::{Primitive, Shape},
+    widgets::ScrollBar,
+};
+
+#[derive(Clone, Copy, Debug)]
+enum ScrollViewPrimEnum {
+    Track(usize),
+    Thumb(usize),
+}
+
+impl PrimEnum for ScrollViewPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            ScrollViewPrimEnum::Track(index) => index * 2,
+            ScrollViewPrimEnum::Thumb(index) => index * 2 + 1,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForScrollView;
+
+impl ThemeForScrollView for DarkSimpleThemeForScrollView {
+    fn new_scroll_view(
+        &self,
+        bars: &[ScrollBar],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForScrollView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) {
+        self.set_scroll_bars(bars, size_for_clipping, style, prim_man)
+    }
+    fn set_scroll_bars(
+        &self,
+        bars: &[ScrollBar],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForScrollView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) {
+        let content_size = prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        for (index, bar) in bars.iter().enumerate() {
+            let render_id = index as isize * 2;
+            let mut track = Shape::new_square(
+                0,
+                Vector2D::new(bar.track.width(), bar.track.height()),
+                0,
+                style.background_color.clone(),
+            );
+            track.set_position(Vector2D::new(bar.track.x(), bar.track.y()));
+            prim_man.insert(ScrollViewPrimEnum::Track(index), track, render_id);
+            let mut thumb = Shape::new_square(
+                0,
+                Vector2D::new(bar.thumb.width(), bar.thumb.height()),
+                0,
+                style.color.clone(),
+            );
+            thumb.set_position(Vector2D::new(bar.thumb.x(), bar.thumb.y()));
+            prim_man.insert(ScrollViewPrimEnum::Thumb(index), thumb, render_id + 1);
+        }
+        if let Some(content_size) = content_size {
+            prim_man.frame(content_size, &style.box_model, None);
+        }
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForScrollView {}
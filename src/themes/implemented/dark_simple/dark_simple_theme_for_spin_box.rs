@@ -0,0 +1,165 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForSpinBox, ThemeForWidget,
+    ThemeStyleForSpinBox,
+};
+use crate::{
+    general::{ArrowDirection, Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{SpinBoxFrame, SpinBoxLayout},
+};
+
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum SpinBoxPrimEnum {
+    Background,
+    Text,
+    Caret,
+    UpButton,
+    UpArrow,
+    DownButton,
+    DownArrow,
+}
+
+impl PrimEnum for SpinBoxPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        self as PrimId
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForSpinBox;
+
+impl ThemeForSpinBox for DarkSimpleThemeForSpinBox {
+    fn new_spin_box(
+        &self,
+        frame: &SpinBoxFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForSpinBox>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> SpinBoxLayout {
+        self.set_spin_box(frame, size_for_clipping, style, prim_man)
+    }
+    fn set_spin_box(
+        &self,
+        frame: &SpinBoxFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForSpinBox>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> SpinBoxLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let spacing = style.spacing;
+        let width_of = |text: &str| -> f32 {
+            if text.is_empty() {
+                return 0.0;
+            }
+            Text::new(
+                0,
+                text,
+                style.font_size,
+                style.font.clone(),
+                style.color.clone(),
+            )
+            .size()
+            .x()
+        };
+        let text_height = Text::new(
+            0,
+            "0",
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        )
+        .size()
+        .y();
+        // Wide enough for the widest value, so it doesn't jump while typing
+        let text_width = width_of(&frame.text).max(width_of(&frame.width_hint));
+        let height = text_height + spacing;
+        let button_width = height * 0.75;
+        let buttons_x = spacing + text_width + spacing;
+        let width = buttons_x + button_width;
+
+        let background = Shape::new_square(
+            0,
+            Vector2D::new(width, height),
+            0,
+            style.background_color.clone(),
+        );
+        prim_man.insert(SpinBoxPrimEnum::Background, background, 0);
+        let color = if frame.valid {
+            style.color.clone()
+        } else {
+            style.error_color.clone()
+        };
+        if !frame.text.is_empty() {
+            let mut text = Text::new(
+                0,
+                &frame.text,
+                style.font_size,
+                style.font.clone(),
+                color.clone(),
+            );
+            text.set_position(Vector2D::new(spacing, spacing / 2.0));
+            prim_man.insert(SpinBoxPrimEnum::Text, text, 1);
+        }
+        if let Some(caret) = frame.caret {
+            let before: String = frame.text.chars().take(caret).collect();
+            let mut caret = Shape::new_square(0, Vector2D::new(2.0, text_height), 0, color);
+            caret.set_position(Vector2D::new(spacing + width_of(&before), spacing / 2.0));
+            prim_man.insert(SpinBoxPrimEnum::Caret, caret, 2);
+        }
+
+        let up = Rect::new(buttons_x, 0.0, button_width, height / 2.0);
+        let down = Rect::new(buttons_x, height / 2.0, button_width, height / 2.0);
+        let buttons = [
+            (
+                up,
+                ArrowDirection::Up,
+                SpinBoxPrimEnum::UpButton,
+                SpinBoxPrimEnum::UpArrow,
+            ),
+            (
+                down,
+                ArrowDirection::Down,
+                SpinBoxPrimEnum::DownButton,
+                SpinBoxPrimEnum::DownArrow,
+            ),
+        ];
+        for (index, (rect, direction, button_enum, arrow_enum)) in buttons.into_iter().enumerate() {
+            let render_id = 3 + index as isize * 2;
+            let mut button = Shape::new_square(
+                0,
+                Vector2D::new(rect.width() - 1.0, rect.height() - 1.0),
+                0,
+                style.selection_color.clone(),
+            );
+            button.set_position(Vector2D::new(rect.x() + 1.0, rect.y() + 1.0));
+            prim_man.insert(button_enum, button, render_id);
+            let arrow_size = rect.height() / 2.0;
+            let mut arrow = Shape::new_arrow(
+                0,
+                Vector2D::new(arrow_size, arrow_size),
+                direction,
+                style.color.clone(),
+            );
+            arrow.set_position(Vector2D::new(
+                rect.x() + (rect.width() - arrow_size) / 2.0,
+                rect.y() + (rect.height() - arrow_size) / 2.0,
+            ));
+            prim_man.insert(arrow_enum, arrow, render_id + 1);
+        }
+        SpinBoxLayout {
+            size: prim_man.frame(
+                Vector2D::new(width, height),
+                &style.box_model,
+                Some(&style.background_color),
+            ),
+            up,
+            down,
+        }
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForSpinBox {}
@@ -0,0 +1,103 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForSplitter, ThemeForWidget,
+    ThemeStyleForSplitter,
+};
+use crate::{
+    general::{Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape},
+    widgets::Direction,
+};
+
+const GRIP_DOTS: usize = 3;
+
+#[derive(Clone, Copy, Debug)]
+enum SplitterPrimEnum {
+    Divider(usize),
+    Grip(usize, usize),
+}
+
+impl PrimEnum for SplitterPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            SplitterPrimEnum::Divider(index) => index * (GRIP_DOTS + 1),
+            SplitterPrimEnum::Grip(index, dot) => index * (GRIP_DOTS + 1) + 1 + dot,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForSplitter;
+
+impl ThemeForSplitter for DarkSimpleThemeForSplitter {
+    fn new_splitter(
+        &self,
+        direction: Direction,
+        dividers: &[Rect<f32>],
+        dragged: Option<usize>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForSplitter>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) {
+        self.set_splitter(
+            direction,
+            dividers,
+            dragged,
+            size_for_clipping,
+            style,
+            prim_man,
+        )
+    }
+    fn set_splitter(
+        &self,
+        direction: Direction,
+        dividers: &[Rect<f32>],
+        dragged: Option<usize>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForSplitter>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) {
+        let content_size = prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        for (index, divider) in dividers.iter().enumerate() {
+            let color = if dragged == Some(index) {
+                style.selection_color.clone()
+            } else {
+                style.background_color.clone()
+            };
+            let mut shape = Shape::new_square(
+                0,
+                Vector2D::new(divider.width(), divider.height()),
+                0,
+                color,
+            );
+            shape.set_position(Vector2D::new(divider.x(), divider.y()));
+            let prim_enum = SplitterPrimEnum::Divider(index);
+            prim_man.insert(prim_enum, shape, prim_enum.to_prim_id() as isize);
+            // A few dots in the middle, so it looks like something to grab
+            let dot = (style.thickness / 3.0).max(1.0);
+            let (center_x, center_y) = (
+                divider.x() + divider.width() / 2.0,
+                divider.y() + divider.height() / 2.0,
+            );
+            for index_dot in 0..GRIP_DOTS {
+                let offset = (index_dot as f32 - (GRIP_DOTS / 2) as f32) * dot * 2.0;
+                let (x, y) = match direction {
+                    Direction::Horizontal => (center_x, center_y + offset),
+                    Direction::Vertical => (center_x + offset, center_y),
+                };
+                let mut grip =
+                    Shape::new_square(0, Vector2D::new(dot, dot), 0, style.color.clone());
+                grip.set_position(Vector2D::new(x - dot / 2.0, y - dot / 2.0));
+                let prim_enum = SplitterPrimEnum::Grip(index, index_dot);
+                prim_man.insert(prim_enum, grip, prim_enum.to_prim_id() as isize);
+            }
+        }
+        if let Some(content_size) = content_size {
+            prim_man.frame(content_size, &style.box_model, None);
+        }
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForSplitter {}
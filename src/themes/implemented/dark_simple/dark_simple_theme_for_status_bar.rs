@@ -0,0 +1,115 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForStatusBar, ThemeForWidget,
+    ThemeStyleForStatusBar,
+};
+use crate::{
+    general::Vector2D,
+    widgets::primitives::{Primitive, Shape, Text},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum StatusBarPrimEnum {
+    Background,
+    Text,
+    Section(usize),
+    Divider(usize),
+}
+
+impl PrimEnum for StatusBarPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            StatusBarPrimEnum::Background => 0,
+            StatusBarPrimEnum::Text => 1,
+            StatusBarPrimEnum::Section(index) => 2 + index * 2,
+            StatusBarPrimEnum::Divider(index) => 3 + index * 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForStatusBar;
+
+impl ThemeForStatusBar for DarkSimpleThemeForStatusBar {
+    fn new_status_bar(
+        &self,
+        text: &str,
+        sections: &[String],
+        width: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForStatusBar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_status_bar(text, sections, width, size_for_clipping, style, prim_man)
+    }
+    fn set_status_bar(
+        &self,
+        text: &str,
+        sections: &[String],
+        width: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForStatusBar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let spacing = style.spacing;
+        let text_height = Text::new(
+            0,
+            "M",
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        )
+        .size()
+        .y();
+        let height = text_height + spacing;
+        let mut text_width = 0.0;
+        if !text.is_empty() {
+            let mut text_prim = Text::new(
+                0,
+                text,
+                style.font_size,
+                style.font.clone(),
+                style.color.clone(),
+            );
+            text_width = text_prim.size().x() + spacing * 2.0;
+            text_prim.set_position(Vector2D::new(spacing, spacing / 2.0));
+            prim_man.insert(StatusBarPrimEnum::Text, text_prim, 1);
+        }
+        // Sections go from the right border to the left, the first one is the rightmost
+        let mut right = width;
+        for (index, section) in sections.iter().enumerate() {
+            let render_id = 2 + index as isize * 2;
+            if !section.is_empty() {
+                let mut section_prim = Text::new(
+                    0,
+                    section,
+                    style.font_size,
+                    style.font.clone(),
+                    style.color.clone(),
+                );
+                right -= section_prim.size().x() + spacing;
+                section_prim.set_position(Vector2D::new(right, spacing / 2.0));
+                prim_man.insert(StatusBarPrimEnum::Section(index), section_prim, render_id);
+            }
+            right -= spacing;
+            let mut divider =
+                Shape::new_square(0, Vector2D::new(1.0, text_height), 0, style.color.clone());
+            divider.set_position(Vector2D::new(right, spacing / 2.0));
+            prim_man.insert(StatusBarPrimEnum::Divider(index), divider, render_id + 1);
+        }
+        // As wide as the window, or as the contents before it's placed in one
+        let width = width.max(text_width + (width - right));
+        let size = prim_man.clipped_size(Vector2D::new(width, height));
+        prim_man.insert(
+            StatusBarPrimEnum::Background,
+            Shape::new_square(0, size.clone(), 0, style.background_color.clone()),
+            0,
+        );
+        prim_man.frame(size, &style.box_model, Some(&style.background_color))
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForStatusBar {}
@@ -0,0 +1,115 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForTabView, ThemeForWidget,
+    ThemeStyleForTabView,
+};
+use crate::{
+    general::{Rect, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{TabStripLayout, TabViewTab},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum TabViewPrimEnum {
+    Tab(usize),
+    Title(usize),
+    Close(usize),
+}
+
+impl PrimEnum for TabViewPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            TabViewPrimEnum::Tab(index) => index * 3,
+            TabViewPrimEnum::Title(index) => index * 3 + 1,
+            TabViewPrimEnum::Close(index) => index * 3 + 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForTabView;
+
+impl ThemeForTabView for DarkSimpleThemeForTabView {
+    fn new_tab_view(
+        &self,
+        tabs: &[TabViewTab],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTabView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> TabStripLayout {
+        self.set_tabs(tabs, size_for_clipping, style, prim_man)
+    }
+    fn set_tabs(
+        &self,
+        tabs: &[TabViewTab],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTabView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> TabStripLayout {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let mut layout = TabStripLayout::default();
+        let mut tab_x = 0.0;
+        let mut titles = Vec::with_capacity(tabs.len());
+        let mut closes = Vec::with_capacity(tabs.len());
+        let mut height: f32 = 0.0;
+        for tab in tabs {
+            let mut title = Text::new(
+                0,
+                &tab.title,
+                style.font_size,
+                style.font.clone(),
+                style.color.clone(),
+            );
+            height = height.max(title.size().y());
+            titles.push(title);
+            let close = tab.closable.then(|| {
+                Text::new(
+                    0,
+                    "x",
+                    style.font_size,
+                    style.font.clone(),
+                    style.color.clone(),
+                )
+            });
+            closes.push(close);
+        }
+        for (index, (mut title, close)) in titles.into_iter().zip(closes).enumerate() {
+            let render_id = index as isize * 3;
+            let mut x = tab_x + style.spacing;
+            let title_size = title.size().clone();
+            title.set_position(Vector2D::new(x, (height - title_size.y()) / 2.0));
+            prim_man.insert(TabViewPrimEnum::Title(index), title, render_id + 1);
+            x += title_size.x() + style.spacing;
+            let close_rect = close.map(|mut close| {
+                let close_size = close.size().clone();
+                close.set_position(Vector2D::new(x, (height - close_size.y()) / 2.0));
+                prim_man.insert(TabViewPrimEnum::Close(index), close, render_id + 2);
+                let rect = Rect::new(x, 0.0, close_size.x(), height);
+                x += close_size.x() + style.spacing;
+                rect
+            });
+            let color = if tabs[index].active {
+                style.selection_color.clone()
+            } else {
+                style.background_color.clone()
+            };
+            let mut tab = Shape::new_square(0, Vector2D::new(x - tab_x, height), 0, color);
+            tab.set_position(Vector2D::new(tab_x, 0.0));
+            prim_man.insert(TabViewPrimEnum::Tab(index), tab, render_id);
+            layout.tabs.push(Rect::new(tab_x, 0.0, x - tab_x, height));
+            layout.close_buttons.push(close_rect);
+            // A thin gap so inactive tabs don't merge
+            tab_x = x + 1.0;
+        }
+        layout.size = prim_man.frame(
+            Vector2D::new(tab_x, height),
+            &style.box_model,
+            Some(&style.background_color),
+        );
+        layout
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForTabView {}
@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForTextArea, ThemeForWidget,
+    ThemeStyleForTextArea,
+};
+use crate::{
+    general::Vector2D,
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::TextAreaFrame,
+};
+
+// Rows get their ids from their keys, above anything else
+const ROW_BASE: usize = 1 << 20;
+
+#[derive(Clone, Copy, Debug)]
+enum TextAreaPrimEnum {
+    Background,
+    ScrollThumb,
+    Caret,
+    Selection(usize),
+    Row(usize),
+}
+
+impl PrimEnum for TextAreaPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            TextAreaPrimEnum::Background => 0,
+            TextAreaPrimEnum::ScrollThumb => 1,
+            TextAreaPrimEnum::Caret => 2,
+            TextAreaPrimEnum::Selection(index) => 3 + index,
+            TextAreaPrimEnum::Row(key) => ROW_BASE + key,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForTextArea;
+
+impl ThemeForTextArea for DarkSimpleThemeForTextArea {
+    fn new_text_area(
+        &self,
+        frame: &TextAreaFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTextArea>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_text_area(frame, size_for_clipping, style, prim_man)
+    }
+    fn set_text_area(
+        &self,
+        frame: &TextAreaFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTextArea>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        // Only the rows that are still shown are kept, so they aren't rasterized again
+        let rows: HashSet<PrimId> = frame
+            .rows
+            .iter()
+            .map(|row| TextAreaPrimEnum::Row(row.key).to_prim_id())
+            .collect();
+        prim_man.retain(|prim_id| rows.contains(&prim_id));
+        for row in &frame.rows {
+            let prim_enum = TextAreaPrimEnum::Row(row.key);
+            if row.text.is_empty() {
+                continue;
+            }
+            if !prim_man.contains(prim_enum) {
+                let text = Text::new(
+                    0,
+                    &row.text,
+                    style.font_size,
+                    style.font.clone(),
+                    style.color.clone(),
+                );
+                prim_man.insert(prim_enum, text, prim_enum.to_prim_id() as isize);
+            }
+            let text = prim_man
+                .get_mut(prim_enum)
+                .expect("DarkSimpleThemeForTextArea:set_text_area get_mut");
+            if text.x() != row.position.x() || text.y() != row.position.y() {
+                text.set_position(row.position.clone());
+            }
+        }
+
+        let background =
+            Shape::new_square(0, frame.size.clone(), 0, style.background_color.clone());
+        prim_man.insert(TextAreaPrimEnum::Background, background, 0);
+        for (index, rect) in frame.selection.iter().enumerate() {
+            let mut selection = Shape::new_square(
+                0,
+                Vector2D::new(rect.width(), rect.height()),
+                0,
+                style.selection_color.clone(),
+            );
+            selection.set_position(Vector2D::new(rect.x(), rect.y()));
+            prim_man.insert(
+                TextAreaPrimEnum::Selection(index),
+                selection,
+                1 + index as isize,
+            );
+        }
+        if let Some(rect) = &frame.caret {
+            let mut caret = Shape::new_square(
+                0,
+                Vector2D::new(rect.width(), rect.height()),
+                0,
+                style.color.clone(),
+            );
+            caret.set_position(Vector2D::new(rect.x(), rect.y()));
+            prim_man.insert(TextAreaPrimEnum::Caret, caret, isize::MAX - 1);
+        }
+        if let Some(rect) = &frame.scroll_thumb {
+            let mut thumb = Shape::new_square(
+                0,
+                Vector2D::new(rect.width(), rect.height()),
+                (style.thickness / 2.0) as i32,
+                style.selection_color.clone(),
+            );
+            thumb.set_position(Vector2D::new(rect.x(), rect.y()));
+            prim_man.insert(TextAreaPrimEnum::ScrollThumb, thumb, isize::MAX);
+        }
+        prim_man.frame(
+            frame.size.clone(),
+            &style.box_model,
+            Some(&style.background_color),
+        )
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForTextArea {}
@@ -35,27 +35,28 @@ impl ThemeForTextBox for DarkSimpleThemeForTextBox {
         style: Box<ThemeStyleForTextBox>,
         prim_man: &mut PrimitivesManagerForThemes,
     ) -> Vector2D<f32> {
-        assert!(
-            size_for_clipping.is_none(),
-            "theme_for_button:ThemeForTextBox:new size_for_clipping not supported yet."
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        let mut text_prim = Text::new(
+            0,
+            text,
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
         );
-        let mut text_prim = Text::new(0, text, style.font_size, style.font, style.color);
         let text_size = text_prim.size().clone();
         prim_man.insert(TextBoxPrimEnum::Text, text_prim, 0);
-        text_size
+        // Whatever is longer is clipped
+        prim_man.frame(text_size, &style.box_model, None)
     }
     fn set_text(
         &self,
         text: &str,
         size_for_clipping: Option<Vector2D<f32>>,
-        _style: Box<ThemeStyleForTextBox>,
+        style: Box<ThemeStyleForTextBox>,
         prim_man: &mut PrimitivesManagerForThemes,
     ) -> Vector2D<f32> {
-        assert!(
-            size_for_clipping.is_none(),
-            "theme_for_button:ThemeForTextBox:new"
-        );
         info!("DaskSimpleThemeForTextBox:set_text {}", text);
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
         let prim_text = prim_man
             .get_mut(TextBoxPrimEnum::Text)
             .expect("DarkSimpleThemeForTextBox:set_text get_mut");
@@ -63,7 +64,8 @@ impl ThemeForTextBox for DarkSimpleThemeForTextBox {
             .downcast_mut::<Text>()
             .expect("DarkSimpleThemeForTextBox:set_text downcast_mut");
         text_prim.set_text(text);
-        text_prim.size().clone()
+        let text_size = text_prim.size().clone();
+        prim_man.frame(text_size, &style.box_model, None)
     }
 }
 
@@ -0,0 +1,61 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForToast, ThemeForWidget, ThemeStyleForToast,
+};
+use crate::{
+    general::Vector2D,
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::ToastLevel,
+};
+
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum ToastPrimEnum {
+    Background,
+    Text,
+}
+
+impl PrimEnum for ToastPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        self as PrimId
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForToast;
+
+impl ThemeForToast for DarkSimpleThemeForToast {
+    fn new_toast(
+        &self,
+        text: &str,
+        level: ToastLevel,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForToast>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        let background_color = match level {
+            ToastLevel::Info => style.info_color,
+            ToastLevel::Success => style.success_color,
+            ToastLevel::Warning => style.warning_color,
+            ToastLevel::Error => style.error_color,
+        };
+        let mut text_prim = Text::new(0, text, style.font_size, style.font, style.color);
+        let text_size = text_prim.size().clone();
+        text_prim.set_position(Vector2D::new(style.spacing, style.spacing));
+        let size = prim_man.clipped_size(Vector2D::new(
+            text_size.x() + style.spacing * 2.0,
+            text_size.y() + style.spacing * 2.0,
+        ));
+        prim_man.insert(
+            ToastPrimEnum::Background,
+            Shape::new_square(0, size.clone(), 0, background_color.clone()),
+            0,
+        );
+        prim_man.insert(ToastPrimEnum::Text, text_prim, 1);
+        prim_man.frame(size, &style.box_model, Some(&background_color))
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForToast {}
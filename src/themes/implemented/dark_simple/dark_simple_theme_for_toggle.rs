@@ -0,0 +1,121 @@
+use std::f32::consts::PI;
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForToggle, ThemeForWidget,
+    ThemeStyleForToggle,
+};
+use crate::{
+    general::{Color, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+};
+
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum TogglePrimEnum {
+    TrackLeft,
+    TrackMiddle,
+    TrackRight,
+    Thumb,
+    Text,
+}
+
+impl PrimEnum for TogglePrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        self as PrimId
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForToggle;
+
+impl DarkSimpleThemeForToggle {
+    fn circle(center_x: f32, center_y: f32, radius: f32, color: Color) -> Shape {
+        let points: Vec<Vector2D<f32>> = (0..24)
+            .map(|side| {
+                let angle = 2.0 * PI * side as f32 / 24.0;
+                Vector2D::new(
+                    center_x + radius * angle.cos(),
+                    center_y + radius * angle.sin(),
+                )
+            })
+            .collect();
+        Shape::new_convex(0, &points, color)
+    }
+    fn mix(from: &Color, to: &Color, amount: f32) -> Color {
+        let channel = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * amount) as u8;
+        Color::new(
+            channel(from.r(), to.r()),
+            channel(from.g(), to.g()),
+            channel(from.b(), to.b()),
+            channel(from.a(), to.a()),
+        )
+    }
+}
+
+impl ThemeForToggle for DarkSimpleThemeForToggle {
+    fn new_toggle(
+        &self,
+        text: &str,
+        position: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForToggle>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_toggle(text, position, size_for_clipping, style, prim_man)
+    }
+    fn set_toggle(
+        &self,
+        text: &str,
+        position: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForToggle>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let height = Text::line_height(style.font_size, style.font.clone());
+        let radius = height / 2.0;
+        let position = position.clamp(0.0, 1.0);
+
+        // A pill, two half circles joined by a square
+        let track_color = Self::mix(&style.background_color, &style.selection_color, position);
+        let left = Self::circle(radius, radius, radius, track_color.clone());
+        prim_man.insert(TogglePrimEnum::TrackLeft, left, 0);
+        let mut middle =
+            Shape::new_square(0, Vector2D::new(height, height), 0, track_color.clone());
+        middle.set_position(Vector2D::new(radius, 0.0));
+        prim_man.insert(TogglePrimEnum::TrackMiddle, middle, 1);
+        let right = Self::circle(radius + height, radius, radius, track_color);
+        prim_man.insert(TogglePrimEnum::TrackRight, right, 2);
+        let thumb = Self::circle(
+            radius + height * position,
+            radius,
+            radius - 3.0,
+            style.color.clone(),
+        );
+        prim_man.insert(TogglePrimEnum::Thumb, thumb, 3);
+
+        let track_width = height * 2.0;
+        if text.is_empty() {
+            return prim_man.frame(Vector2D::new(track_width, height), &style.box_model, None);
+        }
+        let mut text = Text::new(
+            0,
+            text,
+            style.font_size,
+            style.font.clone(),
+            style.color.clone(),
+        );
+        let text_width = text.size().x();
+        text.set_position(Vector2D::new(track_width + style.spacing, 0.0));
+        prim_man.insert(TogglePrimEnum::Text, text, 4);
+        prim_man.frame(
+            Vector2D::new(track_width + style.spacing + text_width, height),
+            &style.box_model,
+            None,
+        )
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForToggle {}
@@ -0,0 +1,64 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForTooltip, ThemeForWidget,
+    ThemeStyleForTooltip,
+};
+use crate::{
+    general::Vector2D,
+    widgets::primitives::{Primitive, Shape, Text},
+};
+
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum TooltipPrimEnum {
+    Background,
+    Text,
+}
+
+impl PrimEnum for TooltipPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        self as PrimId
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForTooltip;
+
+impl ThemeForTooltip for DarkSimpleThemeForTooltip {
+    fn new_tooltip(
+        &self,
+        text: &str,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTooltip>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_text(text, size_for_clipping, style, prim_man)
+    }
+    fn set_text(
+        &self,
+        text: &str,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTooltip>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let mut text_prim = Text::new(0, text, style.font_size, style.font, style.color);
+        let text_size = text_prim.size().clone();
+        text_prim.set_position(Vector2D::new(style.spacing, style.spacing));
+        let size = prim_man.clipped_size(Vector2D::new(
+            text_size.x() + style.spacing * 2.0,
+            text_size.y() + style.spacing * 2.0,
+        ));
+        prim_man.insert(
+            TooltipPrimEnum::Background,
+            Shape::new_square(0, size.clone(), 0, style.background_color.clone()),
+            0,
+        );
+        prim_man.insert(TooltipPrimEnum::Text, text_prim, 1);
+        prim_man.frame(size, &style.box_model, Some(&style.background_color))
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForTooltip {}
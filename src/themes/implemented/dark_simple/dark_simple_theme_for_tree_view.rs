@@ -0,0 +1,120 @@
+use std::fmt::Debug;
+
+use crate::themes::{
+    PrimEnum, PrimId, PrimitivesManagerForThemes, ThemeForTreeView, ThemeForWidget,
+    ThemeStyleForTreeView,
+};
+use crate::{
+    general::{ArrowDirection, Vector2D},
+    widgets::primitives::{Primitive, Shape, Text},
+    widgets::{TreeViewExpander, TreeViewRow},
+};
+
+#[derive(Clone, Copy, Debug)]
+enum TreeViewPrimEnum {
+    Background,
+    Selection,
+    Expander(usize),
+    Label(usize),
+}
+
+impl PrimEnum for TreeViewPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        match self {
+            TreeViewPrimEnum::Background => 0,
+            TreeViewPrimEnum::Selection => 1,
+            TreeViewPrimEnum::Expander(row) => 2 + row * 2,
+            TreeViewPrimEnum::Label(row) => 3 + row * 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DarkSimpleThemeForTreeView;
+
+impl ThemeForTreeView for DarkSimpleThemeForTreeView {
+    fn new_tree_view(
+        &self,
+        rows: &[TreeViewRow],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTreeView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        self.set_rows(rows, size_for_clipping, style, prim_man)
+    }
+    fn set_rows(
+        &self,
+        rows: &[TreeViewRow],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTreeView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32> {
+        prim_man.set_framed_clip_size(size_for_clipping, &style.box_model);
+        prim_man.clear();
+        let row_height = style.row_height;
+        let arrow_size = row_height / 2.0;
+        let mut width: f32 = 0.0;
+        let mut selected_y = None;
+        for (index, row) in rows.iter().enumerate() {
+            let x = row.depth as f32 * style.indent;
+            let y = index as f32 * row_height;
+            let render_id = 2 + 2 * index as isize;
+            if row.selected {
+                selected_y = Some(y);
+            }
+            let direction = match row.expander {
+                TreeViewExpander::Collapsed => Some(ArrowDirection::Right),
+                TreeViewExpander::Expanded => Some(ArrowDirection::Down),
+                TreeViewExpander::None => None,
+            };
+            if let Some(direction) = direction {
+                let mut arrow = Shape::new_arrow(
+                    0,
+                    Vector2D::new(arrow_size, arrow_size),
+                    direction,
+                    style.color.clone(),
+                );
+                arrow.set_position(Vector2D::new(x + arrow_size / 2.0, y + arrow_size / 2.0));
+                prim_man.insert(TreeViewPrimEnum::Expander(index), arrow, render_id);
+            }
+            let label_x = x + row_height;
+            width = width.max(label_x);
+            if row.label.is_empty() {
+                continue;
+            }
+            let mut text = Text::new(
+                0,
+                &row.label,
+                style.font_size,
+                style.font.clone(),
+                style.color.clone(),
+            );
+            let text_size = text.size().clone();
+            text.set_position(Vector2D::new(
+                label_x,
+                y + (row_height - text_size.y()) / 2.0,
+            ));
+            width = width.max(label_x + text_size.x());
+            prim_man.insert(TreeViewPrimEnum::Label(index), text, render_id + 1);
+        }
+        let size = prim_man.clipped_size(Vector2D::new(width, rows.len() as f32 * row_height));
+        if let Some(y) = selected_y {
+            let mut selection = Shape::new_square(
+                0,
+                Vector2D::new(width, row_height),
+                0,
+                style.selection_color.clone(),
+            );
+            selection.set_position(Vector2D::new(0.0, y));
+            prim_man.insert(TreeViewPrimEnum::Selection, selection, 1);
+        }
+        prim_man.insert(
+            TreeViewPrimEnum::Background,
+            Shape::new_square(0, size.clone(), 0, style.background_color.clone()),
+            0,
+        );
+        prim_man.frame(size, &style.box_model, Some(&style.background_color))
+    }
+}
+
+impl ThemeForWidget for DarkSimpleThemeForTreeView {}
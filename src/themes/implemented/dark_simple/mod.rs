@@ -1,11 +1,53 @@
 pub use dark_simple_style::*;
 pub use dark_simple_theme::*;
+pub use dark_simple_theme_for_accordion::*;
 pub use dark_simple_theme_for_button::*;
+pub use dark_simple_theme_for_calendar::*;
+pub use dark_simple_theme_for_canvas::*;
+pub use dark_simple_theme_for_color_picker::*;
+pub use dark_simple_theme_for_date_picker::*;
+pub use dark_simple_theme_for_dialog::*;
+pub use dark_simple_theme_for_framebuffer::*;
+pub use dark_simple_theme_for_group_box::*;
 pub use dark_simple_theme_for_image::*;
+pub use dark_simple_theme_for_label::*;
+pub use dark_simple_theme_for_menu::*;
+pub use dark_simple_theme_for_menu_bar::*;
+pub use dark_simple_theme_for_scroll_view::*;
+pub use dark_simple_theme_for_spin_box::*;
+pub use dark_simple_theme_for_splitter::*;
+pub use dark_simple_theme_for_status_bar::*;
+pub use dark_simple_theme_for_tab_view::*;
+pub use dark_simple_theme_for_text_area::*;
 pub use dark_simple_theme_for_text_box::*;
+pub use dark_simple_theme_for_toast::*;
+pub use dark_simple_theme_for_toggle::*;
+pub use dark_simple_theme_for_tooltip::*;
+pub use dark_simple_theme_for_tree_view::*;
 
 mod dark_simple_style;
 mod dark_simple_theme;
+mod dark_simple_theme_for_accordion;
 mod dark_simple_theme_for_button;
+mod dark_simple_theme_for_calendar;
+mod dark_simple_theme_for_canvas;
+mod dark_simple_theme_for_color_picker;
+mod dark_simple_theme_for_date_picker;
+mod dark_simple_theme_for_dialog;
+mod dark_simple_theme_for_framebuffer;
+mod dark_simple_theme_for_group_box;
 mod dark_simple_theme_for_image;
+mod dark_simple_theme_for_label;
+mod dark_simple_theme_for_menu;
+mod dark_simple_theme_for_menu_bar;
+mod dark_simple_theme_for_scroll_view;
+mod dark_simple_theme_for_spin_box;
+mod dark_simple_theme_for_splitter;
+mod dark_simple_theme_for_status_bar;
+mod dark_simple_theme_for_tab_view;
+mod dark_simple_theme_for_text_area;
 mod dark_simple_theme_for_text_box;
+mod dark_simple_theme_for_toast;
+mod dark_simple_theme_for_toggle;
+mod dark_simple_theme_for_tooltip;
+mod dark_simple_theme_for_tree_view;
@@ -3,7 +3,9 @@ use std::fmt::Debug;
 
 use std::collections::btree_map::IterMut;
 
-use crate::widgets::primitives::Primitive;
+use crate::general::{Color, Edges, Vector2D};
+use crate::themes::BoxModel;
+use crate::widgets::primitives::{Primitive, Shape};
 
 pub type PrimId = usize;
 
@@ -18,6 +20,10 @@ type PrimManPidRid = HashMap<PrimId, isize>;
 pub struct PrimitivesManagerForThemes {
     prims: Prims,
     pid_rid: PrimManPidRid,
+    clip_size: Option<Vector2D<f32>>,
+    // The border and the background behind the padding, around the rest, see frame()
+    frame: Vec<Box<dyn Primitive>>,
+    inset: Edges,
 }
 
 impl PrimitivesManagerForThemes {
@@ -26,8 +32,90 @@ impl PrimitivesManagerForThemes {
         PrimitivesManagerForThemes {
             prims: Default::default(),
             pid_rid: Default::default(),
+            clip_size: None,
+            frame: vec![],
+            inset: Default::default(),
         }
     }
+    /// What is outside of it isn't rendered, it's kept by clear().
+    pub fn set_clip_size(&mut self, clip_size: Option<Vector2D<f32>>) {
+        self.clip_size = clip_size;
+    }
+    pub fn clip_size(&self) -> Option<&Vector2D<f32>> {
+        self.clip_size.as_ref()
+    }
+    /// What a theme returns as the widget's size, the clip size when it was given one.
+    pub fn clipped_size(&self, size: Vector2D<f32>) -> Vector2D<f32> {
+        self.clip_size.clone().unwrap_or(size)
+    }
+    /// Like set_clip_size(), but for what goes inside the border and padding of the box model.
+    /// Returns the size_for_clipping to build the content with, at the origin.
+    pub fn set_framed_clip_size(
+        &mut self,
+        size_for_clipping: Option<Vector2D<f32>>,
+        box_model: &BoxModel,
+    ) -> Option<Vector2D<f32>> {
+        self.inset = box_model.inset();
+        let content_size = size_for_clipping.map(|size| box_model.content_size(&size));
+        self.set_clip_size(content_size.clone());
+        content_size
+    }
+    /// Draws the border and the background behind the padding around the content, which is
+    /// moved inside them when the geometry is built. Returns the size of the widget, with the
+    /// content of the clip size if there is one, see clipped_size().
+    pub fn frame(
+        &mut self,
+        content_size: Vector2D<f32>,
+        box_model: &BoxModel,
+        background_color: Option<&Color>,
+    ) -> Vector2D<f32> {
+        self.frame.clear();
+        let content_size = self.clipped_size(content_size);
+        let border = &box_model.border_width;
+        let width = content_size.x() + self.inset.horizontal();
+        let height = content_size.y() + self.inset.vertical();
+        if let Some(color) = background_color {
+            let mut background = Shape::new_square(
+                0,
+                Vector2D::new(width - border.horizontal(), height - border.vertical()),
+                0,
+                color.clone(),
+            );
+            background.set_position(Vector2D::new(border.left, border.top));
+            self.frame.push(Box::new(background));
+        }
+        let sides = [
+            (0.0, 0.0, width, border.top),
+            (width - border.right, 0.0, border.right, height),
+            (0.0, height - border.bottom, width, border.bottom),
+            (0.0, 0.0, border.left, height),
+        ];
+        for (x, y, side_width, side_height) in sides {
+            if side_width <= 0.0 || side_height <= 0.0 {
+                continue;
+            }
+            let mut side = Shape::new_square(
+                0,
+                Vector2D::new(side_width, side_height),
+                0,
+                box_model.border_color.clone(),
+            );
+            side.set_position(Vector2D::new(x, y));
+            self.frame.push(Box::new(side));
+        }
+        Vector2D::new(width, height)
+    }
+    /// The border and padding of the last frame().
+    pub fn inset(&self) -> &Edges {
+        &self.inset
+    }
+    /// Where the content is drawn, relative to the widget, past the border and padding.
+    pub fn content_offset(&self) -> Vector2D<f32> {
+        Vector2D::new(self.inset.left, self.inset.top)
+    }
+    pub fn frame_iter_mut(&mut self) -> std::slice::IterMut<'_, Box<dyn Primitive>> {
+        self.frame.iter_mut()
+    }
     pub fn insert<K: PrimEnum, V: Primitive>(&mut self, prim_enum: K, prim: V, render_id: isize) {
         self.pid_rid.insert(prim_enum.to_prim_id(), render_id);
         self.prims.insert(render_id, Box::new(prim));
@@ -46,6 +134,24 @@ impl PrimitivesManagerForThemes {
             .unwrap_or_else(|| panic!("{} {:?}", Self::NOT_FOUND, prim_enum));
         self.prims.remove(rid).map(|p| (*rid, p))
     }
+    pub fn contains<K: PrimEnum>(&self, prim_enum: K) -> bool {
+        self.pid_rid.contains_key(&prim_enum.to_prim_id())
+    }
+    /// Removes the primitives whose prim id doesn't pass, to keep the rest as they are.
+    pub fn retain<F: FnMut(PrimId) -> bool>(&mut self, mut keep: F) {
+        let prims = &mut self.prims;
+        self.pid_rid.retain(|pid, rid| {
+            let kept = keep(*pid);
+            if !kept {
+                prims.remove(rid);
+            }
+            kept
+        });
+    }
+    pub fn clear(&mut self) {
+        self.prims.clear();
+        self.pid_rid.clear();
+    }
     pub fn iter_mut(&mut self) -> IterMut<'_, isize, Box<dyn Primitive>> {
         self.prims.iter_mut()
     }
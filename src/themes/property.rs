@@ -1,6 +1,6 @@
 use std::any::TypeId;
 
-use crate::general::{Color, Vector2D};
+use crate::general::{Color, Edges, Vector2D};
 use crate::themes::ExtraStyle;
 use crate::themes::ExtraStyleMap;
 use glyph_brush::ab_glyph::FontArc;
@@ -14,6 +14,7 @@ pub enum Property {
     Col(Color),
     Usize(usize),
     Vec2D(Vector2D<f32>),
+    Edges(Edges),
     ApplyTo(ApplyTo),
     ExtraProperties(ExtraStyleMap),
 }
@@ -67,6 +68,12 @@ impl From<Vector2D<f32>> for Property {
     }
 }
 
+impl From<Edges> for Property {
+    fn from(value: Edges) -> Self {
+        Property::Edges(value)
+    }
+}
+
 impl From<&Option<(f32, f32)>> for Property {
     fn from(value: &Option<(f32, f32)>) -> Self {
         match value {
@@ -154,6 +161,17 @@ impl TryInto<Vector2D<f32>> for Property {
     }
 }
 
+impl TryInto<Edges> for Property {
+    type Error = String;
+
+    fn try_into(self) -> Result<Edges, Self::Error> {
+        match self {
+            Property::Edges(value) => Ok(value),
+            _ => Err("Couldn't convert Property into Edges".to_string()),
+        }
+    }
+}
+
 impl TryInto<ApplyTo> for Property {
     type Error = String;
 
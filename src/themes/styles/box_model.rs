@@ -0,0 +1,45 @@
+use crate::general::{Color, Edges, Vector2D};
+use crate::themes::{PropertiesMap, StyleEnum};
+
+/// Margin, border and padding of a widget, like in CSS. What a style doesn't set is 0.
+#[derive(Debug, Clone)]
+pub struct BoxModel {
+    /// Outside the border, kept free by the layouts.
+    pub margin: Edges,
+    pub border_width: Edges,
+    pub border_color: Color,
+    /// Between the border and the content.
+    pub padding: Edges,
+}
+
+impl BoxModel {
+    pub fn from_properties(properties: &PropertiesMap) -> Self {
+        let edges = |style_enum: &StyleEnum| {
+            properties
+                .get(style_enum)
+                .and_then(|property| property.clone().try_into().ok())
+                .unwrap_or_default()
+        };
+        Self {
+            margin: edges(&StyleEnum::Margin),
+            border_width: edges(&StyleEnum::BorderWidth),
+            border_color: properties
+                .get(&StyleEnum::BorderColor)
+                .and_then(|property| property.clone().try_into().ok())
+                .unwrap_or(Color::new(0, 0, 0, 0)),
+            padding: edges(&StyleEnum::Padding),
+        }
+    }
+    /// From the border to the content.
+    pub fn inset(&self) -> Edges {
+        self.border_width + self.padding
+    }
+    /// What's left for the content of a widget of that size.
+    pub fn content_size(&self, size: &Vector2D<f32>) -> Vector2D<f32> {
+        let inset = self.inset();
+        Vector2D::new(
+            (size.x() - inset.horizontal()).max(0.0),
+            (size.y() - inset.vertical()).max(0.0),
+        )
+    }
+}
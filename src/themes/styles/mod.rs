@@ -4,6 +4,9 @@ pub use style::*;
 mod style_enum;
 pub use style_enum::*;
 
+mod box_model;
+pub use box_model::*;
+
 mod style_master;
 pub use style_master::*;
 
@@ -8,4 +8,20 @@ pub enum StyleEnum {
     Size,
     Font,
     FontSize,
+    SelectionColor,
+    RowHeight,
+    Indent,
+    Spacing,
+    Thickness,
+    OverlayColor,
+    ButtonColor,
+    InfoColor,
+    SuccessColor,
+    WarningColor,
+    ErrorColor,
+    DisabledColor,
+    Padding,
+    Margin,
+    BorderWidth,
+    BorderColor,
 }
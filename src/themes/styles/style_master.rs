@@ -10,8 +10,8 @@ use log::debug;
 
 use crate::{
     themes::{
-        property::ApplyTo, ArcFnNewStyleForWidgetWrap, PropertiesMap, Property, Style, StyleEnum,
-        StyleForWidget, ThemeEngine, ThemeForWidget,
+        property::ApplyTo, ArcFnNewStyleForWidgetWrap, BoxModel, PropertiesMap, Property, Style,
+        StyleEnum, StyleForWidget, ThemeEngine, ThemeForWidget,
     },
     utils::Assets,
 };
@@ -77,6 +77,24 @@ impl StyleMaster {
         &self,
         type_id: TypeId,
     ) -> Result<Box<dyn StyleForWidget>, Box<dyn Error>> {
+        let properties = self.properties_for(type_id);
+        return self
+            .style_for_widget_mapping
+            .get(&type_id)
+            .map(|s| s.0(properties))
+            .unwrap_or_else(|| {
+                Err(Box::from(format!(
+                    "Unsupported type, no mapping for {:?}",
+                    type_id
+                )))
+            });
+    }
+    /// Margin, border and padding of a type of widget, e.g. for a layout to keep the margins free.
+    pub fn box_model(&self, type_id: TypeId) -> BoxModel {
+        BoxModel::from_properties(&self.properties_for(type_id))
+    }
+    /// Every style that applies to the type merged, the later ones win.
+    fn properties_for(&self, type_id: TypeId) -> PropertiesMap {
         let mut properties: PropertiesMap = Default::default();
         debug!("dyn_get_style {:?}", self.styles);
         for style in &self.styles {
@@ -105,16 +123,7 @@ impl StyleMaster {
         }
         debug!("Properties: {:?}", properties);
         debug!("type_id: {:?}", type_id);
-        return self
-            .style_for_widget_mapping
-            .get(&type_id)
-            .map(|s| s.0(properties))
-            .unwrap_or_else(|| {
-                Err(Box::from(format!(
-                    "Unsupported type, no mapping for {:?}",
-                    type_id
-                )))
-            });
+        properties
     }
     pub fn style_for_widget_t<T: StyleForWidget>(
         &self,
@@ -3,8 +3,8 @@ use std::error::Error;
 use glyph_brush::ab_glyph::FontArc;
 
 use crate::{
-    general::{Color, Vector2D},
-    themes::{ExtraStyleMap, PropertiesMap, StyleEnum, StyleExtractor, StyleForWidget},
+    general::{Color, Edges, Vector2D},
+    themes::{BoxModel, ExtraStyleMap, PropertiesMap, StyleEnum, StyleExtractor, StyleForWidget},
 };
 
 use crate::{
@@ -20,6 +20,11 @@ pub struct ButtonCompleteStyle {
     pub size: Option<(f32, f32)>,
     pub font: String,
     pub font_size: f32,
+    pub spacing: f32,
+    pub padding: Edges,
+    pub margin: Edges,
+    pub border_width: Edges,
+    pub border_color: (u8, u8, u8, u8),
     pub extra: ExtraStyle,
 }
 
@@ -36,6 +41,11 @@ impl Style for ButtonCompleteStyle {
             (Size, (&self.size).into()),
             (Font, self.font.clone().into()),
             (FontSize, self.font_size.into()),
+            (Spacing, self.spacing.into()),
+            (Padding, self.padding.into()),
+            (Margin, self.margin.into()),
+            (BorderWidth, self.border_width.into()),
+            (BorderColor, general::Color::from(&self.border_color).into()),
             (Extra, self.extra.clone().into()),
         ];
         vec_for_map.into_iter().collect()
@@ -49,6 +59,9 @@ pub struct ThemeStyleForButton {
     pub size: Option<Vector2D<f32>>,
     pub font: FontArc,
     pub font_size: f32,
+    /// Between the icon and the text.
+    pub spacing: f32,
+    pub box_model: BoxModel,
     pub extra: ExtraStyleMap,
 }
 
@@ -63,6 +76,8 @@ impl StyleForWidget for ThemeStyleForButton {
             size: e.extract(p, &Size)?.try_into()?,
             font: e.extract(p, &Font)?.try_into()?,
             font_size: e.extract(p, &FontSize)?.try_into()?,
+            spacing: e.extract(p, &Spacing)?.try_into()?,
+            box_model: BoxModel::from_properties(p),
             extra: e.extract(p, &Extra)?.try_into()?,
         }))
     }
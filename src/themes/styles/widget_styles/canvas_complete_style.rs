@@ -0,0 +1,66 @@
+use std::error::Error;
+
+use glyph_brush::ab_glyph::FontArc;
+
+use crate::{
+    general::{self, Color, Edges},
+    themes::{
+        property::ApplyTo, BoxModel, ExtraStyle, ExtraStyleMap, GeneralStyleVec, PropertiesMap,
+        Style, StyleEnum, StyleExtractor, StyleForWidget,
+    },
+};
+
+#[derive(Debug, Default)]
+pub struct CanvasCompleteStyle {
+    pub apply_to: ApplyTo,
+    pub background_color: (u8, u8, u8, u8),
+    pub font: String,
+    pub padding: Edges,
+    pub margin: Edges,
+    pub border_width: Edges,
+    pub border_color: (u8, u8, u8, u8),
+    pub extra: ExtraStyle,
+}
+
+impl Style for CanvasCompleteStyle {
+    fn to_properties_map(&self) -> PropertiesMap {
+        use StyleEnum::*;
+        let vec_for_map: GeneralStyleVec = vec![
+            (ApplyTo, self.apply_to.clone().into()),
+            (
+                BackgroundColor,
+                general::Color::from(&self.background_color).into(),
+            ),
+            (Font, self.font.clone().into()),
+            (Padding, self.padding.into()),
+            (Margin, self.margin.into()),
+            (BorderWidth, self.border_width.into()),
+            (BorderColor, general::Color::from(&self.border_color).into()),
+            (Extra, self.extra.clone().into()),
+        ];
+        vec_for_map.into_iter().collect()
+    }
+}
+
+/// font is the one Painter::text() uses.
+#[derive(Debug)]
+pub struct ThemeStyleForCanvas {
+    pub background_color: Color,
+    pub font: FontArc,
+    pub box_model: BoxModel,
+    pub extra: ExtraStyleMap,
+}
+
+impl StyleForWidget for ThemeStyleForCanvas {
+    fn new(mut properties: PropertiesMap) -> Result<Box<dyn StyleForWidget>, Box<dyn Error>> {
+        let p = &mut properties;
+        let e = StyleExtractor;
+        use StyleEnum::*;
+        Ok(Box::new(ThemeStyleForCanvas {
+            background_color: e.extract(p, &BackgroundColor)?.try_into()?,
+            font: e.extract(p, &Font)?.try_into()?,
+            box_model: BoxModel::from_properties(p),
+            extra: e.extract(p, &Extra)?.try_into()?,
+        }))
+    }
+}
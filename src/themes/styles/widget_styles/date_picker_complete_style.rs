@@ -0,0 +1,85 @@
+use std::error::Error;
+
+use glyph_brush::ab_glyph::FontArc;
+
+use crate::{
+    general::{self, Color, Edges},
+    themes::{
+        property::ApplyTo, BoxModel, ExtraStyle, ExtraStyleMap, GeneralStyleVec, PropertiesMap,
+        Style, StyleEnum, StyleExtractor, StyleForWidget,
+    },
+};
+
+#[derive(Debug, Default)]
+pub struct DatePickerCompleteStyle {
+    pub apply_to: ApplyTo,
+    pub color: (u8, u8, u8, u8),
+    pub background_color: (u8, u8, u8, u8),
+    pub selection_color: (u8, u8, u8, u8),
+    pub font: String,
+    pub font_size: f32,
+    pub spacing: f32,
+    pub padding: Edges,
+    pub margin: Edges,
+    pub border_width: Edges,
+    pub border_color: (u8, u8, u8, u8),
+    pub extra: ExtraStyle,
+}
+
+impl Style for DatePickerCompleteStyle {
+    fn to_properties_map(&self) -> PropertiesMap {
+        use StyleEnum::*;
+        let vec_for_map: GeneralStyleVec = vec![
+            (ApplyTo, self.apply_to.clone().into()),
+            (Color, general::Color::from(&self.color).into()),
+            (
+                BackgroundColor,
+                general::Color::from(&self.background_color).into(),
+            ),
+            (
+                SelectionColor,
+                general::Color::from(&self.selection_color).into(),
+            ),
+            (Font, self.font.clone().into()),
+            (FontSize, self.font_size.into()),
+            (Spacing, self.spacing.into()),
+            (Padding, self.padding.into()),
+            (Margin, self.margin.into()),
+            (BorderWidth, self.border_width.into()),
+            (BorderColor, general::Color::from(&self.border_color).into()),
+            (Extra, self.extra.clone().into()),
+        ];
+        vec_for_map.into_iter().collect()
+    }
+}
+
+/// selection_color is for the button that opens the calendar.
+#[derive(Debug)]
+pub struct ThemeStyleForDatePicker {
+    pub color: Color,
+    pub background_color: Color,
+    pub selection_color: Color,
+    pub font: FontArc,
+    pub font_size: f32,
+    pub spacing: f32,
+    pub box_model: BoxModel,
+    pub extra: ExtraStyleMap,
+}
+
+impl StyleForWidget for ThemeStyleForDatePicker {
+    fn new(mut properties: PropertiesMap) -> Result<Box<dyn StyleForWidget>, Box<dyn Error>> {
+        let p = &mut properties;
+        let e = StyleExtractor;
+        use StyleEnum::*;
+        Ok(Box::new(ThemeStyleForDatePicker {
+            color: e.extract(p, &Color)?.try_into()?,
+            background_color: e.extract(p, &BackgroundColor)?.try_into()?,
+            selection_color: e.extract(p, &SelectionColor)?.try_into()?,
+            font: e.extract(p, &Font)?.try_into()?,
+            font_size: e.extract(p, &FontSize)?.try_into()?,
+            spacing: e.extract(p, &Spacing)?.try_into()?,
+            box_model: BoxModel::from_properties(p),
+            extra: e.extract(p, &Extra)?.try_into()?,
+        }))
+    }
+}
@@ -1,9 +1,12 @@
 use std::error::Error;
 
-use crate::themes::{ExtraStyleMap, PropertiesMap, StyleEnum, StyleExtractor, StyleForWidget};
+use crate::themes::{
+    BoxModel, ExtraStyleMap, PropertiesMap, StyleEnum, StyleExtractor, StyleForWidget,
+};
 
 #[derive(Debug)]
 pub struct ThemeStyleForImage {
+    pub box_model: BoxModel,
     pub extra: ExtraStyleMap,
 }
 
@@ -11,6 +14,7 @@ impl StyleForWidget for ThemeStyleForImage {
     fn new(mut properties: PropertiesMap) -> Result<Box<dyn StyleForWidget>, Box<dyn Error>> {
         use StyleEnum::*;
         Ok(Box::new(ThemeStyleForImage {
+            box_model: BoxModel::from_properties(&properties),
             extra: StyleExtractor.extract(&mut properties, &Extra)?.try_into()?,
         }))
     }
@@ -9,3 +9,66 @@ pub use text_box_complete_style::*;
 
 mod image_complete_style;
 pub use image_complete_style::*;
+
+mod tree_view_complete_style;
+pub use tree_view_complete_style::*;
+
+mod tab_view_complete_style;
+pub use tab_view_complete_style::*;
+
+mod dialog_complete_style;
+pub use dialog_complete_style::*;
+
+mod scroll_view_complete_style;
+pub use scroll_view_complete_style::*;
+
+mod tooltip_complete_style;
+pub use tooltip_complete_style::*;
+
+mod toast_complete_style;
+pub use toast_complete_style::*;
+
+mod menu_complete_style;
+pub use menu_complete_style::*;
+
+mod menu_bar_complete_style;
+pub use menu_bar_complete_style::*;
+
+mod status_bar_complete_style;
+pub use status_bar_complete_style::*;
+
+mod spin_box_complete_style;
+pub use spin_box_complete_style::*;
+
+mod calendar_complete_style;
+pub use calendar_complete_style::*;
+
+mod date_picker_complete_style;
+pub use date_picker_complete_style::*;
+
+mod text_area_complete_style;
+pub use text_area_complete_style::*;
+
+mod label_complete_style;
+pub use label_complete_style::*;
+
+mod canvas_complete_style;
+pub use canvas_complete_style::*;
+
+mod framebuffer_complete_style;
+pub use framebuffer_complete_style::*;
+
+mod splitter_complete_style;
+pub use splitter_complete_style::*;
+
+mod color_picker_complete_style;
+pub use color_picker_complete_style::*;
+
+mod toggle_complete_style;
+pub use toggle_complete_style::*;
+
+mod group_box_complete_style;
+pub use group_box_complete_style::*;
+
+mod accordion_complete_style;
+pub use accordion_complete_style::*;
@@ -3,10 +3,10 @@ use std::error::Error;
 use glyph_brush::ab_glyph::FontArc;
 
 use crate::{
-    general::{self, Color, Vector2D},
+    general::{self, Color, Edges, Vector2D},
     themes::{
-        property::ApplyTo, ExtraStyle, ExtraStyleMap, GeneralStyleVec, PropertiesMap, Style,
-        StyleEnum, StyleExtractor, StyleForWidget,
+        property::ApplyTo, BoxModel, ExtraStyle, ExtraStyleMap, GeneralStyleVec, PropertiesMap,
+        Style, StyleEnum, StyleExtractor, StyleForWidget,
     },
 };
 
@@ -18,6 +18,10 @@ pub struct TextBoxCompleteStyle {
     pub size: Option<(f32, f32)>,
     pub font: String,
     pub font_size: f32,
+    pub padding: Edges,
+    pub margin: Edges,
+    pub border_width: Edges,
+    pub border_color: (u8, u8, u8, u8),
     pub extra: ExtraStyle,
 }
 
@@ -34,6 +38,10 @@ impl Style for TextBoxCompleteStyle {
             (Size, (&self.size).into()),
             (Font, self.font.clone().into()),
             (FontSize, self.font_size.into()),
+            (Padding, self.padding.into()),
+            (Margin, self.margin.into()),
+            (BorderWidth, self.border_width.into()),
+            (BorderColor, general::Color::from(&self.border_color).into()),
             (Extra, self.extra.clone().into()),
         ];
         vec_for_map.into_iter().collect()
@@ -47,6 +55,7 @@ pub struct ThemeStyleForTextBox {
     pub size: Option<Vector2D<f32>>,
     pub font: FontArc,
     pub font_size: f32,
+    pub box_model: BoxModel,
     pub extra: ExtraStyleMap,
 }
 
@@ -61,6 +70,7 @@ impl StyleForWidget for ThemeStyleForTextBox {
             size: e.extract(p, &Size)?.try_into()?,
             font: e.extract(p, &Font)?.try_into()?,
             font_size: e.extract(p, &FontSize)?.try_into()?,
+            box_model: BoxModel::from_properties(p),
             extra: e.extract(p, &Extra)?.try_into()?,
         }))
     }
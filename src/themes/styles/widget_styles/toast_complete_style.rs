@@ -0,0 +1,93 @@
+use std::error::Error;
+
+use glyph_brush::ab_glyph::FontArc;
+
+use crate::{
+    general::{self, Color, Edges},
+    themes::{
+        property::ApplyTo, BoxModel, ExtraStyle, ExtraStyleMap, GeneralStyleVec, PropertiesMap,
+        Style, StyleEnum, StyleExtractor, StyleForWidget,
+    },
+};
+
+#[derive(Debug, Default)]
+pub struct ToastCompleteStyle {
+    pub apply_to: ApplyTo,
+    pub color: (u8, u8, u8, u8),
+    pub info_color: (u8, u8, u8, u8),
+    pub success_color: (u8, u8, u8, u8),
+    pub warning_color: (u8, u8, u8, u8),
+    pub error_color: (u8, u8, u8, u8),
+    pub font: String,
+    pub font_size: f32,
+    pub spacing: f32,
+    pub padding: Edges,
+    pub margin: Edges,
+    pub border_width: Edges,
+    pub border_color: (u8, u8, u8, u8),
+    pub extra: ExtraStyle,
+}
+
+impl Style for ToastCompleteStyle {
+    fn to_properties_map(&self) -> PropertiesMap {
+        use StyleEnum::*;
+        let vec_for_map: GeneralStyleVec = vec![
+            (ApplyTo, self.apply_to.clone().into()),
+            (Color, general::Color::from(&self.color).into()),
+            (InfoColor, general::Color::from(&self.info_color).into()),
+            (
+                SuccessColor,
+                general::Color::from(&self.success_color).into(),
+            ),
+            (
+                WarningColor,
+                general::Color::from(&self.warning_color).into(),
+            ),
+            (ErrorColor, general::Color::from(&self.error_color).into()),
+            (Font, self.font.clone().into()),
+            (FontSize, self.font_size.into()),
+            (Spacing, self.spacing.into()),
+            (Padding, self.padding.into()),
+            (Margin, self.margin.into()),
+            (BorderWidth, self.border_width.into()),
+            (BorderColor, general::Color::from(&self.border_color).into()),
+            (Extra, self.extra.clone().into()),
+        ];
+        vec_for_map.into_iter().collect()
+    }
+}
+
+/// One background color per ToastLevel, color is for the text.
+#[derive(Debug)]
+pub struct ThemeStyleForToast {
+    pub color: Color,
+    pub info_color: Color,
+    pub success_color: Color,
+    pub warning_color: Color,
+    pub error_color: Color,
+    pub font: FontArc,
+    pub font_size: f32,
+    pub spacing: f32,
+    pub box_model: BoxModel,
+    pub extra: ExtraStyleMap,
+}
+
+impl StyleForWidget for ThemeStyleForToast {
+    fn new(mut properties: PropertiesMap) -> Result<Box<dyn StyleForWidget>, Box<dyn Error>> {
+        let p = &mut properties;
+        let e = StyleExtractor;
+        use StyleEnum::*;
+        Ok(Box::new(ThemeStyleForToast {
+            color: e.extract(p, &Color)?.try_into()?,
+            info_color: e.extract(p, &InfoColor)?.try_into()?,
+            success_color: e.extract(p, &SuccessColor)?.try_into()?,
+            warning_color: e.extract(p, &WarningColor)?.try_into()?,
+            error_color: e.extract(p, &ErrorColor)?.try_into()?,
+            font: e.extract(p, &Font)?.try_into()?,
+            font_size: e.extract(p, &FontSize)?.try_into()?,
+            spacing: e.extract(p, &Spacing)?.try_into()?,
+            box_model: BoxModel::from_properties(p),
+            extra: e.extract(p, &Extra)?.try_into()?,
+        }))
+    }
+}
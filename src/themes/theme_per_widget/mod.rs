@@ -1,11 +1,53 @@
+pub use theme_for_accordion::*;
 pub use theme_for_button::*;
+pub use theme_for_calendar::*;
+pub use theme_for_canvas::*;
+pub use theme_for_color_picker::*;
+pub use theme_for_date_picker::*;
+pub use theme_for_dialog::*;
+pub use theme_for_framebuffer::*;
+pub use theme_for_group_box::*;
 pub use theme_for_image::*;
+pub use theme_for_label::*;
+pub use theme_for_menu::*;
+pub use theme_for_menu_bar::*;
+pub use theme_for_scroll_view::*;
+pub use theme_for_spin_box::*;
+pub use theme_for_splitter::*;
+pub use theme_for_status_bar::*;
+pub use theme_for_tab_view::*;
 pub use theme_for_text::*;
+pub use theme_for_text_area::*;
+pub use theme_for_toast::*;
+pub use theme_for_toggle::*;
+pub use theme_for_tooltip::*;
+pub use theme_for_tree_view::*;
 pub use theme_for_widget::*;
 pub use theme_style::*;
 
+mod theme_for_accordion;
 mod theme_for_button;
+mod theme_for_calendar;
+mod theme_for_canvas;
+mod theme_for_color_picker;
+mod theme_for_date_picker;
+mod theme_for_dialog;
+mod theme_for_framebuffer;
+mod theme_for_group_box;
 mod theme_for_image;
+mod theme_for_label;
+mod theme_for_menu;
+mod theme_for_menu_bar;
+mod theme_for_scroll_view;
+mod theme_for_spin_box;
+mod theme_for_splitter;
+mod theme_for_status_bar;
+mod theme_for_tab_view;
 mod theme_for_text;
+mod theme_for_text_area;
+mod theme_for_toast;
+mod theme_for_toggle;
+mod theme_for_tooltip;
+mod theme_for_tree_view;
 mod theme_for_widget;
 mod theme_style;
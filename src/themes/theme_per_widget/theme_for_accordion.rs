@@ -0,0 +1,25 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForAccordion},
+    widgets::{AccordionLayout, AccordionSectionFrame},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForAccordion: ThemeForWidget {
+    fn new_accordion(
+        &self,
+        sections: &[AccordionSectionFrame],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForAccordion>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> AccordionLayout;
+    /// Called every frame while a section opens or closes.
+    fn set_accordion(
+        &self,
+        sections: &[AccordionSectionFrame],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForAccordion>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> AccordionLayout;
+}
@@ -1,6 +1,10 @@
+use std::sync::{Arc, Mutex};
+
 use crate::{
     general::Vector2D,
+    texture::SoftTexture,
     themes::{PrimitivesManagerForThemes, ThemeStyleForButton},
+    widgets::IconPlacement,
 };
 
 use super::ThemeForWidget;
@@ -20,4 +24,24 @@ pub trait ThemeForButton: ThemeForWidget {
         style: Box<ThemeStyleForButton>,
         prim_man: &mut PrimitivesManagerForThemes,
     ) -> Vector2D<f32>;
+    /// The text can be empty for buttons that only show the icon.
+    fn new_icon_button(
+        &self,
+        icon: Arc<Mutex<dyn SoftTexture>>,
+        text: &str,
+        placement: IconPlacement,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForButton>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    /// Called when the icon, the text or the placement change.
+    fn set_icon_button(
+        &self,
+        icon: Arc<Mutex<dyn SoftTexture>>,
+        text: &str,
+        placement: IconPlacement,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForButton>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
 }
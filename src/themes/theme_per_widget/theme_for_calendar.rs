@@ -0,0 +1,25 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForCalendar},
+    widgets::{CalendarFrame, CalendarLayout},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForCalendar: ThemeForWidget {
+    fn new_calendar(
+        &self,
+        frame: &CalendarFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForCalendar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> CalendarLayout;
+    /// Called when the selected day or the shown month change.
+    fn set_calendar(
+        &self,
+        frame: &CalendarFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForCalendar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> CalendarLayout;
+}
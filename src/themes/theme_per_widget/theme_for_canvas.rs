@@ -0,0 +1,24 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForCanvas},
+};
+
+use super::ThemeForWidget;
+
+/// Only the background, what is painted on it comes from the Canvas' draw callback.
+pub trait ThemeForCanvas: ThemeForWidget {
+    fn new_canvas(
+        &self,
+        size: Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForCanvas>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    fn set_canvas(
+        &self,
+        size: Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForCanvas>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
@@ -0,0 +1,25 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForColorPicker},
+    widgets::{ColorPickerFrame, ColorPickerLayout},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForColorPicker: ThemeForWidget {
+    fn new_color_picker(
+        &self,
+        frame: &ColorPickerFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForColorPicker>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> ColorPickerLayout;
+    /// Called when the color, the texts, the caret or the palette change.
+    fn set_color_picker(
+        &self,
+        frame: &ColorPickerFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForColorPicker>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> ColorPickerLayout;
+}
@@ -0,0 +1,27 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForDatePicker},
+    widgets::DatePickerLayout,
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForDatePicker: ThemeForWidget {
+    fn new_date_picker(
+        &self,
+        text: &str,
+        open: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForDatePicker>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> DatePickerLayout;
+    /// Called when the date changes and when the calendar opens or closes.
+    fn set_date_picker(
+        &self,
+        text: &str,
+        open: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForDatePicker>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> DatePickerLayout;
+}
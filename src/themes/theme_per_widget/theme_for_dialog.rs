@@ -0,0 +1,27 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForDialog},
+    widgets::{DialogFrame, DialogLayout},
+};
+
+use super::ThemeForWidget;
+
+/// Themes draw the dimmed background, the panel, the title and the buttons, the content is drawn
+/// by its own widgets where the layout says.
+pub trait ThemeForDialog: ThemeForWidget {
+    fn new_dialog(
+        &self,
+        frame: &DialogFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForDialog>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> DialogLayout;
+    /// Called when the focus moves or the dialog is placed in a window.
+    fn set_dialog(
+        &self,
+        frame: &DialogFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForDialog>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> DialogLayout;
+}
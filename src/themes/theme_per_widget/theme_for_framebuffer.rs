@@ -0,0 +1,24 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForFramebuffer},
+};
+
+use super::ThemeForWidget;
+
+/// Only the background, seen around the picture when it doesn't fill the widget.
+pub trait ThemeForFramebuffer: ThemeForWidget {
+    fn new_framebuffer(
+        &self,
+        size: Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForFramebuffer>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    fn set_framebuffer(
+        &self,
+        size: Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForFramebuffer>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
@@ -0,0 +1,27 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForGroupBox},
+    widgets::GroupBoxLayout,
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForGroupBox: ThemeForWidget {
+    fn new_group_box(
+        &self,
+        title: &str,
+        content_size: &Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForGroupBox>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> GroupBoxLayout;
+    /// Called when the title or the size of the content change.
+    fn set_group_box(
+        &self,
+        title: &str,
+        content_size: &Vector2D<f32>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForGroupBox>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> GroupBoxLayout;
+}
@@ -0,0 +1,29 @@
+use crate::{
+    general::{TextAlign, Vector2D},
+    themes::{PrimitivesManagerForThemes, ThemeStyleForLabel},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForLabel: ThemeForWidget {
+    fn new_label(
+        &self,
+        text: &str,
+        align: TextAlign,
+        wrap: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForLabel>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    /// With size_for_clipping the text wraps (or is cut) at its width and the lines that
+    /// don't fit in its height are dropped, ending the last one shown with an ellipsis.
+    fn set_label(
+        &self,
+        text: &str,
+        align: TextAlign,
+        wrap: bool,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForLabel>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
@@ -0,0 +1,25 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForMenu},
+    widgets::{MenuLayout, MenuRow},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForMenu: ThemeForWidget {
+    fn new_menu(
+        &self,
+        rows: &[MenuRow],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForMenu>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> MenuLayout;
+    /// Called every time the highlighted row changes.
+    fn set_menu(
+        &self,
+        rows: &[MenuRow],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForMenu>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> MenuLayout;
+}
@@ -0,0 +1,27 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForMenuBar},
+    widgets::{MenuBarLayout, MenuBarTitle},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForMenuBar: ThemeForWidget {
+    fn new_menu_bar(
+        &self,
+        titles: &[MenuBarTitle],
+        width: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForMenuBar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> MenuBarLayout;
+    /// Called when a menu is opened or closed and when the window width changes.
+    fn set_menu_bar(
+        &self,
+        titles: &[MenuBarTitle],
+        width: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForMenuBar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> MenuBarLayout;
+}
@@ -0,0 +1,26 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForScrollView},
+    widgets::ScrollBar,
+};
+
+use super::ThemeForWidget;
+
+/// Themes only draw the scroll bars, the content is drawn by its own widgets.
+pub trait ThemeForScrollView: ThemeForWidget {
+    fn new_scroll_view(
+        &self,
+        bars: &[ScrollBar],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForScrollView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    );
+    /// Called every time the content is scrolled or changes its size.
+    fn set_scroll_bars(
+        &self,
+        bars: &[ScrollBar],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForScrollView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    );
+}
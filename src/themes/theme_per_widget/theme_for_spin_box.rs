@@ -0,0 +1,25 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForSpinBox},
+    widgets::{SpinBoxFrame, SpinBoxLayout},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForSpinBox: ThemeForWidget {
+    fn new_spin_box(
+        &self,
+        frame: &SpinBoxFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForSpinBox>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> SpinBoxLayout;
+    /// Called when the text, the caret or the validity change.
+    fn set_spin_box(
+        &self,
+        frame: &SpinBoxFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForSpinBox>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> SpinBoxLayout;
+}
@@ -0,0 +1,30 @@
+use crate::{
+    general::{Rect, Vector2D},
+    themes::{PrimitivesManagerForThemes, ThemeStyleForSplitter},
+    widgets::Direction,
+};
+
+use super::ThemeForWidget;
+
+/// Only the dividers, the Splitter places them and its panes.
+pub trait ThemeForSplitter: ThemeForWidget {
+    fn new_splitter(
+        &self,
+        direction: Direction,
+        dividers: &[Rect<f32>],
+        dragged: Option<usize>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForSplitter>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    );
+    /// Called when a divider moves or starts or stops being dragged.
+    fn set_splitter(
+        &self,
+        direction: Direction,
+        dividers: &[Rect<f32>],
+        dragged: Option<usize>,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForSplitter>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    );
+}
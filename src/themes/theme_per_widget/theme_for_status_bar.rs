@@ -0,0 +1,28 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForStatusBar},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForStatusBar: ThemeForWidget {
+    fn new_status_bar(
+        &self,
+        text: &str,
+        sections: &[String],
+        width: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForStatusBar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    /// Called when the text or the sections change and when the window width changes.
+    fn set_status_bar(
+        &self,
+        text: &str,
+        sections: &[String],
+        width: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForStatusBar>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
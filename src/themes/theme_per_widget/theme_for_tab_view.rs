@@ -0,0 +1,26 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForTabView},
+    widgets::{TabStripLayout, TabViewTab},
+};
+
+use super::ThemeForWidget;
+
+/// Themes only draw the tab strip, pages are drawn by their own widgets.
+pub trait ThemeForTabView: ThemeForWidget {
+    fn new_tab_view(
+        &self,
+        tabs: &[TabViewTab],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTabView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> TabStripLayout;
+    /// Called every time a tab is added, closed, moved or activated.
+    fn set_tabs(
+        &self,
+        tabs: &[TabViewTab],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTabView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> TabStripLayout;
+}
@@ -0,0 +1,26 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForTextArea},
+    widgets::TextAreaFrame,
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForTextArea: ThemeForWidget {
+    fn new_text_area(
+        &self,
+        frame: &TextAreaFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTextArea>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    /// Called after every change, rows with a key that was already drawn haven't changed,
+    /// only their position may have.
+    fn set_text_area(
+        &self,
+        frame: &TextAreaFrame,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTextArea>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
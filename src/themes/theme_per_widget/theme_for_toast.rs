@@ -0,0 +1,18 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForToast},
+    widgets::ToastLevel,
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForToast: ThemeForWidget {
+    fn new_toast(
+        &self,
+        text: &str,
+        level: ToastLevel,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForToast>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
@@ -0,0 +1,27 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForToggle},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForToggle: ThemeForWidget {
+    /// position goes from 0 when off to 1 when on, it's in between while the thumb moves.
+    fn new_toggle(
+        &self,
+        text: &str,
+        position: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForToggle>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    /// Called every frame while the thumb moves.
+    fn set_toggle(
+        &self,
+        text: &str,
+        position: f32,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForToggle>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
@@ -0,0 +1,23 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForTooltip},
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForTooltip: ThemeForWidget {
+    fn new_tooltip(
+        &self,
+        text: &str,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTooltip>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    fn set_text(
+        &self,
+        text: &str,
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTooltip>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
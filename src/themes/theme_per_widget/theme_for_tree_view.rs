@@ -0,0 +1,25 @@
+use crate::{
+    general::Vector2D,
+    themes::{PrimitivesManagerForThemes, ThemeStyleForTreeView},
+    widgets::TreeViewRow,
+};
+
+use super::ThemeForWidget;
+
+pub trait ThemeForTreeView: ThemeForWidget {
+    fn new_tree_view(
+        &self,
+        rows: &[TreeViewRow],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTreeView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+    /// Called every time a node is expanded, collapsed, added or selected.
+    fn set_rows(
+        &self,
+        rows: &[TreeViewRow],
+        size_for_clipping: Option<Vector2D<f32>>,
+        style: Box<ThemeStyleForTreeView>,
+        prim_man: &mut PrimitivesManagerForThemes,
+    ) -> Vector2D<f32>;
+}
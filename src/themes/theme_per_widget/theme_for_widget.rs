@@ -2,4 +2,7 @@ use std::any::Any;
 
 use std::fmt::Debug;
 
+/// size_for_clipping, when a theme method takes it, is the size the widget must have.
+/// Themes lay their primitives out inside it where they can, e.g. cutting text with an
+/// ellipsis, and set it as the clip size of the PrimitivesManagerForThemes for the rest.
 pub trait ThemeForWidget: Any + Debug + Sync {}
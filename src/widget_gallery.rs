@@ -1,18 +1,27 @@
 use std::io::Write;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::Duration;
 
 use env_logger::Target;
 use log::info;
 
 use crate::engines::sdl::SDLEngine;
-use crate::general::Vector2D;
+use crate::general::{Color, Date, Rect, SizeConstraints, TextAlign, Vector2D, Weekday};
 use crate::themes::{DarkSimpleTheme, StyleMaster};
 use crate::utils::SDLLoggerPipe;
 use crate::widgets::events::HasEvents;
 use crate::widgets::primitives::Primitive;
-use crate::widgets::{Button, Compound, Direction, Image, TextBox, Widget, WidgetEnum, WidgetId};
-use crate::window::WindowBuilder;
+use crate::widgets::HasTooltip;
+use crate::widgets::{
+    Accordion, Align, Button, Canvas, ColorPicker, Compound, ContextMenu, DatePicker, Dialog,
+    DialogResult, Direction, FlexLayout, Framebuffer, FramebufferScaling, Grid, GridCell, GroupBox,
+    IconPlacement, Image, Justify, Label, MenuBar, MenuItem, ScrollView, SpinBox, Splitter,
+    StatusBar, TabView, TextArea, TextBox, ToastLevel, Toggle, Tooltip, TrackSize, TreeNode,
+    TreeView, Widget, WidgetEnum, WidgetId,
+};
+use crate::window::{Anchors, Placement, ToastManager, WindowBuilder, WindowRegion};
 
 /*
 Start with one DSL, it could be empty, declare it old_dsl
@@ -45,6 +54,81 @@ pub enum WidgetGalleryEnum {
     Button,
     Textbox2,
     COMPOUND,
+    TreeView,
+    TabView,
+    TabPageText,
+    TabPageImage,
+    TabText,
+    TabImage,
+    ScrollView,
+    ScrollContent,
+    ScrollButton,
+    ScrollImage,
+    ScrollEnd,
+    DialogButton,
+    Dialog,
+    DialogContent,
+    DialogText,
+    ButtonTooltip,
+    TreeViewTooltip,
+    MenuBar,
+    StatusBar,
+    AddressSpinBox,
+    ScaleSpinBox,
+    DatePicker,
+    Label,
+    Canvas,
+    Framebuffer,
+    Splitter,
+    SplitterLeft,
+    SplitterLeftText,
+    SplitterRight,
+    SplitterRightText,
+    TabPageColor,
+    TabColorPicker,
+    Toolbar,
+    PlayButton,
+    PlayTextButton,
+    SoundToggle,
+    TabPageSettings,
+    SettingsGroupBox,
+    SettingsContent,
+    Accordion,
+    GeneralSection,
+    NameTextBox,
+    AdvancedSection,
+    PathTextBox,
+    SettingsLabel,
+    FormGrid,
+    WidthLabel,
+    WidthTextBox,
+    HeightLabel,
+    HeightTextBox,
+    Tags,
+    TagRust,
+    TagGui,
+    TagSdl,
+    TagThemes,
+    TagLayout,
+    TagWidgets,
+    TabTextArea,
+}
+
+// Given back by the menus when an item is activated
+#[repr(usize)]
+#[derive(Clone, Copy, Debug)]
+enum MenuAction {
+    New,
+    Open,
+    OpenRecentImage,
+    OpenRecentFont,
+    Save,
+    WordWrap,
+    ShowGrid,
+    About,
+    Rename,
+    Delete,
+    CopyPath,
 }
 
 impl WidgetEnum for WidgetGalleryEnum {
@@ -53,6 +137,96 @@ impl WidgetEnum for WidgetGalleryEnum {
     }
 }
 
+// Callbacks can't capture, so the ones creating widgets take the StyleMaster from here
+static STYLE_MASTER: OnceLock<Arc<StyleMaster>> = OnceLock::new();
+
+fn new_dialog() -> Result<Dialog, Box<dyn std::error::Error>> {
+    let style_master = STYLE_MASTER
+        .get()
+        .expect("widget_gallery:new_dialog STYLE_MASTER")
+        .clone();
+    let mut content = Compound::new(
+        WidgetGalleryEnum::DialogContent,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    content.add_widget(TextBox::new(
+        WidgetGalleryEnum::DialogText,
+        "Save the changes?",
+        style_master.clone(),
+    )?);
+    let mut dialog =
+        Dialog::new_ok_cancel(WidgetGalleryEnum::Dialog, "Unsaved", content, style_master)?;
+    dialog.add_button("Later", DialogResult::Custom(0));
+    dialog.set_event_closed(|root, _wid, result| {
+        TextBox::get_by_id(root, WidgetGalleryEnum::Textbox)
+            .expect("widget_gallery:new_dialog:dialog.set_event_closed")
+            .lock()
+            .expect("set_event_closed")
+            .set_text(&format!("{:?}", result));
+        let level = match result {
+            DialogResult::Ok => ToastLevel::Success,
+            DialogResult::Cancel => ToastLevel::Warning,
+            DialogResult::Custom(_) => ToastLevel::Info,
+        };
+        root.notify(
+            &format!("Dialog closed with {:?}", result),
+            level,
+            Duration::from_secs(3),
+        )
+        .expect("widget_gallery:new_dialog:dialog.set_event_closed notify");
+    });
+    Ok(dialog)
+}
+
+fn new_menu_bar(style_master: Arc<StyleMaster>) -> Result<MenuBar, Box<dyn std::error::Error>> {
+    let mut menu_bar = MenuBar::new(
+        WidgetGalleryEnum::MenuBar,
+        vec![
+            MenuItem::new_submenu(
+                "&File",
+                vec![
+                    MenuItem::new("&New", MenuAction::New as usize).with_accelerator("Ctrl+N"),
+                    MenuItem::new("&Open", MenuAction::Open as usize).with_accelerator("Ctrl+O"),
+                    MenuItem::new_submenu(
+                        "Open &Recent",
+                        vec![
+                            MenuItem::new("&image.bmp", MenuAction::OpenRecentImage as usize),
+                            MenuItem::new("&Nouveau_IBM.ttf", MenuAction::OpenRecentFont as usize),
+                        ],
+                    ),
+                    MenuItem::new_separator(),
+                    MenuItem::new("&Save", MenuAction::Save as usize)
+                        .with_accelerator("Ctrl+S")
+                        .disabled(),
+                ],
+            ),
+            MenuItem::new_submenu(
+                "&View",
+                vec![
+                    MenuItem::new_check("&Word wrap", MenuAction::WordWrap as usize, false),
+                    MenuItem::new_check("Show &grid", MenuAction::ShowGrid as usize, true),
+                ],
+            ),
+            MenuItem::new_submenu(
+                "&Help",
+                vec![MenuItem::new("&About", MenuAction::About as usize)],
+            ),
+        ],
+        style_master,
+    )?;
+    menu_bar.set_event_activated(|root, _wid, action| {
+        TextBox::get_by_id(root, WidgetGalleryEnum::Textbox)
+            .expect("widget_gallery:new_menu_bar:menu_bar.set_event_activated")
+            .lock()
+            .expect("set_event_activated")
+            .set_text(&format!("Menu {}", action));
+        root.set_status_text(&format!("Menu item {} activated", action))
+            .expect("widget_gallery:new_menu_bar:menu_bar.set_event_activated set_status_text");
+    });
+    Ok(menu_bar)
+}
+
 pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     std::env::set_var("RUST_BACKTRACE", "full");
     // std::env::set_var("RUST_LOG", "info");
@@ -75,8 +249,16 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Single-threaded
     let style_master = Arc::new(StyleMaster::new(Box::new(DarkSimpleTheme))?);
+    STYLE_MASTER.get_or_init(|| style_master.clone());
     // Can we have a global theme instead of sending it to each widget?
     let mut window_builder = WindowBuilder::new(1024, 768)?;
+    window_builder.set_resizable(true);
+    window_builder.set_toast_manager(ToastManager::new(style_master.clone()));
+    window_builder.set_menu_bar(new_menu_bar(style_master.clone())?);
+    let mut status_bar =
+        StatusBar::new(WidgetGalleryEnum::StatusBar, "Ready", style_master.clone())?;
+    status_bar.set_sections(vec!["DarkSimple".to_string(), "1024x768".to_string()]);
+    window_builder.set_status_bar(status_bar);
     let mut image = Image::from_bmp(
         WidgetGalleryEnum::Image,
         Box::from(Path::new("assets/image.bmp")),
@@ -111,16 +293,500 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
             .set_text("Mickey es gason");
     });
 
+    button.set_tooltip(Some(Box::new(Tooltip::new(
+        WidgetGalleryEnum::ButtonTooltip,
+        "Shows where it was clicked",
+        style_master.clone(),
+    )?)));
+
     let mut compound = Compound::new(
         WidgetGalleryEnum::COMPOUND,
         Direction::Horizontal,
         style_master.clone(),
     )?;
     compound.set_position(Vector2D::new(100.0, 100.0));
+    // Same width whatever their text, the clicked text is cut with an ellipsis
+    let same_width = SizeConstraints::fixed_width(120.0);
+    button.set_size_constraints(same_width);
     compound.add_widget(button);
     compound.add_widget(text_box);
+    let mut dialog_button = Button::new(
+        WidgetGalleryEnum::DialogButton,
+        "Dialog",
+        style_master.clone(),
+    )?;
+    dialog_button.set_size_constraints(same_width);
+    dialog_button.set_event_mouse_button_down(|root, _x, _y| {
+        root.show_dialog(new_dialog().expect("widget_gallery:main:dialog_button"))
+            .expect("widget_gallery:main:dialog_button show_dialog");
+    });
+    compound.add_widget(dialog_button);
     window_builder.add_widget(5, compound);
 
+    let mut toolbar = Compound::new(
+        WidgetGalleryEnum::Toolbar,
+        Direction::Horizontal,
+        style_master.clone(),
+    )?;
+    toolbar.set_position(Vector2D::new(100.0, 50.0));
+    // Spread over a fixed width, centered vertically whatever their heights
+    let mut toolbar_layout = FlexLayout::new(Direction::Horizontal);
+    toolbar_layout.size = Some(Vector2D::new(480.0, 40.0));
+    toolbar_layout.gap = 8.0;
+    toolbar_layout.justify = Justify::SpaceBetween;
+    toolbar_layout.align_items = Align::Center;
+    toolbar.set_flex_layout(Some(toolbar_layout));
+    let mut play_button = Button::new_with_icon(
+        WidgetGalleryEnum::PlayButton,
+        Box::from(Path::new("assets/play.bmp")),
+        "",
+        IconPlacement::Left,
+        style_master.clone(),
+    )?;
+    play_button.set_event_mouse_button_down(|root, _x, _y| {
+        root.set_status_text("Play")
+            .expect("widget_gallery:main:play_button set_status_text");
+    });
+    toolbar.add_widget(play_button);
+    let mut play_text_button = Button::new_with_icon(
+        WidgetGalleryEnum::PlayTextButton,
+        Box::from(Path::new("assets/play.bmp")),
+        "Play",
+        IconPlacement::Left,
+        style_master.clone(),
+    )?;
+    play_text_button.set_event_mouse_button_down(|root, _x, _y| {
+        root.set_status_text("Play with text")
+            .expect("widget_gallery:main:play_text_button set_status_text");
+    });
+    toolbar.add_widget(play_text_button);
+    let mut sound_toggle = Toggle::new(
+        WidgetGalleryEnum::SoundToggle,
+        "Sound",
+        true,
+        style_master.clone(),
+    )?;
+    sound_toggle.set_event_toggled(|root, _wid, on| {
+        root.set_status_text(if on { "Sound on" } else { "Sound off" })
+            .expect("widget_gallery:main:sound_toggle set_status_text");
+    });
+    toolbar.add_widget(sound_toggle);
+    // Keeps its distance to both sides of the window when it's resized
+    window_builder.add_anchored_widget(16, toolbar, Placement::new(Anchors::TOP_LEFT_RIGHT));
+
+    let mut tree_view = TreeView::new(
+        WidgetGalleryEnum::TreeView,
+        vec![
+            TreeNode::new("Scene").with_children(vec![
+                TreeNode::new("Camera"),
+                TreeNode::new("Player").with_children(vec![TreeNode::new("Sprite")]),
+            ]),
+            TreeNode::new("assets").with_lazy_children(),
+        ],
+        style_master.clone(),
+    )?;
+    tree_view.set_load_children(|key| {
+        // A real asset browser would list the directory here
+        vec![
+            TreeNode::new("image.bmp").with_key(&format!("{}/image.bmp", key)),
+            TreeNode::new("Nouveau_IBM.ttf").with_key(&format!("{}/Nouveau_IBM.ttf", key)),
+        ]
+    });
+    tree_view.set_event_selection_changed(|root, _wid, node| {
+        let tree_view = TreeView::get_by_id(root, WidgetGalleryEnum::TreeView)
+            .expect("widget_gallery:main:tree_view.set_event_selection_changed");
+        let key = tree_view
+            .lock()
+            .expect("set_event_selection_changed")
+            .key(node)
+            .to_string();
+        TextBox::get_by_id(root, WidgetGalleryEnum::Textbox)
+            .expect("widget_gallery:main:tree_view.set_event_selection_changed")
+            .lock()
+            .expect("set_event_selection_changed")
+            .set_text(&key);
+    });
+    tree_view.set_tooltip(Some(Box::new(Tooltip::new(
+        WidgetGalleryEnum::TreeViewTooltip,
+        "Arrows to move, Left/Right to collapse/expand",
+        style_master.clone(),
+    )?)));
+    window_builder.add_widget_to_region(WindowRegion::Right, 6, tree_view);
+    let mut tree_view_menu = ContextMenu::new(
+        vec![
+            MenuItem::new("&Rename", MenuAction::Rename as usize).with_accelerator("F2"),
+            MenuItem::new("&Delete", MenuAction::Delete as usize)
+                .with_accelerator("Del")
+                .disabled(),
+            MenuItem::new_separator(),
+            MenuItem::new("&Copy path", MenuAction::CopyPath as usize),
+        ],
+        style_master.clone(),
+    );
+    tree_view_menu.set_event_activated(|root, _wid, action| {
+        root.notify(
+            &format!("Tree view menu {}", action),
+            ToastLevel::Info,
+            Duration::from_secs(2),
+        )
+        .expect("widget_gallery:main:tree_view_menu.set_event_activated");
+    });
+    window_builder.set_context_menu(WidgetGalleryEnum::TreeView, tree_view_menu);
+
+    let mut text_page = Compound::new(
+        WidgetGalleryEnum::TabPageText,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    text_page.add_widget(TextBox::new(
+        WidgetGalleryEnum::TabText,
+        "Ctrl+Tab",
+        style_master.clone(),
+    )?);
+    text_page.add_widget(TextArea::new(
+        WidgetGalleryEnum::TabTextArea,
+        "Multi-line text, it wraps at the border.\nCtrl+Z undoes, Ctrl+Y redoes.",
+        Vector2D::new(240.0, 96.0),
+        style_master.clone(),
+    )?);
+    let mut color_page = Compound::new(
+        WidgetGalleryEnum::TabPageColor,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    let mut color_picker = ColorPicker::new(
+        WidgetGalleryEnum::TabColorPicker,
+        Color::new(0, 96, 192, 255),
+        style_master.clone(),
+    )?;
+    color_picker.set_event_color_changed(|root, _wid, color| {
+        root.set_status_text(&color.to_hex())
+            .expect("widget_gallery:main:color_picker set_status_text");
+    });
+    color_page.add_widget(color_picker);
+    let mut image_page = Compound::new(
+        WidgetGalleryEnum::TabPageImage,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    image_page.add_widget(Image::from_bmp(
+        WidgetGalleryEnum::TabImage,
+        Box::from(Path::new("assets/image.bmp")),
+        style_master.clone(),
+    )?);
+    // The Label moves down as the Accordion sections open
+    let mut general_section = Compound::new(
+        WidgetGalleryEnum::GeneralSection,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    general_section.add_widget(TextBox::new(
+        WidgetGalleryEnum::NameTextBox,
+        "Name",
+        style_master.clone(),
+    )?);
+    let mut advanced_section = Compound::new(
+        WidgetGalleryEnum::AdvancedSection,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    advanced_section.add_widget(TextBox::new(
+        WidgetGalleryEnum::PathTextBox,
+        "/tmp",
+        style_master.clone(),
+    )?);
+    let mut accordion = Accordion::new(WidgetGalleryEnum::Accordion, style_master.clone())?;
+    accordion.add_section("General", general_section, true);
+    accordion.add_section("Advanced", advanced_section, false);
+    let mut settings_content = Compound::new(
+        WidgetGalleryEnum::SettingsContent,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    settings_content.add_widget(accordion);
+    settings_content.add_widget(Label::new(
+        WidgetGalleryEnum::SettingsLabel,
+        "Saved on exit",
+        style_master.clone(),
+    )?);
+    let mut settings_page = Compound::new(
+        WidgetGalleryEnum::TabPageSettings,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    settings_page.add_widget(GroupBox::new(
+        WidgetGalleryEnum::SettingsGroupBox,
+        "Settings",
+        settings_content,
+        style_master.clone(),
+    )?);
+    // Labels in a column as wide as the longest one, the fields take the rest
+    let mut form_grid = Grid::new(
+        WidgetGalleryEnum::FormGrid,
+        vec![TrackSize::Auto, TrackSize::Fraction(1.0)],
+        vec![TrackSize::Auto, TrackSize::Auto],
+        style_master.clone(),
+    )?;
+    form_grid.set_gap(8.0, 4.0);
+    form_grid.set_size(Some(Vector2D::new(360.0, 0.0)));
+    let fields = [
+        (
+            WidgetGalleryEnum::WidthLabel,
+            "Width",
+            WidgetGalleryEnum::WidthTextBox,
+            "640",
+        ),
+        (
+            WidgetGalleryEnum::HeightLabel,
+            "Height",
+            WidgetGalleryEnum::HeightTextBox,
+            "480",
+        ),
+    ];
+    for (row, (label_wid, label, text_box_wid, text)) in fields.into_iter().enumerate() {
+        form_grid.add_widget(
+            Label::new(label_wid, label, style_master.clone())?,
+            GridCell::new(row, 0).with_align(Align::End, Align::Center),
+        );
+        form_grid.add_widget(
+            TextBox::new(text_box_wid, text, style_master.clone())?,
+            GridCell::new(row, 1),
+        );
+    }
+    settings_page.add_widget(form_grid);
+    let mut tags = Compound::new(
+        WidgetGalleryEnum::Tags,
+        Direction::Horizontal,
+        style_master.clone(),
+    )?;
+    tags.set_wrap_limit(Some(240.0));
+    tags.set_gaps(4.0, 4.0);
+    // The click goes to the tag, not to the Compound it's in, and both widgets it
+    // changes are a few containers down the TabView
+    let mut rust_tag = Button::new(WidgetGalleryEnum::TagRust, "rust", style_master.clone())?;
+    rust_tag.set_event_mouse_button_down(|root, _x, _y| {
+        let tag = Button::get_by_id(root, WidgetGalleryEnum::TagRust)
+            .expect("widget_gallery:main:rust_tag.set_event_mouse_button_down tag");
+        let text = format!("Tagged {}", tag.lock().expect("tag").text());
+        Label::get_by_id(root, WidgetGalleryEnum::SettingsLabel)
+            .expect("widget_gallery:main:rust_tag.set_event_mouse_button_down label")
+            .lock()
+            .expect("set_event_mouse_button_down")
+            .set_text(&text);
+    });
+    tags.add_widget(rust_tag);
+    let tag_names = [
+        (WidgetGalleryEnum::TagGui, "gui"),
+        (WidgetGalleryEnum::TagSdl, "sdl2"),
+        (WidgetGalleryEnum::TagThemes, "themes"),
+        (WidgetGalleryEnum::TagLayout, "layout"),
+        (WidgetGalleryEnum::TagWidgets, "widgets"),
+    ];
+    for (tag_wid, name) in tag_names {
+        tags.add_widget(Button::new(tag_wid, name, style_master.clone())?);
+    }
+    settings_page.add_widget(tags);
+    let mut tab_view = TabView::new(WidgetGalleryEnum::TabView, style_master.clone())?;
+    tab_view.add_tab("Text", text_page);
+    tab_view.add_tab("Color", color_page);
+    tab_view.add_tab("Settings", settings_page);
+    tab_view.add_closable_tab("Image", image_page);
+    tab_view.set_position(Vector2D::new(100.0, 300.0));
+    window_builder.add_widget(7, tab_view);
+
+    let mut scroll_button = Button::new(
+        WidgetGalleryEnum::ScrollButton,
+        "Go to the end",
+        style_master.clone(),
+    )?;
+    scroll_button.set_event_mouse_button_down(|root, _x, _y| {
+        ScrollView::get_by_id(root, WidgetGalleryEnum::ScrollView)
+            .expect("widget_gallery:main:scroll_button.set_event_mouse_button_down")
+            .lock()
+            .expect("set_event_mouse_button_down")
+            .scroll_to(WidgetGalleryEnum::ScrollEnd.to_wid());
+    });
+    let mut scroll_content = Compound::new(
+        WidgetGalleryEnum::ScrollContent,
+        Direction::Vertical,
+        style_master.clone(),
+    )?;
+    scroll_content.add_widget(scroll_button);
+    scroll_content.add_widget(Image::from_bmp(
+        WidgetGalleryEnum::ScrollImage,
+        Box::from(Path::new("assets/image.bmp")),
+        style_master.clone(),
+    )?);
+    scroll_content.add_widget(TextBox::new(
+        WidgetGalleryEnum::ScrollEnd,
+        "The end",
+        style_master.clone(),
+    )?);
+    let mut scroll_view = ScrollView::new(
+        WidgetGalleryEnum::ScrollView,
+        Vector2D::new(250.0, 200.0),
+        scroll_content,
+        style_master.clone(),
+    )?;
+    scroll_view.set_position(Vector2D::new(700.0, 450.0));
+    window_builder.add_widget(8, scroll_view);
+
+    // Emulator memory addresses, shown and typed in hex
+    let mut address = SpinBox::new_int(
+        WidgetGalleryEnum::AddressSpinBox,
+        0x8000,
+        0,
+        0xFFFF,
+        style_master.clone(),
+    )?;
+    address.set_step(16.0);
+    address.set_formatter(
+        |value| format!("0x{:04X}", value as i64),
+        |text| {
+            let text = text.trim();
+            let digits = text
+                .strip_prefix("0x")
+                .or_else(|| text.strip_prefix("0X"))
+                .unwrap_or(text);
+            i64::from_str_radix(digits, 16)
+                .ok()
+                .map(|value| value as f64)
+        },
+    );
+    address.set_event_value_changed(|root, _wid, value| {
+        root.set_status_text(&format!("Address 0x{:04X}", value as i64))
+            .expect("widget_gallery:main:address set_status_text");
+    });
+    address.set_position(Vector2D::new(100.0, 200.0));
+    window_builder.add_widget(9, address);
+    let mut scale = SpinBox::new_float(
+        WidgetGalleryEnum::ScaleSpinBox,
+        1.0,
+        0.25,
+        4.0,
+        2,
+        style_master.clone(),
+    )?;
+    scale.set_step(0.25);
+    scale.set_event_value_changed(|root, _wid, value| {
+        root.set_status_text(&format!("Scale {:.2}", value))
+            .expect("widget_gallery:main:scale set_status_text");
+    });
+    scale.set_position(Vector2D::new(350.0, 200.0));
+    window_builder.add_widget(10, scale);
+    let today = Date::today();
+    let mut date_picker =
+        DatePicker::new(WidgetGalleryEnum::DatePicker, today, style_master.clone())?;
+    date_picker.set_range(Some(today.add_months(-6)), Some(today.add_months(6)))?;
+    date_picker.set_first_weekday(Weekday::Sunday);
+    date_picker.set_event_date_selected(|root, _wid, date| {
+        root.set_status_text(&date.describe())
+            .expect("widget_gallery:main:date_picker set_status_text");
+    });
+    date_picker.set_position(Vector2D::new(550.0, 200.0));
+    window_builder.add_widget(11, date_picker);
+    let mut label = Label::new(
+        WidgetGalleryEnum::Label,
+        "Labels wrap their text to the width they are given and can be aligned left, \
+        centered, to the right or justified like this one.\n\
+        When there isn't room for every line the last one shown ends with an ellipsis, \
+        so this sentence is cut somewhere.",
+        style_master.clone(),
+    )?;
+    label.set_align(TextAlign::Justify);
+    label.set_max_size(Some(Vector2D::new(550.0, 90.0)));
+    label.set_position(Vector2D::new(100.0, 640.0));
+    window_builder.add_widget(12, label);
+    let samples = [0.3, 0.8, 0.55, 0.9, 0.4, 0.65];
+    let mut canvas = Canvas::new(
+        WidgetGalleryEnum::Canvas,
+        Vector2D::new(220.0, 160.0),
+        move |painter| {
+            let (width, height) = painter.size().unpack();
+            let white = Color::new(224, 224, 224, 255);
+            painter.text(Vector2D::new(8.0, 4.0), "Canvas", 18.0, white.clone());
+            let bar_width = (width - 16.0) / samples.len() as f32;
+            let mut tops = vec![];
+            for (index, sample) in samples.iter().enumerate() {
+                let bar_height = (height - 40.0) * sample;
+                let x = 8.0 + bar_width * index as f32;
+                let y = height - 8.0 - bar_height;
+                let bar = Rect::new(x + 2.0, y, bar_width - 4.0, bar_height);
+                painter.fill_rect(bar, Color::new(0, 96, 192, 255));
+                tops.push(Vector2D::new(x + bar_width / 2.0, y));
+            }
+            painter.polyline(&tops, 2.0, Color::new(255, 192, 0, 255));
+            for top in tops {
+                painter.fill_circle(top, 4.0, Color::new(255, 192, 0, 255));
+            }
+            painter.line(
+                Vector2D::new(8.0, height - 8.0),
+                Vector2D::new(width - 8.0, height - 8.0),
+                1.0,
+                white,
+            );
+        },
+        style_master.clone(),
+    )?;
+    canvas.set_position(Vector2D::new(750.0, 220.0));
+    window_builder.add_widget(13, canvas);
+    let resolution = (64, 48);
+    let mut framebuffer = Framebuffer::new(
+        WidgetGalleryEnum::Framebuffer,
+        Vector2D::new(220.0, 180.0),
+        resolution,
+        style_master.clone(),
+    )?;
+    framebuffer.set_scaling(FramebufferScaling::Integer);
+    framebuffer.set_position(Vector2D::new(750.0, 20.0));
+    // Stands in for an emulator, scrolling diagonal stripes at about 60 fps
+    let handle = framebuffer.handle();
+    thread::spawn(move || {
+        let palette: Vec<Color> = (0..16)
+            .map(|shade| Color::new(shade * 16, 64, 255 - shade * 16, 255))
+            .collect();
+        let (width, height) = (resolution.0 as usize, resolution.1 as usize);
+        let mut indices = vec![0; width * height];
+        for frame in 0usize.. {
+            for (index, pixel) in indices.iter_mut().enumerate() {
+                let (x, y) = (index % width, index / width);
+                *pixel = ((x + y + frame) / 4 % palette.len()) as u8;
+            }
+            if handle.set_indexed(&indices, &palette).is_err() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(16));
+        }
+    });
+    window_builder.add_widget(14, framebuffer);
+    let mut splitter = Splitter::new(
+        WidgetGalleryEnum::Splitter,
+        Vector2D::new(550.0, 60.0),
+        Direction::Horizontal,
+        style_master.clone(),
+    )?;
+    let panes = [
+        (
+            WidgetGalleryEnum::SplitterLeft,
+            WidgetGalleryEnum::SplitterLeftText,
+            "Drag the divider",
+        ),
+        (
+            WidgetGalleryEnum::SplitterRight,
+            WidgetGalleryEnum::SplitterRightText,
+            "or double click it",
+        ),
+    ];
+    for (pane_wid, text_wid, text) in panes {
+        let mut pane = Compound::new(pane_wid, Direction::Vertical, style_master.clone())?;
+        pane.add_widget(TextBox::new(text_wid, text, style_master.clone())?);
+        splitter.add_pane(pane);
+    }
+    splitter.set_ratios(&[0.4, 0.6]);
+    splitter.set_pane_limits(1, 100.0, f32::MAX);
+    splitter.set_position(Vector2D::new(100.0, 570.0));
+    window_builder.add_widget(15, splitter);
+
     sdl_engine.add_window_builder(window_builder)?;
 
     // let mut w2 = WindowBuilder::new()?;
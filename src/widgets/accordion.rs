@@ -0,0 +1,486 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForAccordion, ThemeStyleForAccordion,
+};
+use crate::widgets::events::{DeferredEvents, MouseButtonDownCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{
+    CommonWidget, Compound, Container, DowncastableBorrowedWidget, HasTooltip, OwnedDynWidget,
+    WidgetEnum, WidgetId,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+// How long a section takes to open or close
+const SLIDE: Duration = Duration::from_millis(200);
+
+/// What themes get to draw of each section. openness goes from 0 when closed to 1 when open,
+/// it's in between while the section slides.
+#[derive(Debug, Clone)]
+pub struct AccordionSectionFrame {
+    pub title: String,
+    pub openness: f32,
+    pub content_size: Vector2D<f32>,
+}
+
+/// Where the theme placed each header and the visible part of each content,
+/// relative to the Accordion.
+#[derive(Debug, Clone)]
+pub struct AccordionLayout {
+    pub size: Vector2D<f32>,
+    pub headers: Vec<Rect<f32>>,
+    pub contents: Vec<Rect<f32>>,
+}
+
+#[derive(Debug)]
+struct AccordionSection {
+    title: String,
+    content: Compound,
+    expanded: bool,
+    // Where the section started sliding from, and when
+    slide: Option<(f32, Instant)>,
+}
+
+impl AccordionSection {
+    fn openness(&self) -> f32 {
+        let target = if self.expanded { 1.0 } else { 0.0 };
+        match self.slide {
+            Some((from, since)) => {
+                let progress = (since.elapsed().as_secs_f32() / SLIDE.as_secs_f32()).min(1.0);
+                from + (target - from) * progress
+            }
+            None => target,
+        }
+    }
+}
+
+/// Sections stacked one under the other, clicking a header opens or closes its section.
+/// The Accordion changes height while they slide, so whatever is after it moves along.
+#[derive(Debug)]
+pub struct Accordion {
+    common: CommonWidget,
+    sections: Vec<AccordionSection>,
+    exclusive: bool,
+    focused_section: Option<usize>,
+    layout: AccordionLayout,
+    translated_geometry: Geometry,
+}
+
+impl Accordion {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Accordion, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForAccordion =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForAccordion> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let layout = theme.new_accordion(&[], None, style, &mut prim_man);
+        Ok(Accordion {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                layout.size.clone(),
+                style_master,
+                prim_man,
+            ),
+            sections: vec![],
+            exclusive: false,
+            focused_section: None,
+            layout,
+            translated_geometry: Default::default(),
+        })
+    }
+    /// Returns its index. It starts open or closed without sliding.
+    pub fn add_section(&mut self, title: &str, content: Compound, expanded: bool) -> usize {
+        if expanded && self.exclusive {
+            self.close_all();
+        }
+        self.sections.push(AccordionSection {
+            title: title.to_string(),
+            content,
+            expanded,
+            slide: None,
+        });
+        self.refresh();
+        self.sections.len() - 1
+    }
+    pub fn section_count(&self) -> usize {
+        self.sections.len()
+    }
+    pub fn section(&self, index: usize) -> Option<&Compound> {
+        self.sections.get(index).map(|section| &section.content)
+    }
+    pub fn section_mut(&mut self, index: usize) -> Option<&mut Compound> {
+        self.sections
+            .get_mut(index)
+            .map(|section| &mut section.content)
+    }
+    pub fn is_expanded(&self, index: usize) -> bool {
+        self.sections
+            .get(index)
+            .is_some_and(|section| section.expanded)
+    }
+    /// Slides the section open or closed, like clicking its header.
+    pub fn set_expanded(&mut self, index: usize, expanded: bool) {
+        if self.sections.get(index).map(|section| section.expanded) != Some(!expanded) {
+            return;
+        }
+        if expanded && self.exclusive {
+            self.close_all();
+        }
+        Self::slide(&mut self.sections[index], expanded);
+        self.refresh();
+    }
+    pub fn is_exclusive(&self) -> bool {
+        self.exclusive
+    }
+    /// Only one section open at a time, opening one closes the others.
+    pub fn set_exclusive(&mut self, exclusive: bool) {
+        self.exclusive = exclusive;
+        if let Some(first) = self.sections.iter().position(|section| section.expanded) {
+            if exclusive {
+                for section in self.sections.iter_mut().skip(first + 1) {
+                    Self::slide(section, false);
+                }
+                self.refresh();
+            }
+        }
+    }
+    fn slide(section: &mut AccordionSection, expanded: bool) {
+        if section.expanded == expanded {
+            return;
+        }
+        // Turning around halfway starts from where the section is
+        section.slide = Some((section.openness(), Instant::now()));
+        section.expanded = expanded;
+    }
+    fn close_all(&mut self) {
+        for section in &mut self.sections {
+            Self::slide(section, false);
+        }
+    }
+    fn is_sliding(&self) -> bool {
+        self.sections.iter().any(|section| section.slide.is_some())
+    }
+    fn frame(&self) -> Vec<AccordionSectionFrame> {
+        self.sections
+            .iter()
+            .map(|section| AccordionSectionFrame {
+                title: section.title.clone(),
+                openness: section.openness(),
+                content_size: Vector2D::new(section.content.width(), section.content.height()),
+            })
+            .collect()
+    }
+    fn set_contents_position(&mut self) {
+        let (x, y) = self.common.content_position().unpack();
+        for (section, rect) in self.sections.iter_mut().zip(&self.layout.contents) {
+            section
+                .content
+                .set_position(Vector2D::new(x + rect.x(), y + rect.y()));
+        }
+    }
+    /// Only what is visible of a section's content.
+    fn section_at(&self, local: &Vector2D<f32>) -> Option<usize> {
+        self.layout
+            .contents
+            .iter()
+            .position(|rect| rect.height() > 0.0 && rect.contains_point(local.clone()))
+    }
+    /// Tells the section that had the focus when it loses it.
+    fn focus_section(&mut self, section: Option<usize>) -> DeferredEvents {
+        let old_section = std::mem::replace(&mut self.focused_section, section);
+        match old_section.filter(|&old_section| Some(old_section) != section) {
+            Some(old_section) => self.sections[old_section]
+                .content
+                .handle_focus_changed(false),
+            None => vec![],
+        }
+    }
+    fn refresh(&mut self) {
+        let frame = self.frame();
+        for section in &mut self.sections {
+            if section
+                .slide
+                .is_some_and(|(_, since)| since.elapsed() >= SLIDE)
+            {
+                section.slide = None;
+            }
+        }
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForAccordion =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForAccordion> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout = theme.set_accordion(&frame, size_for_clipping, style, prim_man);
+            self.layout.size.clone()
+        });
+        self.set_contents_position();
+    }
+}
+
+impl Primitive for Accordion {
+    fn class_name() -> &'static str {
+        "Accordion"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position);
+        self.set_contents_position();
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        let (x, y) = self.common.content_position().unpack();
+        let mut geometries = vec![self.common.translate_geometry()];
+        for (section, rect) in self.sections.iter_mut().zip(&self.layout.contents) {
+            if rect.height() <= 0.0 {
+                continue;
+            }
+            // Sliding sections show only their top part
+            let mut geometry = section.content.translate_geometry();
+            geometry.clip(&Rect::new(
+                x + rect.x(),
+                y + rect.y(),
+                rect.width(),
+                rect.height(),
+            ));
+            geometries.push(geometry);
+        }
+        self.translated_geometry = Geometry::new_from_geometries(Self::class_name(), geometries);
+        self.translated_geometry.clone()
+    }
+}
+
+impl PrivatePrimitiveMethods for Accordion {
+    fn update_geometry(&mut self) {
+        let resized = self.sections.iter_mut().any(|section| {
+            let (width, height) = (section.content.width(), section.content.height());
+            section.content.update_geometry();
+            section.content.width() != width || section.content.height() != height
+        });
+        if resized || self.is_sliding() {
+            self.refresh();
+        }
+        if self.common.needs_update() {
+            self.common.update_geometry();
+        }
+    }
+    /// Every frame while a section slides.
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+            || self.is_sliding()
+            || self
+                .sections
+                .iter()
+                .any(|section| section.content.needs_update())
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update);
+        for section in &mut self.sections {
+            section.content.set_needs_update(needs_update);
+        }
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+            || self
+                .sections
+                .iter()
+                .any(|section| section.content.needs_translation())
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation);
+        for section in &mut self.sections {
+            section.content.set_needs_translation(needs_translation);
+        }
+    }
+    fn clone_geometry(&self) -> Geometry {
+        let mut geometries = vec![self.common.clone_geometry()];
+        geometries.extend(
+            self.sections
+                .iter()
+                .map(|section| section.content.clone_geometry()),
+        );
+        Geometry::new_from_geometries(Self::class_name(), geometries)
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+impl HasEvents for Accordion {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Accordion {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Accordion {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let local = self.common.local(x, y);
+        if let Some(header) = self
+            .layout
+            .headers
+            .iter()
+            .position(|rect| rect.contains_point(local.clone()))
+        {
+            let deferred_events = self.focus_section(None);
+            let expanded = self.sections[header].expanded;
+            self.set_expanded(header, !expanded);
+            return deferred_events;
+        }
+        let section = self.section_at(&local);
+        let mut deferred_events = self.focus_section(section);
+        if let Some(index) = section {
+            deferred_events.extend(self.sections[index].content.handle_mouse_button_down(x, y));
+        }
+        deferred_events
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        match self.focused_section {
+            Some(index) => self.sections[index].content.handle_mouse_drag(x, y),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_button_up(&mut self, x: i32, y: i32) -> DeferredEvents {
+        match self.focused_section {
+            Some(index) => self.sections[index].content.handle_mouse_button_up(x, y),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) -> DeferredEvents {
+        match self.section_at(&self.common.local(x, y)) {
+            Some(index) => self.sections[index]
+                .content
+                .handle_mouse_wheel(x, y, dx, dy),
+            None => vec![],
+        }
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        match self.focused_section {
+            Some(index) => self.sections[index].content.handle_key_down(key, keymod),
+            None => vec![],
+        }
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        self.focused_section
+            .is_some_and(|index| self.sections[index].content.wants_key(key))
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        match self.focused_section {
+            Some(index) => self.sections[index].content.handle_text_input(text),
+            None => vec![],
+        }
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        match self.focused_section {
+            Some(index) => self.sections[index].content.handle_focus_changed(focused),
+            None => vec![],
+        }
+    }
+    fn as_container(&self) -> Option<&dyn Container> {
+        Some(self)
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        Some(self)
+    }
+}
+
+/// Clicks only reach what is visible of an open section.
+impl Container for Accordion {
+    fn child_wids(&self) -> Vec<WidgetId> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.content.child_wids())
+            .collect()
+    }
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget> {
+        self.sections
+            .iter()
+            .find_map(|section| section.content.child(wid))
+    }
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        let index = self.section_at(&self.common.local(x, y))?;
+        self.sections[index].content.child_that_accepts_click(x, y)
+    }
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+        self.sections
+            .iter_mut()
+            .find_map(|section| section.content.borrow_child(wid))
+    }
+    fn return_borrowed_widgets(&mut self) {
+        for section in &mut self.sections {
+            section.content.return_borrowed_widgets();
+        }
+    }
+}
@@ -1,21 +1,36 @@
 use std::any::TypeId;
 use std::error::Error;
 use std::fmt::Debug;
-use std::sync::Arc;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 
-use crate::general::{Geometry, Vector2D};
+use crate::general::{Geometry, SizeConstraints, Vector2D};
+use crate::texture::{RAMSoftTexture, SoftTexture};
 use crate::themes::{PrimitivesManagerForThemes, StyleMaster, ThemeForButton, ThemeStyleForButton};
 use crate::widgets::events::MouseButtonDownCallback;
 use crate::widgets::primitives::private::PrivatePrimitiveMethods;
 use crate::widgets::primitives::Primitive;
-use crate::widgets::{CommonWidget, WidgetEnum};
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
 
 use super::events::HasEvents;
 use super::Widget;
 
+/// Where the icon goes, relative to the text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IconPlacement {
+    #[default]
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
 #[derive(Debug)]
 pub struct Button {
     common: CommonWidget,
+    text: String,
+    icon: Option<Arc<Mutex<dyn SoftTexture>>>,
+    icon_placement: IconPlacement,
 }
 
 impl Button {
@@ -33,16 +48,76 @@ impl Button {
         let size = theme.new_button(text, None, style, &mut prim_man);
         Ok(Button {
             common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            text: text.to_string(),
+            icon: None,
+            icon_placement: IconPlacement::default(),
         })
     }
+    /// For toolbars, the text can be empty to only show the icon.
+    pub fn new_with_icon<WENUM: WidgetEnum>(
+        wid: WENUM,
+        icon: Box<Path>,
+        text: &str,
+        placement: IconPlacement,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Button, Box<dyn Error>> {
+        let icon: Arc<Mutex<dyn SoftTexture>> = Arc::new(Mutex::new(RAMSoftTexture::from_bmp(icon)?));
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForButton =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForButton> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let size = theme.new_icon_button(icon.clone(), text, placement, None, style, &mut prim_man);
+        Ok(Button {
+            common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            text: text.to_string(),
+            icon: Some(icon),
+            icon_placement: placement,
+        })
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
     pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.refresh();
+    }
+    /// Only for buttons made with new_with_icon().
+    pub fn set_icon(&mut self, icon: Box<Path>) -> Result<(), Box<dyn Error>> {
+        if self.icon.is_none() {
+            return Err(Box::from("button:Button:set_icon the button was made without an icon"));
+        }
+        self.icon = Some(Arc::new(Mutex::new(RAMSoftTexture::from_bmp(icon)?)));
+        self.refresh();
+        Ok(())
+    }
+    pub fn icon_placement(&self) -> IconPlacement {
+        self.icon_placement
+    }
+    pub fn set_icon_placement(&mut self, placement: IconPlacement) {
+        self.icon_placement = placement;
+        if self.icon.is_some() {
+            self.refresh();
+        }
+    }
+    /// With size constraints or a size from a layout the text is centered, or cut if it doesn't fit.
+    fn refresh(&mut self) {
         let type_id = TypeId::of::<Self>();
         let binding = self.common.style_master();
         let theme: &dyn ThemeForButton = binding.expect_theme_for_widget_t(type_id, Self::class_name());
-        let style: Box<ThemeStyleForButton> = binding.expect_style_for_widget_t(type_id, Self::class_name());
-        let size = theme.set_text(text, None, style, self.common.prim_man());
+        let constraints = self.common.size_constraints();
+        let layout_size = self.common.layout_size().cloned();
+        let (text, icon, placement) = (&self.text, &self.icon, self.icon_placement);
+        let prim_man = self.common.prim_man();
+        let size = constraints.fit(layout_size.as_ref(), |size_for_clipping| {
+            let style: Box<ThemeStyleForButton> = binding.expect_style_for_widget_t(type_id, Self::class_name());
+            match icon {
+                Some(icon) => theme.set_icon_button(icon.clone(), text, placement, size_for_clipping, style, prim_man),
+                None => theme.set_text(text, size_for_clipping, style, prim_man),
+            }
+        });
         self.common.set_size(size);
-        // self.set_needs_update(true);
     }
 }
 
@@ -125,4 +200,25 @@ impl HasEvents for Button {
     }
 }
 
-impl Widget for Button {}
+impl HasTooltip for Button {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Button {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+}
@@ -0,0 +1,425 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Date, Geometry, Rect, SizeConstraints, Vector2D, Weekday};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForCalendar, ThemeStyleForCalendar,
+};
+use crate::widgets::events::{
+    DateSelected, DateSelectedCallback, DeferredEvent, DeferredEvents, MouseButtonDownCallback,
+};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+// Always six weeks, so the calendar doesn't change its height between months
+const CALENDAR_DAYS: usize = 6 * 7;
+
+/// A cell of the month grid, it's what themes get to draw.
+#[derive(Debug, Clone)]
+pub struct CalendarDay {
+    pub date: Date,
+    pub in_month: bool,
+    pub selected: bool,
+    pub today: bool,
+    pub enabled: bool,
+}
+
+/// weekdays starts with the first weekday, days are the grid row by row.
+#[derive(Debug, Clone)]
+pub struct CalendarFrame {
+    pub title: String,
+    pub weekdays: Vec<Weekday>,
+    pub days: Vec<CalendarDay>,
+    pub can_go_previous: bool,
+    pub can_go_next: bool,
+}
+
+/// Where the theme placed things, relative to the calendar. days matches CalendarFrame::days.
+#[derive(Debug, Clone)]
+pub struct CalendarLayout {
+    pub size: Vector2D<f32>,
+    pub previous: Rect<f32>,
+    pub next: Rect<f32>,
+    pub days: Vec<Rect<f32>>,
+}
+
+/// Month grid to pick a day, optionally between a min and a max date.
+/// Arrows move by day and week, PageUp/PageDown by month, with Shift by year.
+#[derive(Debug)]
+pub struct Calendar {
+    common: CommonWidget,
+    selected: Date,
+    shown: Date, // First day of the month shown
+    min: Option<Date>,
+    max: Option<Date>,
+    first_weekday: Weekday,
+    days: Vec<Date>,
+    layout: CalendarLayout,
+    event_date_selected: DateSelected,
+}
+
+impl Calendar {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        selected: Date,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Calendar, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForCalendar =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForCalendar> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut calendar = Calendar {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                Vector2D::new(0.0, 0.0),
+                style_master.clone(),
+                PrimitivesManagerForThemes::new(),
+            ),
+            selected,
+            shown: selected.first_of_month(),
+            min: None,
+            max: None,
+            first_weekday: Weekday::Monday,
+            days: vec![],
+            layout: CalendarLayout {
+                size: Vector2D::new(0.0, 0.0),
+                previous: Rect::new(0.0, 0.0, 0.0, 0.0),
+                next: Rect::new(0.0, 0.0, 0.0, 0.0),
+                days: vec![],
+            },
+            event_date_selected: Default::default(),
+        };
+        let frame = calendar.frame();
+        calendar.layout = theme.new_calendar(&frame, None, style, calendar.common.prim_man());
+        calendar.common.set_size(calendar.layout.size.clone());
+        Ok(calendar)
+    }
+    pub fn selected(&self) -> Date {
+        self.selected
+    }
+    /// Clamped to the range and shows its month, doesn't call the date selected callback.
+    pub fn set_selected(&mut self, date: Date) {
+        self.selected = self.clamp(date);
+        self.shown = self.selected.first_of_month();
+        self.refresh();
+    }
+    /// The first day of the month shown, its last_of_month() tells how long it is.
+    pub fn shown_month(&self) -> Date {
+        self.shown
+    }
+    /// Shows the month of the date without selecting anything.
+    pub fn show_month(&mut self, date: Date) {
+        self.shown = date.first_of_month();
+        self.refresh();
+    }
+    pub fn min(&self) -> Option<Date> {
+        self.min
+    }
+    pub fn max(&self) -> Option<Date> {
+        self.max
+    }
+    pub fn set_range(
+        &mut self,
+        min: Option<Date>,
+        max: Option<Date>,
+    ) -> Result<(), Box<dyn Error>> {
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err(Box::from(format!(
+                    "calendar:Calendar:set_range min {} is after max {}",
+                    min, max
+                )));
+            }
+        }
+        self.min = min;
+        self.max = max;
+        self.set_selected(self.selected);
+        Ok(())
+    }
+    pub fn first_weekday(&self) -> Weekday {
+        self.first_weekday
+    }
+    /// Monday by default.
+    pub fn set_first_weekday(&mut self, first_weekday: Weekday) {
+        self.first_weekday = first_weekday;
+        self.refresh();
+    }
+    pub fn event_date_selected(&self) -> Arc<DateSelectedCallback> {
+        self.event_date_selected.callback.clone()
+    }
+    pub fn set_event_date_selected(&mut self, callback: DateSelectedCallback) {
+        self.event_date_selected = DateSelected {
+            callback: Arc::new(callback),
+        }
+    }
+    /// What a screen reader should say about it, e.g. when it's opened.
+    pub fn announcement(&self) -> String {
+        format!(
+            "Calendar, {}. Selected {}",
+            self.month_announcement(),
+            self.selected.describe()
+        )
+    }
+    /// The day under the point, in window coordinates, None if it's outside the range.
+    pub fn day_at(&self, x: i32, y: i32) -> Option<Date> {
+        let local = self.common.local(x, y);
+        self.layout
+            .days
+            .iter()
+            .position(|cell| cell.contains_point(local.clone()))
+            .and_then(|index| self.days.get(index).copied())
+            .filter(|date| self.is_enabled(*date))
+    }
+    pub fn is_enabled(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min) && self.max.is_none_or(|max| date <= max)
+    }
+    fn clamp(&self, date: Date) -> Date {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+    fn can_go_previous(&self) -> bool {
+        self.min.is_none_or(|min| min < self.shown)
+    }
+    fn can_go_next(&self) -> bool {
+        self.max.is_none_or(|max| max >= self.shown.add_months(1))
+    }
+    fn month_announcement(&self) -> String {
+        let last = self.shown.last_of_month();
+        format!(
+            "{} {}, {} days, the last one is a {}",
+            self.shown.name_of_month(),
+            self.shown.year(),
+            last.day(),
+            last.weekday().name()
+        )
+    }
+    fn announce(text: String) -> DeferredEvent {
+        Box::new(move |root| {
+            root.announce(&text)
+                .expect("calendar:Calendar:announce deferred events are run by the window")
+        })
+    }
+    /// Selects it as if the user did, calling the date selected callback if it changed.
+    fn select_by_user(&mut self, date: Date) -> DeferredEvents {
+        let date = self.clamp(date);
+        let month_changed = date.first_of_month() != self.shown;
+        if date == self.selected && !month_changed {
+            return vec![];
+        }
+        self.selected = date;
+        self.shown = date.first_of_month();
+        self.refresh();
+        let text = if month_changed {
+            format!("{}. {}", self.month_announcement(), date.describe())
+        } else {
+            date.describe()
+        };
+        vec![
+            self.event_date_selected.defer(self.wid(), date),
+            Self::announce(text),
+        ]
+    }
+    fn change_month(&mut self, months: i32) -> DeferredEvents {
+        if (months < 0 && !self.can_go_previous()) || (months > 0 && !self.can_go_next()) {
+            return vec![];
+        }
+        self.show_month(self.shown.add_months(months));
+        vec![Self::announce(self.month_announcement())]
+    }
+    fn frame(&mut self) -> CalendarFrame {
+        let offset = (self.shown.weekday().index() + 7 - self.first_weekday.index()) % 7;
+        let start = self.shown.add_days(-(offset as i64));
+        self.days = (0..CALENDAR_DAYS as i64)
+            .map(|index| start.add_days(index))
+            .collect();
+        let today = Date::today();
+        CalendarFrame {
+            title: format!("{} {}", self.shown.name_of_month(), self.shown.year()),
+            weekdays: (0..7)
+                .map(|index| self.first_weekday.after(index))
+                .collect(),
+            days: self
+                .days
+                .iter()
+                .map(|date| CalendarDay {
+                    date: *date,
+                    in_month: date.month() == self.shown.month(),
+                    selected: *date == self.selected,
+                    today: *date == today,
+                    enabled: self.is_enabled(*date),
+                })
+                .collect(),
+            can_go_previous: self.can_go_previous(),
+            can_go_next: self.can_go_next(),
+        }
+    }
+    fn refresh(&mut self) {
+        let frame = self.frame();
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForCalendar =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForCalendar> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout = theme.set_calendar(&frame, size_for_clipping, style, prim_man);
+            self.layout.size.clone()
+        });
+    }
+}
+
+impl Primitive for Calendar {
+    fn class_name() -> &'static str {
+        "Calendar"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for Calendar {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for Calendar {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Calendar {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Calendar {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let local = self.common.local(x, y);
+        if self.layout.previous.contains_point(local.clone()) {
+            return self.change_month(-1);
+        }
+        if self.layout.next.contains_point(local) {
+            return self.change_month(1);
+        }
+        match self.day_at(x, y) {
+            Some(date) => self.select_by_user(date),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_wheel(&mut self, _x: i32, _y: i32, _dx: i32, dy: i32) -> DeferredEvents {
+        match dy {
+            0 => vec![],
+            // Away from the user goes back in time, like scrolling up a list of months
+            dy if dy > 0 => self.change_month(-1),
+            _ => self.change_month(1),
+        }
+    }
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        let months = if keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD) {
+            12
+        } else {
+            1
+        };
+        let selected = self.selected;
+        let date = match key {
+            Keycode::Left => selected.add_days(-1),
+            Keycode::Right => selected.add_days(1),
+            Keycode::Up => selected.add_days(-7),
+            Keycode::Down => selected.add_days(7),
+            Keycode::PageUp => selected.add_months(-months),
+            Keycode::PageDown => selected.add_months(months),
+            Keycode::Home => selected.first_of_month(),
+            Keycode::End => selected.last_of_month(),
+            _ => return vec![],
+        };
+        self.select_by_user(date)
+    }
+}
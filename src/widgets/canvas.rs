@@ -0,0 +1,208 @@
+use std::any::{type_name, TypeId};
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::general::{Geometry, SizeConstraints, Vector2D};
+use crate::texture::{RAMSoftTexture, SoftTexture};
+use crate::themes::{PrimitivesManagerForThemes, StyleMaster, ThemeForCanvas, ThemeStyleForCanvas};
+use crate::widgets::events::MouseButtonDownCallback;
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CanvasImage, CommonWidget, HasTooltip, OwnedDynWidget, Painter, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+pub type CanvasDrawCallback = Box<dyn FnMut(&mut Painter) + Send>;
+
+// Same as ArcFnNewStyleForWidgetWrap, a dyn FnMut isn't Debug
+struct CanvasDraw(CanvasDrawCallback);
+
+impl Debug for CanvasDraw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<Fn: {}>", type_name::<dyn FnMut(&mut Painter) + Send>())
+    }
+}
+
+/// For custom drawing, the draw callback paints everything again on each redraw().
+/// The callback can own whatever it needs to know what to paint, e.g. an Arc<Mutex<_>>
+/// shared with the rest of the app, just call redraw() after changing it.
+#[derive(Debug)]
+pub struct Canvas {
+    common: CommonWidget,
+    size: Vector2D<f32>,
+    draw: CanvasDraw,
+    images: Vec<Arc<Mutex<dyn SoftTexture>>>,
+    translated_geometry: Geometry,
+}
+
+impl Canvas {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        size: Vector2D<f32>,
+        draw: impl FnMut(&mut Painter) + Send + 'static,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Canvas, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForCanvas =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForCanvas> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let theme_size = theme.new_canvas(size.clone(), None, style, &mut prim_man);
+        let mut canvas = Canvas {
+            common: CommonWidget::new(wid, Self::class_name(), theme_size, style_master, prim_man),
+            size,
+            draw: CanvasDraw(Box::new(draw)),
+            images: vec![],
+            translated_geometry: Default::default(),
+        };
+        canvas.redraw();
+        Ok(canvas)
+    }
+    pub fn set_draw(&mut self, draw: impl FnMut(&mut Painter) + Send + 'static) {
+        self.draw = CanvasDraw(Box::new(draw));
+        self.redraw();
+    }
+    /// Its own size, a layout that stretches it and its size constraints still win.
+    pub fn resize(&mut self, size: Vector2D<f32>) {
+        self.size = size;
+        self.redraw();
+    }
+    /// Loads a BMP once, so the draw callback can paint it with Painter::image().
+    pub fn load_image(&mut self, path: &Path) -> Result<CanvasImage, Box<dyn Error>> {
+        let tex = RAMSoftTexture::from_bmp(path.into())?;
+        self.images.push(Arc::new(Mutex::new(tex)));
+        Ok(CanvasImage(self.images.len() - 1))
+    }
+    /// Throws away what was painted and calls the draw callback.
+    pub fn redraw(&mut self) {
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForCanvas =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForCanvas> =
+            binding.expect_style_for_widget_t(type_id, Self::class_name());
+        let font = style.font.clone();
+        let own_size = &self.size;
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForCanvas> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            theme.set_canvas(own_size.clone(), size_for_clipping, style, prim_man)
+        });
+        let size = self.common.content_size();
+        let mut painter = Painter::new(size, font, &self.images, self.common.prim_man());
+        (self.draw.0)(&mut painter);
+    }
+}
+
+impl Primitive for Canvas {
+    fn class_name() -> &'static str {
+        "Canvas"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        // Whatever is painted outside is cut
+        let mut geometry = self.common.translate_geometry();
+        geometry.clip(&self.get_rect());
+        self.translated_geometry = geometry.clone();
+        geometry
+    }
+}
+
+impl PrivatePrimitiveMethods for Canvas {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+
+impl HasEvents for Canvas {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Canvas {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Canvas {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.redraw();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.redraw();
+    }
+}
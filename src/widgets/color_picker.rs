@@ -0,0 +1,564 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Color, Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForColorPicker, ThemeStyleForColorPicker,
+};
+use crate::widgets::events::{
+    ColorChanged, ColorChangedCallback, DeferredEvents, MouseButtonDownCallback,
+};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CommonWidget, EditableText, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+const FIELD_LABELS: [&str; 3] = ["Hex", "RGB", "HSV"];
+const HEX: usize = 0;
+const RGB: usize = 1;
+const HSV: usize = 2;
+
+/// A text entry of the picker, caret is only there while it's being typed into.
+#[derive(Debug, Clone)]
+pub struct ColorPickerField {
+    pub label: String,
+    pub text: String,
+    pub caret: Option<usize>,
+    pub valid: bool,
+}
+
+/// What themes get to draw. The hue is in degrees, saturation and value go from 0 to 1.
+#[derive(Debug, Clone)]
+pub struct ColorPickerFrame {
+    pub hue: f32,
+    pub saturation: f32,
+    pub value: f32,
+    pub alpha: u8,
+    pub fields: Vec<ColorPickerField>,
+    pub swatches: Vec<Color>,
+}
+
+/// Where the theme placed each part, relative to the picker.
+/// fields and swatches are in the same order as in the frame.
+#[derive(Debug, Clone)]
+pub struct ColorPickerLayout {
+    pub size: Vector2D<f32>,
+    pub square: Rect<f32>,
+    pub hue: Rect<f32>,
+    pub alpha: Rect<f32>,
+    pub fields: Vec<Rect<f32>>,
+    pub swatches: Vec<Rect<f32>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorPickerDrag {
+    Square,
+    Hue,
+    Alpha,
+}
+
+/// Picks a color with a saturation/value square, a hue strip and an alpha slider, by typing it
+/// as hex, RGB or HSV, or from a palette of swatches.
+/// Typed text is only applied with Enter, Tab or when it loses the focus.
+#[derive(Debug)]
+pub struct ColorPicker {
+    common: CommonWidget,
+    hue: f32,
+    saturation: f32,
+    value: f32,
+    alpha: u8,
+    palette: Vec<Color>,
+    editors: [EditableText; 3],
+    focused_field: Option<usize>,
+    dragging: Option<ColorPickerDrag>,
+    layout: ColorPickerLayout,
+    event_color_changed: ColorChanged,
+}
+
+impl ColorPicker {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        color: Color,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<ColorPicker, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForColorPicker =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForColorPicker> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut color_picker = ColorPicker {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                Vector2D::new(0.0, 0.0),
+                style_master.clone(),
+                PrimitivesManagerForThemes::new(),
+            ),
+            hue: 0.0,
+            saturation: 0.0,
+            value: 0.0,
+            alpha: 255,
+            palette: Self::default_palette(),
+            editors: Default::default(),
+            focused_field: None,
+            dragging: None,
+            layout: ColorPickerLayout {
+                size: Vector2D::new(0.0, 0.0),
+                square: Rect::new_zero(),
+                hue: Rect::new_zero(),
+                alpha: Rect::new_zero(),
+                fields: vec![],
+                swatches: vec![],
+            },
+            event_color_changed: Default::default(),
+        };
+        color_picker.take_color(&color);
+        color_picker.revert_texts();
+        let frame = color_picker.frame();
+        color_picker.layout =
+            theme.new_color_picker(&frame, None, style, color_picker.common.prim_man());
+        color_picker
+            .common
+            .set_size(color_picker.layout.size.clone());
+        Ok(color_picker)
+    }
+    pub fn color(&self) -> Color {
+        Color::from_hsv(self.hue, self.saturation, self.value, self.alpha)
+    }
+    /// Doesn't call the color changed callback. Greys keep the hue that was picked before.
+    pub fn set_color(&mut self, color: Color) {
+        self.take_color(&color);
+        self.revert_texts();
+        self.refresh();
+    }
+    /// Hue in degrees, saturation and value from 0 to 1.
+    pub fn hsv(&self) -> (f32, f32, f32) {
+        (self.hue, self.saturation, self.value)
+    }
+    pub fn palette(&self) -> &[Color] {
+        &self.palette
+    }
+    /// The swatches, clicking one picks its color.
+    pub fn set_palette(&mut self, palette: Vec<Color>) {
+        self.palette = palette;
+        self.refresh();
+    }
+    pub fn event_color_changed(&self) -> Arc<ColorChangedCallback> {
+        self.event_color_changed.callback.clone()
+    }
+    pub fn set_event_color_changed(&mut self, callback: ColorChangedCallback) {
+        self.event_color_changed = ColorChanged {
+            callback: Arc::new(callback),
+        }
+    }
+    fn default_palette() -> Vec<Color> {
+        [
+            (0, 0, 0),
+            (128, 128, 128),
+            (192, 192, 192),
+            (255, 255, 255),
+            (128, 0, 0),
+            (255, 0, 0),
+            (255, 128, 0),
+            (255, 255, 0),
+            (0, 128, 0),
+            (0, 255, 0),
+            (0, 255, 255),
+            (0, 128, 128),
+            (0, 0, 128),
+            (0, 0, 255),
+            (128, 0, 128),
+            (255, 0, 255),
+        ]
+        .iter()
+        .map(|&(r, g, b)| Color::new_opaque(r, g, b))
+        .collect()
+    }
+    fn take_color(&mut self, color: &Color) {
+        let (hue, saturation, value) = color.to_hsv();
+        // Without them the hue, or the saturation, can't be told from the color
+        if value > 0.0 {
+            if saturation > 0.0 {
+                self.hue = hue;
+            }
+            self.saturation = saturation;
+        }
+        self.value = value;
+        self.alpha = color.a();
+    }
+    fn format(&self, field: usize) -> String {
+        let color = self.color();
+        match field {
+            HEX => color.to_hex(),
+            RGB if color.a() == 255 => format!("{}, {}, {}", color.r(), color.g(), color.b()),
+            RGB => format!("{}, {}, {}, {}", color.r(), color.g(), color.b(), color.a()),
+            _ => format!(
+                "{:.0}, {:.0}, {:.0}",
+                self.hue,
+                self.saturation * 100.0,
+                self.value * 100.0
+            ),
+        }
+    }
+    /// "r, g, b" takes an optional alpha, "h, s, v" has the saturation and value in percent.
+    fn parse(&self, field: usize, text: &str) -> Option<Color> {
+        match field {
+            HEX => Color::from_hex(text),
+            RGB => {
+                let channels = text
+                    .split(',')
+                    .map(|channel| channel.trim().parse::<u8>().ok())
+                    .collect::<Option<Vec<u8>>>()?;
+                match channels[..] {
+                    [r, g, b] => Some(Color::new(r, g, b, self.alpha)),
+                    [r, g, b, a] => Some(Color::new(r, g, b, a)),
+                    _ => None,
+                }
+            }
+            _ => {
+                let (hue, saturation, value) = Self::parse_hsv(text)?;
+                Some(Color::from_hsv(hue, saturation, value, self.alpha))
+            }
+        }
+    }
+    /// Saturation and value come back from 0 to 1.
+    fn parse_hsv(text: &str) -> Option<(f32, f32, f32)> {
+        let numbers = text
+            .split(',')
+            .map(|number| number.trim().parse::<f32>().ok())
+            .collect::<Option<Vec<f32>>>()?;
+        match numbers[..] {
+            [hue, saturation, value]
+                if (0.0..=360.0).contains(&hue)
+                    && (0.0..=100.0).contains(&saturation)
+                    && (0.0..=100.0).contains(&value) =>
+            {
+                Some((hue, saturation / 100.0, value / 100.0))
+            }
+            _ => None,
+        }
+    }
+    fn frame(&self) -> ColorPickerFrame {
+        let fields = FIELD_LABELS
+            .iter()
+            .zip(&self.editors)
+            .enumerate()
+            .map(|(index, (label, editor))| ColorPickerField {
+                label: label.to_string(),
+                text: editor.text().to_string(),
+                caret: (self.focused_field == Some(index)).then_some(editor.caret()),
+                valid: self.parse(index, editor.text()).is_some(),
+            })
+            .collect();
+        ColorPickerFrame {
+            hue: self.hue,
+            saturation: self.saturation,
+            value: self.value,
+            alpha: self.alpha,
+            fields,
+            swatches: self.palette.clone(),
+        }
+    }
+    fn revert_texts(&mut self) {
+        for field in 0..self.editors.len() {
+            let text = self.format(field);
+            self.editors[field].set_text(&text);
+        }
+    }
+    /// Takes the typed text of the focused field, or goes back to the color if it doesn't parse.
+    fn apply_field(&mut self) -> DeferredEvents {
+        let Some(field) = self.focused_field else {
+            return vec![];
+        };
+        // HSV is taken as typed, so greys keep the hue that was typed
+        if field == HSV {
+            if let Some((hue, saturation, value)) = Self::parse_hsv(self.editors[field].text()) {
+                return self.change(hue, saturation, value, self.alpha);
+            }
+        }
+        match self.parse(field, self.editors[field].text()) {
+            Some(color) => self.change_color(&color),
+            None => {
+                self.revert_texts();
+                self.refresh();
+                vec![]
+            }
+        }
+    }
+    fn change_color(&mut self, color: &Color) -> DeferredEvents {
+        let old_color = self.color();
+        self.take_color(color);
+        self.changed(old_color)
+    }
+    fn change(&mut self, hue: f32, saturation: f32, value: f32, alpha: u8) -> DeferredEvents {
+        let old_color = self.color();
+        self.hue = hue.clamp(0.0, 360.0);
+        self.saturation = saturation.clamp(0.0, 1.0);
+        self.value = value.clamp(0.0, 1.0);
+        self.alpha = alpha;
+        self.changed(old_color)
+    }
+    /// Redraws, and calls the color changed callback if the color isn't old_color anymore.
+    fn changed(&mut self, old_color: Color) -> DeferredEvents {
+        self.revert_texts();
+        self.refresh();
+        let color = self.color();
+        if color == old_color {
+            return vec![];
+        }
+        vec![self.event_color_changed.defer(self.wid(), color)]
+    }
+    fn focus_field(&mut self, field: Option<usize>) -> DeferredEvents {
+        let deferred_events = self.apply_field();
+        self.focused_field = field;
+        self.refresh();
+        deferred_events
+    }
+    /// Position within the rect from 0 to 1, clamped so dragging out of it keeps working.
+    fn fraction(rect: &Rect<f32>, local: &Vector2D<f32>) -> (f32, f32) {
+        (
+            ((local.x() - rect.x()) / rect.width()).clamp(0.0, 1.0),
+            ((local.y() - rect.y()) / rect.height()).clamp(0.0, 1.0),
+        )
+    }
+    fn drag(&mut self, drag: ColorPickerDrag, local: &Vector2D<f32>) -> DeferredEvents {
+        let (hue, saturation, value, alpha) = (self.hue, self.saturation, self.value, self.alpha);
+        match drag {
+            ColorPickerDrag::Square => {
+                let (x, y) = Self::fraction(&self.layout.square, local);
+                self.change(hue, x, 1.0 - y, alpha)
+            }
+            ColorPickerDrag::Hue => {
+                let (_, y) = Self::fraction(&self.layout.hue, local);
+                self.change(y * 360.0, saturation, value, alpha)
+            }
+            ColorPickerDrag::Alpha => {
+                let (x, _) = Self::fraction(&self.layout.alpha, local);
+                self.change(hue, saturation, value, (x * 255.0).round() as u8)
+            }
+        }
+    }
+    fn refresh(&mut self) {
+        let frame = self.frame();
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForColorPicker =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForColorPicker> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout = theme.set_color_picker(&frame, size_for_clipping, style, prim_man);
+            self.layout.size.clone()
+        });
+    }
+}
+
+impl Primitive for ColorPicker {
+    fn class_name() -> &'static str {
+        "ColorPicker"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for ColorPicker {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for ColorPicker {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for ColorPicker {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for ColorPicker {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let local = self.common.local(x, y);
+        if let Some(field) = self
+            .layout
+            .fields
+            .iter()
+            .position(|rect| rect.contains_point(local.clone()))
+        {
+            let deferred_events = self.focus_field(Some(field));
+            self.editors[field].set_caret(usize::MAX);
+            self.refresh();
+            return deferred_events;
+        }
+        let mut deferred_events = self.focus_field(None);
+        let parts = [
+            (self.layout.square, ColorPickerDrag::Square),
+            (self.layout.hue, ColorPickerDrag::Hue),
+            (self.layout.alpha, ColorPickerDrag::Alpha),
+        ];
+        if let Some((_, drag)) = parts
+            .iter()
+            .find(|(rect, _)| rect.contains_point(local.clone()))
+        {
+            self.dragging = Some(*drag);
+            deferred_events.extend(self.drag(*drag, &local));
+        } else if let Some(swatch) = self
+            .layout
+            .swatches
+            .iter()
+            .position(|rect| rect.contains_point(local.clone()))
+        {
+            let color = self.palette[swatch].clone();
+            deferred_events.extend(self.change_color(&color));
+        }
+        deferred_events
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        match self.dragging {
+            Some(drag) => self.drag(drag, &self.common.local(x, y)),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_button_up(&mut self, _x: i32, _y: i32) -> DeferredEvents {
+        self.dragging = None;
+        vec![]
+    }
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        self.focused_field.is_some()
+            && matches!(
+                key,
+                Keycode::Return | Keycode::KpEnter | Keycode::Tab | Keycode::Escape
+            )
+    }
+    fn handle_key_down(&mut self, key: Keycode, _keymod: Mod) -> DeferredEvents {
+        let (hue, saturation, value, alpha) = (self.hue, self.saturation, self.value, self.alpha);
+        let Some(field) = self.focused_field else {
+            return match key {
+                Keycode::Left => self.change(hue, saturation - 0.01, value, alpha),
+                Keycode::Right => self.change(hue, saturation + 0.01, value, alpha),
+                Keycode::Up => self.change(hue, saturation, value + 0.01, alpha),
+                Keycode::Down => self.change(hue, saturation, value - 0.01, alpha),
+                Keycode::PageUp => {
+                    self.change((hue - 10.0).rem_euclid(360.0), saturation, value, alpha)
+                }
+                Keycode::PageDown => {
+                    self.change((hue + 10.0).rem_euclid(360.0), saturation, value, alpha)
+                }
+                _ => vec![],
+            };
+        };
+        match key {
+            Keycode::Return | Keycode::KpEnter => self.apply_field(),
+            Keycode::Tab => self.focus_field(Some((field + 1) % self.editors.len())),
+            Keycode::Escape => {
+                self.revert_texts();
+                self.focus_field(None)
+            }
+            _ => {
+                if self.editors[field].handle_key(key) {
+                    self.refresh();
+                }
+                vec![]
+            }
+        }
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        if let Some(field) = self.focused_field {
+            self.editors[field].insert(text);
+            self.refresh();
+        }
+        vec![]
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        if focused {
+            return vec![];
+        }
+        self.dragging = None;
+        self.focus_field(None)
+    }
+}
@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
-use crate::general::{Geometry, Vector2D};
+use crate::general::{Geometry, SizeConstraints, Vector2D};
 use crate::widgets::events::{Event, MouseButtonDown, MouseButtonDownCallback};
 use crate::widgets::primitives::private::PrivatePrimitiveMethods;
 use crate::widgets::primitives::Primitive;
-use crate::widgets::{WidgetEnum, WidgetId};
+use crate::widgets::{HasTooltip, OwnedDynWidget, WidgetEnum, WidgetId};
 
 use super::events::{HasEvents, KeyDown, KeyDownCallback};
 use super::Widget;
@@ -24,6 +24,9 @@ pub struct CommonWidget {
     class: String,
     style_master: Arc<StyleMaster>,
     prim_man: PrimitivesManagerForThemes,
+    tooltip: Option<OwnedDynWidget>,
+    size_constraints: SizeConstraints,
+    layout_size: Option<Vector2D<f32>>,
 }
 
 impl CommonWidget {
@@ -48,6 +51,9 @@ impl CommonWidget {
             class: class.to_string(),
             style_master,
             prim_man,
+            tooltip: None,
+            size_constraints: Default::default(),
+            layout_size: None,
         }
     }
     pub fn style_master(&self) -> Arc<StyleMaster> {
@@ -60,6 +66,43 @@ impl CommonWidget {
         self.size = size;
         self.set_needs_update(true);
     }
+    /// Where the theme drew the content, inside the border and padding.
+    pub fn content_position(&self) -> Vector2D<f32> {
+        let offset = self.prim_man.content_offset();
+        Vector2D::new(self.x() + offset.x(), self.y() + offset.y())
+    }
+    /// Its size inside the border and padding the theme drew.
+    pub fn content_size(&self) -> Vector2D<f32> {
+        let inset = self.prim_man.inset();
+        Vector2D::new(
+            (self.size.x() - inset.horizontal()).max(0.0),
+            (self.size.y() - inset.vertical()).max(0.0),
+        )
+    }
+    /// A point of the window relative to the content, e.g. to find what was clicked.
+    pub fn local(&self, x: i32, y: i32) -> Vector2D<f32> {
+        let position = self.content_position();
+        Vector2D::new(x as f32 - position.x(), y as f32 - position.y())
+    }
+    /// The last size a layout asked for.
+    pub fn layout_size(&self) -> Option<&Vector2D<f32>> {
+        self.layout_size.as_ref()
+    }
+    /// Builds the primitives and keeps the size, within the constraints and the size a layout
+    /// asked for. build gets the size_for_clipping for the theme, see SizeConstraints::fit().
+    pub fn fit<F>(&mut self, mut build: F) -> Vector2D<f32>
+    where
+        F: FnMut(Option<Vector2D<f32>>, &mut PrimitivesManagerForThemes) -> Vector2D<f32>,
+    {
+        let constraints = self.size_constraints;
+        let layout_size = self.layout_size.clone();
+        let prim_man = &mut self.prim_man;
+        let size = constraints.fit(layout_size.as_ref(), |size_for_clipping| {
+            build(size_for_clipping, prim_man)
+        });
+        self.set_size(size.clone());
+        size
+    }
 }
 
 impl Primitive for CommonWidget {
@@ -146,4 +189,24 @@ impl HasEvents for CommonWidget {
     }
 }
 
-impl Widget for CommonWidget {}
+impl HasTooltip for CommonWidget {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.tooltip.as_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.tooltip = tooltip
+    }
+}
+
+/// Only keeps them, the widget rebuilds its primitives.
+impl Widget for CommonWidget {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.layout_size = size;
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.size_constraints
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.size_constraints = constraints;
+    }
+}
@@ -3,17 +3,22 @@ use std::collections::{BTreeMap, HashMap};
 use std::error::Error;
 use std::fmt::Debug;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use log::{debug, info};
+use sdl2::keyboard::{Keycode, Mod};
 
-use crate::general::{Geometry, Vector2D};
+use crate::general::{Edges, Geometry, Rect, SizeConstraints, Vector2D};
 use crate::themes::StyleMaster;
 use crate::widgets::events::{
-    Event, KeyDown, KeyDownCallback, MouseButtonDown, MouseButtonDownCallback,
+    DeferredEvents, Event, KeyDown, KeyDownCallback, MouseButtonDown, MouseButtonDownCallback,
 };
 use crate::widgets::primitives::private::PrivatePrimitiveMethods;
 use crate::widgets::primitives::Primitive;
-use crate::widgets::{Direction, DowncastableBorrowedWidget, NextPositionCalculator, OwnedDynWidget, WidgetEnum, WidgetId};
+use crate::widgets::{
+    Container, Dialog, Direction, DowncastableBorrowedWidget, FlexItem, FlexLayout, GridLayout,
+    HasTooltip, NextPositionCalculator, OwnedDynWidget, ToastLevel, WidgetEnum, WidgetId,
+};
 use crate::window::Root;
 
 use super::events::HasEvents;
@@ -25,39 +30,62 @@ pub struct Compound {
     wid: WidgetId,
     position: Vector2D<f32>,
     size: Vector2D<f32>,
-    _style_master: Arc<StyleMaster>,
+    style_master: Arc<StyleMaster>,
     event_mouse_button_down: MouseButtonDown,
     event_key_down: KeyDown,
     translated_geometry: Geometry,
     borrowed: HashMap<WidgetId, DowncastableBorrowedWidget>,
+    // Of each child, read from its style once when it's added
+    margins: HashMap<WidgetId, Edges>,
     next_pos_calc: NextPositionCalculator,
+    flex: Option<FlexLayout>,
+    grid: Option<GridLayout>,
+    // Asked by whoever holds it, the FlexLayout or GridLayout is given it too
+    layout_size: Option<Vector2D<f32>>,
+    size_constraints: SizeConstraints,
+    focused_wid: Option<WidgetId>,
+    tooltip: Option<OwnedDynWidget>,
 }
 
 impl Compound {
     pub fn new<WENUM: WidgetEnum>(
         wid: WENUM,
         direction: Direction,
-        _style_master: Arc<StyleMaster>,
+        style_master: Arc<StyleMaster>,
     ) -> Result<Self, Box<dyn Error>> {
         Ok(Self {
             widgets: Default::default(),
             wid: wid.to_wid(),
             position: Default::default(),
             size: Default::default(),
-            _style_master,
+            style_master,
             event_mouse_button_down: Default::default(),
             event_key_down: Default::default(),
             translated_geometry: Default::default(),
             borrowed: Default::default(),
+            margins: Default::default(),
             next_pos_calc: NextPositionCalculator::new(direction),
+            flex: None,
+            grid: None,
+            layout_size: None,
+            size_constraints: Default::default(),
+            focused_wid: None,
+            tooltip: None,
         })
     }
     pub fn add_widget<T: Widget>(&mut self, widget: T) {
         let mut box_widget: Box<dyn Widget> = Box::new(widget);
-        let position = self.next_pos_calc.next(&mut box_widget);
+        let margin = self.style_master.box_model((*box_widget).type_id()).margin;
+        self.margins.insert(box_widget.wid(), margin);
+        if self.flex.is_some() || self.grid.is_some() {
+            self.widgets.insert(box_widget.wid(), box_widget);
+            self.update_layout();
+            return;
+        }
+        let position = self.next_pos_calc.next(&mut box_widget, &margin);
         info!("add_widget: pos: {:?}", position);
         box_widget.set_position(position);
-        self.size = self.next_pos_calc.size();
+        self.size = self.flow_size();
         info!("add_widget: size: {:?}", self.size);
         self.widgets.insert(box_widget.wid(), box_widget);
     }
@@ -69,6 +97,142 @@ impl Compound {
         }
         0
     }
+    /// Wraps the children into rows, or columns when vertical, of at most this size.
+    pub fn set_wrap_limit(&mut self, limit: Option<f32>) {
+        self.next_pos_calc.set_wrap_limit(limit);
+        self.update_layout();
+    }
+    pub fn set_gaps(&mut self, column_gap: f32, row_gap: f32) {
+        self.next_pos_calc.set_gaps(column_gap, row_gap);
+        self.update_layout();
+    }
+    pub fn flex_layout(&self) -> Option<&FlexLayout> {
+        self.flex.as_ref()
+    }
+    /// Lays the children out with a FlexLayout instead of one after the other, None goes back.
+    pub fn set_flex_layout(&mut self, mut flex: Option<FlexLayout>) {
+        if let Some(old_flex) = &mut self.flex {
+            old_flex.release(&mut self.widgets);
+        }
+        if let Some(flex) = &mut flex {
+            flex.set_layout_size(self.constrained_layout_size());
+        }
+        self.flex = flex;
+        self.update_layout();
+    }
+    /// Call update_layout() after changing it.
+    pub fn flex_layout_mut(&mut self) -> Option<&mut FlexLayout> {
+        self.flex.as_mut()
+    }
+    pub fn set_flex_item<WENUM: WidgetEnum>(&mut self, wid: WENUM, item: FlexItem) {
+        self.flex
+            .as_mut()
+            .expect("compound:Compound:set_flex_item call set_flex_layout first")
+            .set_item(wid.to_wid(), item);
+        self.update_layout();
+    }
+    /// Used by Grid, which is a Compound that can only be laid out in a grid.
+    pub(crate) fn set_grid_layout(&mut self, mut grid: GridLayout) {
+        if let Some(old_grid) = &mut self.grid {
+            old_grid.release(&mut self.widgets);
+        }
+        grid.set_layout_size(self.constrained_layout_size());
+        self.grid = Some(grid);
+        self.update_layout();
+    }
+    pub(crate) fn grid_layout(&self) -> Option<&GridLayout> {
+        self.grid.as_ref()
+    }
+    pub(crate) fn grid_layout_mut(&mut self) -> Option<&mut GridLayout> {
+        self.grid.as_mut()
+    }
+    /// Places the children one after the other again, e.g. after one of them changed size.
+    pub fn update_layout(&mut self) {
+        if let Some(flex) = &mut self.flex {
+            let size = flex.arrange(&self.position, &mut self.widgets, &self.margins);
+            self.size = self.size_constraints.clamp(&size);
+            return;
+        }
+        if let Some(grid) = &mut self.grid {
+            let size = grid.arrange(&self.position, &mut self.widgets, &self.margins);
+            self.size = self.size_constraints.clamp(&size);
+            return;
+        }
+        self.next_pos_calc.reset();
+        for (wid, dyn_widget) in self.widgets.iter_mut() {
+            let margin = self.margins.get(wid).copied().unwrap_or_default();
+            let position = self.next_pos_calc.next(dyn_widget, &margin);
+            dyn_widget.set_position(position);
+        }
+        self.size = self.flow_size();
+    }
+    /// As big as the children one after the other, or the layout size if it's bigger.
+    fn flow_size(&self) -> Vector2D<f32> {
+        let size = self.next_pos_calc.size();
+        let size = match &self.layout_size {
+            Some(layout_size) => {
+                Vector2D::new(size.x().max(layout_size.x()), size.y().max(layout_size.y()))
+            }
+            None => size,
+        };
+        self.size_constraints.clamp(&size)
+    }
+    /// What the FlexLayout or GridLayout is given, its own size is used without one.
+    fn constrained_layout_size(&self) -> Option<Vector2D<f32>> {
+        let size = self.layout_size.as_ref()?;
+        Some(self.size_constraints.clamp(size))
+    }
+    fn apply_layout_size(&mut self) {
+        let size = self.constrained_layout_size();
+        if let Some(flex) = &mut self.flex {
+            flex.set_layout_size(size);
+        } else if let Some(grid) = &mut self.grid {
+            grid.set_layout_size(size);
+        }
+        self.update_layout();
+    }
+    fn focused_child(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.focused_wid.and_then(|wid| self.widgets.get_mut(&wid))
+    }
+    pub fn focused_wid(&self) -> Option<WidgetId> {
+        self.focused_wid
+    }
+    /// Tells both children when the focus moves, like the window does.
+    pub fn set_focused_wid(&mut self, wid: Option<WidgetId>) -> DeferredEvents {
+        if self.focused_wid == wid {
+            return vec![];
+        }
+        let mut deferred_events = match self.focused_child() {
+            Some(old_child) => old_child.handle_focus_changed(false),
+            None => vec![],
+        };
+        self.focused_wid = wid;
+        if let Some(child) = self.focused_child() {
+            deferred_events.extend(child.handle_focus_changed(true));
+        }
+        deferred_events
+    }
+    /// Tab between the children that accept the focus, in wid order. Past the last one nothing
+    /// is focused, so whoever holds the Compound can move on, the next Tab starts over.
+    pub fn focus_next(&mut self, reverse: bool) -> DeferredEvents {
+        let mut wids: Vec<WidgetId> = self
+            .widgets
+            .iter()
+            .filter(|(_, child)| child.accepts_focus())
+            .map(|(wid, _)| *wid)
+            .collect();
+        if reverse {
+            wids.reverse();
+        }
+        let next = match self.focused_wid {
+            Some(focused) => wids
+                .iter()
+                .position(|wid| *wid == focused)
+                .and_then(|index| wids.get(index + 1)),
+            None => wids.first(),
+        };
+        self.set_focused_wid(next.copied())
+    }
     pub fn return_borrowed_widgets(&mut self) {
         if !self.borrowed.is_empty() {
             info!("ret_borrows len={}", self.borrowed.len());
@@ -99,6 +263,12 @@ impl Compound {
             //     .expect("Compound:return_borrowed_widgets wid to rid");
             self.widgets.insert(wid, widget); // TODO Convert to Map
         }
+        // Nested containers keep the widgets borrowed from them
+        for dyn_widget in self.widgets.values_mut() {
+            if let Some(container) = dyn_widget.as_container_mut() {
+                container.return_borrowed_widgets();
+            }
+        }
     }
 }
 
@@ -183,17 +353,19 @@ impl Primitive for Compound {
         &self.size
     }
     fn translate_geometry(&mut self) -> Geometry {
-        self.next_pos_calc.reset();
+        self.update_layout();
         let mut geometries = Vec::with_capacity(self.widgets.len());
         for dyn_widget in self.widgets.values_mut() {
             info!("translate_geometry: {}", dyn_widget.class());
-            let position = self.next_pos_calc.next(dyn_widget);
-            dyn_widget.set_position(position);
             let geometry = dyn_widget.translate_geometry();
             geometries.push(geometry);
         }
-        self.size = self.next_pos_calc.size();
-        self.translated_geometry = Geometry::new_from_geometries(Self::class_name(), geometries);
+        let mut geometry = Geometry::new_from_geometries(Self::class_name(), geometries);
+        if self.size_constraints.max_width.is_some() || self.size_constraints.max_height.is_some() {
+            let (x, y) = self.position.unpack();
+            geometry.clip(&Rect::new(x, y, self.size.x(), self.size.y()));
+        }
+        self.translated_geometry = geometry;
         self.translated_geometry.clone()
     }
 
@@ -219,6 +391,8 @@ impl PrivatePrimitiveMethods for Compound {
                 dyn_widget.update_geometry();
             }
         }
+        // So whoever holds this Compound sees its new size before placing it
+        self.update_layout();
     }
     fn needs_update(&self) -> bool {
         for dyn_widget in self.widgets.values() {
@@ -281,36 +455,190 @@ impl HasEvents for Compound {
     }
 }
 
-impl Widget for Compound {}
+impl HasTooltip for Compound {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.tooltip.as_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.tooltip = tooltip
+    }
+}
+
+impl Widget for Compound {
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let Some(child) = self.child_that_accepts_click(x, y) else {
+            return vec![];
+        };
+        let wid = child.wid();
+        let mut deferred_events = child.handle_mouse_button_down(x, y);
+        deferred_events.extend(self.set_focused_wid(Some(wid)));
+        deferred_events
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        match self.focused_child() {
+            Some(child) => child.handle_mouse_drag(x, y),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_button_up(&mut self, x: i32, y: i32) -> DeferredEvents {
+        match self.focused_child() {
+            Some(child) => child.handle_mouse_button_up(x, y),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) -> DeferredEvents {
+        match self.child_that_accepts_click(x, y) {
+            Some(child) => child.handle_mouse_wheel(x, y, dx, dy),
+            None => vec![],
+        }
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        match self.focused_child() {
+            Some(child) => child.handle_key_down(key, keymod),
+            None => vec![],
+        }
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        self.focused_wid
+            .and_then(|wid| self.widgets.get(&wid))
+            .is_some_and(|child| child.wants_key(key))
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        match self.focused_child() {
+            Some(child) => child.handle_text_input(text),
+            None => vec![],
+        }
+    }
+    /// Forgets the focused child when it loses the focus, the next click picks it again, so
+    /// the child isn't told twice.
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        let deferred_events = match self.focused_child() {
+            Some(child) => child.handle_focus_changed(focused),
+            None => vec![],
+        };
+        if !focused {
+            self.focused_wid = None;
+        }
+        deferred_events
+    }
+    /// A FlexLayout or a GridLayout lays the children out in it. Without one the children stay
+    /// one after the other and the Compound only gets bigger, it can't shrink them.
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.layout_size = size;
+        self.apply_layout_size();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.size_constraints
+    }
+    /// The children that don't fit are clipped.
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.size_constraints = constraints;
+        self.apply_layout_size();
+    }
+    fn as_container(&self) -> Option<&dyn Container> {
+        Some(self)
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        Some(self)
+    }
+}
+
+impl Container for Compound {
+    /// The children and the widgets of the nested containers.
+    fn child_wids(&self) -> Vec<WidgetId> {
+        let mut wids: Vec<WidgetId> = self.widgets.keys().cloned().collect();
+        for dyn_widget in self.widgets.values() {
+            if let Some(container) = dyn_widget.as_container() {
+                wids.extend(container.child_wids());
+            }
+        }
+        wids
+    }
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget> {
+        self.widgets.get(&wid).or_else(|| {
+            self.widgets
+                .values()
+                .filter_map(|dyn_widget| dyn_widget.as_container())
+                .find_map(|container| container.child(wid))
+        })
+    }
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        self.widgets.values_mut().find_map(|dyn_widget| {
+            let accepts = dyn_widget.will_accept_mouse_click_event(x, y);
+            accepts.then_some(dyn_widget)
+        })
+    }
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+        self.get_down_widget_by_id(wid).ok()
+    }
+    fn return_borrowed_widgets(&mut self) {
+        Compound::return_borrowed_widgets(self)
+    }
+}
 
 impl Root for Compound {
     fn get_down_widget_by_id(
         &mut self,
         wid: super::WidgetId,
-    ) -> Option<super::DowncastableBorrowedWidget> {
+    ) -> Result<super::DowncastableBorrowedWidget, String> {
         if let Some(borrowed) = self.borrowed.get(&wid) {
             info!(
                 "down_borrow strong_count={}",
                 borrowed.get_borrowed_strong_count()
             );
-            return Some(borrowed.clone());
+            return Ok(borrowed.clone());
         }
 
         info!(
             "Compound get_down_widget_by_id widgets.keys {:?}",
             self.widgets.keys()
         );
-        let opt_widget = self.widgets.remove(&wid);
-        let widget = opt_widget?;
+        let Some(widget) = self.widgets.remove(&wid) else {
+            return self
+                .widgets
+                .values_mut()
+                .filter_map(|dyn_widget| dyn_widget.as_container_mut())
+                .find_map(|container| container.borrow_child(wid))
+                .ok_or_else(|| format!("Not found: wid={} isn't in the Compound", wid));
+        };
         let class = widget.class();
         let type_id = widget.type_id();
         let dyn_widget = Arc::new(Mutex::new(widget));
         let dowcastable = DowncastableBorrowedWidget::new(type_id, dyn_widget, class);
         self.borrowed.insert(wid, dowcastable.clone());
-        Some(dowcastable)
+        Ok(dowcastable)
     }
 
     fn children(&self) -> &BTreeMap<WidgetId, OwnedDynWidget> {
         &self.widgets
     }
+
+    fn show_dialog(&mut self, _dialog: Dialog) -> Result<(), Box<dyn Error>> {
+        Err(Box::from(
+            "Compound:show_dialog dialogs can only be shown by a window",
+        ))
+    }
+
+    fn notify(
+        &mut self,
+        _text: &str,
+        _level: ToastLevel,
+        _duration: Duration,
+    ) -> Result<(), Box<dyn Error>> {
+        Err(Box::from(
+            "Compound:notify notifications can only be shown by a window",
+        ))
+    }
+
+    fn set_status_text(&mut self, _text: &str) -> Result<(), Box<dyn Error>> {
+        Err(Box::from(
+            "Compound:set_status_text only windows have a status bar",
+        ))
+    }
+
+    fn announce(&mut self, _text: &str) -> Result<(), Box<dyn Error>> {
+        Err(Box::from(
+            "Compound:announce announcements can only be made by a window",
+        ))
+    }
 }
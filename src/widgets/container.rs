@@ -0,0 +1,36 @@
+use crate::widgets::{DowncastableBorrowedWidget, OwnedDynWidget, WidgetId};
+
+/// A widget that owns other widgets, the window uses it to reach them for events and lookups.
+/// Containers can be nested, child_wids() and borrow_child() reach the widgets at any depth.
+pub trait Container {
+    fn child_wids(&self) -> Vec<WidgetId>;
+    /// The widget, no matter how deep it's nested. None while it's borrowed.
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget>;
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget>;
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget>;
+    fn return_borrowed_widgets(&mut self);
+    /// The widgets under the pointer, from the direct child down to the innermost one.
+    fn wids_at(&mut self, x: i32, y: i32) -> Vec<WidgetId> {
+        let Some(child) = self.child_that_accepts_click(x, y) else {
+            return vec![];
+        };
+        let mut wids = vec![child.wid()];
+        if let Some(container) = child.as_container_mut() {
+            wids.extend(container.wids_at(x, y));
+        }
+        wids
+    }
+    /// The widget under the pointer that many containers down, 0 is the direct child.
+    fn child_at_depth(&mut self, x: i32, y: i32, depth: usize) -> Option<&mut OwnedDynWidget> {
+        let child = self.child_that_accepts_click(x, y)?;
+        if depth == 0 {
+            return Some(child);
+        }
+        child.as_container_mut()?.child_at_depth(x, y, depth - 1)
+    }
+    /// The widget under the pointer, no matter how deep it's nested.
+    fn innermost_child_at(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        let depth = self.wids_at(x, y).len().checked_sub(1)?;
+        self.child_at_depth(x, y, depth)
+    }
+}
@@ -0,0 +1,307 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Date, Geometry, Rect, SizeConstraints, Vector2D, Weekday};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForDatePicker, ThemeStyleForDatePicker,
+};
+use crate::widgets::events::{
+    DateSelected, DateSelectedCallback, DeferredEvents, MouseButtonDownCallback,
+};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{Calendar, CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// Where the theme placed the button that opens the calendar, relative to the picker.
+#[derive(Debug, Clone)]
+pub struct DatePickerLayout {
+    pub size: Vector2D<f32>,
+    pub button: Rect<f32>,
+}
+
+/// Shows a date and opens a Calendar below it to change it.
+/// Space, Enter or Alt+Down open it, Escape closes it, picking a day closes it too.
+#[derive(Debug)]
+pub struct DatePicker {
+    common: CommonWidget,
+    calendar: Calendar,
+    open: bool,
+    layout: DatePickerLayout,
+    event_date_selected: DateSelected,
+}
+
+impl DatePicker {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        date: Date,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<DatePicker, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForDatePicker =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForDatePicker> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let layout = theme.new_date_picker(&date.to_string(), false, None, style, &mut prim_man);
+        let size = layout.size.clone();
+        Ok(DatePicker {
+            // Never looked up, it lives inside the picker
+            calendar: Calendar::new(wid, date, style_master.clone())?,
+            common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            open: false,
+            layout,
+            event_date_selected: Default::default(),
+        })
+    }
+    pub fn date(&self) -> Date {
+        self.calendar.selected()
+    }
+    /// Clamped to the range, doesn't call the date selected callback.
+    pub fn set_date(&mut self, date: Date) {
+        self.calendar.set_selected(date);
+        self.refresh();
+    }
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+    pub fn set_range(
+        &mut self,
+        min: Option<Date>,
+        max: Option<Date>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.calendar.set_range(min, max)?;
+        self.refresh();
+        Ok(())
+    }
+    pub fn set_first_weekday(&mut self, first_weekday: Weekday) {
+        self.calendar.set_first_weekday(first_weekday);
+    }
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+    pub fn set_open(&mut self, open: bool) {
+        if self.open != open {
+            self.open = open;
+            if open {
+                // Back to the selected month, it may have been left somewhere else
+                self.calendar.show_month(self.calendar.selected());
+            }
+            self.refresh();
+        }
+    }
+    pub fn event_date_selected(&self) -> Arc<DateSelectedCallback> {
+        self.event_date_selected.callback.clone()
+    }
+    pub fn set_event_date_selected(&mut self, callback: DateSelectedCallback) {
+        self.event_date_selected = DateSelected {
+            callback: Arc::new(callback),
+        }
+    }
+    fn open_by_user(&mut self) -> DeferredEvents {
+        self.set_open(true);
+        let text = self.calendar.announcement();
+        vec![Box::new(move |root| {
+            root.announce(&text)
+                .expect("date_picker:DatePicker:open_by_user deferred events are run by the window")
+        })]
+    }
+    /// Passes the input to the calendar, and its date to the lib-user if it changed.
+    fn forward_to_calendar(
+        &mut self,
+        forward: impl FnOnce(&mut Calendar) -> DeferredEvents,
+    ) -> DeferredEvents {
+        let old_date = self.date();
+        let mut deferred_events = forward(&mut self.calendar);
+        if self.date() != old_date {
+            self.refresh();
+            deferred_events.push(self.event_date_selected.defer(self.wid(), self.date()));
+        }
+        deferred_events
+    }
+    fn refresh(&mut self) {
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForDatePicker =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let text = self.date().to_string();
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForDatePicker> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout =
+                theme.set_date_picker(&text, self.open, size_for_clipping, style, prim_man);
+            self.layout.size.clone()
+        });
+    }
+}
+
+impl Primitive for DatePicker {
+    fn class_name() -> &'static str {
+        "DatePicker"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for DatePicker {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for DatePicker {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for DatePicker {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for DatePicker {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        if self.open && self.calendar.will_accept_mouse_click_event(x, y) {
+            // Picking a day closes it, the arrows to change the month don't
+            let picked = self.calendar.day_at(x, y).is_some();
+            let deferred_events =
+                self.forward_to_calendar(|calendar| calendar.handle_mouse_button_down(x, y));
+            if picked {
+                self.set_open(false);
+            }
+            return deferred_events;
+        }
+        if self.open {
+            self.set_open(false);
+            vec![]
+        } else {
+            self.open_by_user()
+        }
+    }
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        match key {
+            Keycode::Return | Keycode::KpEnter => true,
+            Keycode::Escape => self.open,
+            _ => false,
+        }
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        let alt = keymod.intersects(Mod::LALTMOD | Mod::RALTMOD);
+        match key {
+            Keycode::Escape | Keycode::Return | Keycode::KpEnter | Keycode::Space if self.open => {
+                self.set_open(false);
+                vec![]
+            }
+            Keycode::Return | Keycode::KpEnter | Keycode::Space => self.open_by_user(),
+            Keycode::Down if alt && !self.open => self.open_by_user(),
+            _ if self.open => {
+                self.forward_to_calendar(|calendar| calendar.handle_key_down(key, keymod))
+            }
+            _ => vec![],
+        }
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        if !focused {
+            self.set_open(false);
+        }
+        vec![]
+    }
+    fn popup_mut(&mut self) -> Option<&mut dyn Widget> {
+        if !self.open {
+            return None;
+        }
+        let (x, y) = (self.x(), self.y() + self.height());
+        if self.calendar.x() != x || self.calendar.y() != y {
+            self.calendar.set_position(Vector2D::new(x, y));
+        }
+        Some(&mut self.calendar)
+    }
+    fn close_popup(&mut self) -> DeferredEvents {
+        self.set_open(false);
+        vec![]
+    }
+}
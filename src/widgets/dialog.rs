@@ -0,0 +1,459 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::debug;
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{PrimitivesManagerForThemes, StyleMaster, ThemeForDialog, ThemeStyleForDialog};
+use crate::widgets::events::{
+    DeferredEvents, DialogClosed, DialogClosedCallback, MouseButtonDownCallback,
+};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{
+    CommonWidget, Compound, Container, DowncastableBorrowedWidget, HasTooltip, OwnedDynWidget,
+    WidgetEnum, WidgetId,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// How a dialog was closed, Custom carries whatever the lib-user gave to add_button().
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DialogResult {
+    Ok,
+    Cancel,
+    Custom(usize),
+}
+
+/// What themes get to draw for each button.
+#[derive(Debug, Clone)]
+pub struct DialogButton {
+    pub text: String,
+    pub focused: bool,
+}
+
+/// Everything themes need to draw a dialog, dim_area is the window relative to the dialog.
+#[derive(Debug, Clone)]
+pub struct DialogFrame {
+    pub title: String,
+    pub content_size: Vector2D<f32>,
+    pub buttons: Vec<DialogButton>,
+    pub dim_area: Rect<f32>,
+}
+
+/// Where the theme placed things, relative to the dialog.
+#[derive(Debug, Clone, Default)]
+pub struct DialogLayout {
+    pub size: Vector2D<f32>,
+    pub content_position: Vector2D<f32>,
+    pub buttons: Vec<Rect<f32>>,
+}
+
+/// A modal container with a title and a row of buttons, shown with WindowBuilder::show_dialog().
+/// While it's open nothing under it gets input, Tab only moves between its content and buttons.
+#[derive(Debug)]
+pub struct Dialog {
+    common: CommonWidget,
+    content: Compound,
+    title: String,
+    buttons: Vec<(String, DialogResult)>,
+    focused_button: Option<usize>, // None when the content has the focus
+    dim_area: Rect<f32>,
+    layout: DialogLayout,
+    result: Option<DialogResult>,
+    event_closed: DialogClosed,
+    translated_geometry: Geometry,
+}
+
+impl Dialog {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        title: &str,
+        mut content: Compound,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Dialog, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForDialog =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForDialog> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let frame = DialogFrame {
+            title: title.to_string(),
+            content_size: content.size().clone(),
+            buttons: vec![],
+            dim_area: Rect::new(0.0, 0.0, 0.0, 0.0),
+        };
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let layout = theme.new_dialog(&frame, None, style, &mut prim_man);
+        let size = layout.size.clone();
+        let mut dialog = Dialog {
+            common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            content,
+            title: title.to_string(),
+            buttons: vec![],
+            focused_button: None,
+            dim_area: frame.dim_area,
+            layout,
+            result: None,
+            event_closed: Default::default(),
+            translated_geometry: Default::default(),
+        };
+        dialog.set_content_position();
+        Ok(dialog)
+    }
+    /// A dialog with OK and Cancel buttons.
+    pub fn new_ok_cancel<WENUM: WidgetEnum>(
+        wid: WENUM,
+        title: &str,
+        content: Compound,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Dialog, Box<dyn Error>> {
+        let mut dialog = Self::new(wid, title, content, style_master)?;
+        dialog.add_button("OK", DialogResult::Ok);
+        dialog.add_button("Cancel", DialogResult::Cancel);
+        Ok(dialog)
+    }
+    /// Buttons are shown in the order they were added.
+    pub fn add_button(&mut self, text: &str, result: DialogResult) {
+        self.buttons.push((text.to_string(), result));
+        self.refresh();
+    }
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+    pub fn content(&self) -> &Compound {
+        &self.content
+    }
+    pub fn content_mut(&mut self) -> &mut Compound {
+        &mut self.content
+    }
+    /// Some once the dialog was closed, the window removes it after the closed event.
+    pub fn result(&self) -> Option<DialogResult> {
+        self.result
+    }
+    pub fn is_closed(&self) -> bool {
+        self.result.is_some()
+    }
+    /// Closes as if the user did it, returning the closed event to be called by the window.
+    pub fn close(&mut self, result: DialogResult) -> DeferredEvents {
+        if self.result.is_some() {
+            return vec![];
+        }
+        debug!("Dialog: closed with {:?}", result);
+        self.result = Some(result);
+        vec![self.event_closed.defer(self.wid(), result)]
+    }
+    pub fn event_closed(&self) -> Arc<DialogClosedCallback> {
+        self.event_closed.callback.clone()
+    }
+    pub fn set_event_closed(&mut self, callback: DialogClosedCallback) {
+        self.event_closed = DialogClosed {
+            callback: Arc::new(callback),
+        }
+    }
+    /// Centers the dialog and dims the whole window around it.
+    pub(crate) fn center_in(&mut self, window_size: Vector2D<f32>) {
+        let position = Vector2D::new(
+            ((window_size.x() - self.layout.size.x()) / 2.0).max(0.0),
+            ((window_size.y() - self.layout.size.y()) / 2.0).max(0.0),
+        );
+        self.dim_area = Rect::new(
+            -position.x(),
+            -position.y(),
+            window_size.x(),
+            window_size.y(),
+        );
+        self.set_position(position);
+        self.refresh();
+    }
+    fn refresh(&mut self) {
+        let frame = DialogFrame {
+            title: self.title.clone(),
+            content_size: self.content.size().clone(),
+            buttons: self
+                .buttons
+                .iter()
+                .enumerate()
+                .map(|(index, (text, _))| DialogButton {
+                    text: text.clone(),
+                    focused: self.focused_button == Some(index),
+                })
+                .collect(),
+            dim_area: self.dim_area,
+        };
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForDialog =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForDialog> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout = theme.set_dialog(&frame, size_for_clipping, style, prim_man);
+            self.layout.size.clone()
+        });
+        self.set_content_position();
+    }
+    fn set_content_position(&mut self) {
+        let (x, y) = self.common.content_position().unpack();
+        let position = Vector2D::new(
+            x + self.layout.content_position.x(),
+            y + self.layout.content_position.y(),
+        );
+        self.content.set_position(position);
+    }
+    fn content_rect(&mut self) -> Rect<f32> {
+        let (width, height) = self.content.size().unpack();
+        Rect::new(self.content.x(), self.content.y(), width, height)
+    }
+    fn focus_button(&mut self, focused_button: Option<usize>) {
+        if self.focused_button != focused_button {
+            self.focused_button = focused_button;
+            self.refresh();
+        }
+    }
+    /// Tab goes through the content's widgets, then each button and back, never leaving the dialog.
+    fn focus_next(&mut self, reverse: bool) -> DeferredEvents {
+        let count = self.buttons.len();
+        let next_button = match self.focused_button {
+            Some(index) if reverse => index.checked_sub(1),
+            Some(index) => Some(index + 1).filter(|next| *next < count),
+            None => None,
+        };
+        if next_button.is_some() {
+            self.focus_button(next_button);
+            return vec![];
+        }
+        // Past the last button or the content's last widget
+        self.focus_button(None);
+        let mut deferred_events = self.content.focus_next(reverse);
+        if self.content.focused_wid().is_none() {
+            match count {
+                0 => deferred_events.extend(self.content.focus_next(reverse)),
+                _ if reverse => self.focus_button(Some(count - 1)),
+                _ => self.focus_button(Some(0)),
+            }
+        }
+        deferred_events
+    }
+    /// Enter without a focused button means OK, if there is an OK button.
+    fn default_result(&self) -> Option<DialogResult> {
+        match self.focused_button {
+            Some(index) => Some(self.buttons[index].1),
+            None => self
+                .buttons
+                .iter()
+                .any(|(_, result)| *result == DialogResult::Ok)
+                .then_some(DialogResult::Ok),
+        }
+    }
+}
+
+impl Primitive for Dialog {
+    fn class_name() -> &'static str {
+        "Dialog"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position);
+        self.set_content_position();
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        let mut content_geometry = self.content.translate_geometry();
+        // Only cuts something when the size constraints made it smaller than its content
+        content_geometry.clip(&Rect::new(self.x(), self.y(), self.width(), self.height()));
+        let geometries = vec![self.common.translate_geometry(), content_geometry];
+        self.translated_geometry = Geometry::new_from_geometries(Self::class_name(), geometries);
+        self.translated_geometry.clone()
+    }
+}
+
+impl PrivatePrimitiveMethods for Dialog {
+    fn update_geometry(&mut self) {
+        if self.common.needs_update() {
+            self.common.update_geometry();
+        }
+        self.content.update_geometry();
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update() || self.content.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update);
+        self.content.set_needs_update(needs_update);
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation() || self.content.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation);
+        self.content.set_needs_translation(needs_translation);
+    }
+    fn clone_geometry(&self) -> Geometry {
+        let geometries = vec![self.common.clone_geometry(), self.content.clone_geometry()];
+        Geometry::new_from_geometries(Self::class_name(), geometries)
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+
+impl HasEvents for Dialog {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Dialog {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Dialog {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let point = Vector2D::new(x as f32, y as f32);
+        if self.content_rect().contains_point(point) {
+            self.focus_button(None);
+            return self.content.handle_mouse_button_down(x, y);
+        }
+        let local = self.common.local(x, y);
+        let clicked = self
+            .layout
+            .buttons
+            .iter()
+            .position(|button| button.contains_point(local.clone()));
+        match clicked {
+            Some(index) => {
+                self.focus_button(Some(index));
+                let mut deferred_events = self.content.set_focused_wid(None);
+                deferred_events.extend(self.close(self.buttons[index].1));
+                deferred_events
+            }
+            None => vec![],
+        }
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        self.content.handle_mouse_drag(x, y)
+    }
+    fn handle_mouse_button_up(&mut self, x: i32, y: i32) -> DeferredEvents {
+        self.content.handle_mouse_button_up(x, y)
+    }
+    fn handle_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) -> DeferredEvents {
+        self.content.handle_mouse_wheel(x, y, dx, dy)
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        let shift = keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+        let content_focused = self.focused_button.is_none();
+        match key {
+            // The focused widget goes first, e.g. Return makes a new line in a TextArea
+            _ if content_focused && self.content.wants_key(key) => {
+                self.content.handle_key_down(key, keymod)
+            }
+            Keycode::Tab => self.focus_next(shift),
+            Keycode::Escape => self.close(DialogResult::Cancel),
+            Keycode::Return | Keycode::KpEnter => match self.default_result() {
+                Some(result) => self.close(result),
+                None => self.content.handle_key_down(key, keymod),
+            },
+            Keycode::Space if self.focused_button.is_some() => match self.default_result() {
+                Some(result) => self.close(result),
+                None => vec![],
+            },
+            _ if content_focused => self.content.handle_key_down(key, keymod),
+            _ => vec![],
+        }
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        match self.focused_button {
+            Some(_) => vec![],
+            None => self.content.handle_text_input(text),
+        }
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        match self.focused_button {
+            Some(_) => vec![],
+            None => self.content.handle_focus_changed(focused),
+        }
+    }
+    fn as_container(&self) -> Option<&dyn Container> {
+        Some(self)
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        Some(self)
+    }
+}
+
+impl Container for Dialog {
+    fn child_wids(&self) -> Vec<WidgetId> {
+        self.content.child_wids()
+    }
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget> {
+        self.content.child(wid)
+    }
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        self.content.child_that_accepts_click(x, y)
+    }
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+        self.content.borrow_child(wid)
+    }
+    fn return_borrowed_widgets(&mut self) {
+        self.content.return_borrowed_widgets();
+    }
+}
@@ -0,0 +1,69 @@
+use sdl2::keyboard::Keycode;
+
+/// Text with a caret, for widgets that can be typed into. It doesn't draw anything, widgets give
+/// the text and the caret to their themes.
+#[derive(Debug, Clone, Default)]
+pub struct EditableText {
+    text: String,
+    caret: usize, // In chars, not bytes
+}
+
+impl EditableText {
+    pub fn new(text: &str) -> EditableText {
+        EditableText {
+            text: text.to_string(),
+            caret: text.chars().count(),
+        }
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+    /// Moves the caret to the end.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.caret = self.len();
+    }
+    pub fn set_caret(&mut self, caret: usize) {
+        self.caret = caret.min(self.len());
+    }
+    /// Inserts at the caret, what SDL gives with its text input events.
+    pub fn insert(&mut self, text: &str) {
+        let index = self.byte_index(self.caret);
+        self.text.insert_str(index, text);
+        self.caret += text.chars().count();
+    }
+    /// Editing and caret keys, returns false when the key isn't one of them.
+    pub fn handle_key(&mut self, key: Keycode) -> bool {
+        match key {
+            Keycode::Backspace => {
+                if self.caret > 0 {
+                    self.caret -= 1;
+                    self.text.remove(self.byte_index(self.caret));
+                }
+            }
+            Keycode::Delete => {
+                if self.caret < self.len() {
+                    self.text.remove(self.byte_index(self.caret));
+                }
+            }
+            Keycode::Left => self.caret = self.caret.saturating_sub(1),
+            Keycode::Right => self.set_caret(self.caret + 1),
+            Keycode::Home => self.caret = 0,
+            Keycode::End => self.caret = self.len(),
+            _ => return false,
+        }
+        true
+    }
+    fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+    fn byte_index(&self, caret: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(caret)
+            .map_or(self.text.len(), |(index, _)| index)
+    }
+}
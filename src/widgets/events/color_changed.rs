@@ -0,0 +1,48 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::general::Color;
+use crate::widgets::events::{DeferredEvent, Event};
+use crate::widgets::WidgetId;
+use crate::window::Root;
+
+/// Given by ColorPicker whenever the user picks another color.
+pub type ColorChangedCallback = fn(this: &mut dyn Root, wid: WidgetId, color: Color);
+
+pub struct ColorChanged {
+    pub callback: Arc<ColorChangedCallback>,
+}
+
+impl ColorChanged {
+    pub(crate) fn empty_callback(_this: &mut dyn Root, _wid: WidgetId, _color: Color) {}
+    pub fn defer(&self, wid: WidgetId, color: Color) -> DeferredEvent {
+        let callback = self.clone_callback();
+        Box::new(move |root| (callback)(root, wid, color))
+    }
+}
+
+impl Event for ColorChanged {
+    fn class(&self) -> &str {
+        "ColorChanged"
+    }
+
+    type Callback = ColorChangedCallback;
+
+    fn clone_callback(&self) -> Arc<Self::Callback> {
+        Arc::clone(&self.callback)
+    }
+}
+
+impl Debug for ColorChanged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.dyn_fmt(f)
+    }
+}
+
+impl Default for ColorChanged {
+    fn default() -> Self {
+        ColorChanged {
+            callback: Arc::new(Self::empty_callback),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::general::Date;
+use crate::widgets::events::{DeferredEvent, Event};
+use crate::widgets::WidgetId;
+use crate::window::Root;
+
+/// Given by Calendar and DatePicker, the date is always within their range.
+pub type DateSelectedCallback = fn(this: &mut dyn Root, wid: WidgetId, date: Date);
+
+pub struct DateSelected {
+    pub callback: Arc<DateSelectedCallback>,
+}
+
+impl DateSelected {
+    pub(crate) fn empty_callback(_this: &mut dyn Root, _wid: WidgetId, _date: Date) {}
+    pub fn defer(&self, wid: WidgetId, date: Date) -> DeferredEvent {
+        let callback = self.clone_callback();
+        Box::new(move |root| (callback)(root, wid, date))
+    }
+}
+
+impl Event for DateSelected {
+    fn class(&self) -> &str {
+        "DateSelected"
+    }
+
+    type Callback = DateSelectedCallback;
+
+    fn clone_callback(&self) -> Arc<Self::Callback> {
+        Arc::clone(&self.callback)
+    }
+}
+
+impl Debug for DateSelected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.dyn_fmt(f)
+    }
+}
+
+impl Default for DateSelected {
+    fn default() -> Self {
+        DateSelected {
+            callback: Arc::new(Self::empty_callback),
+        }
+    }
+}
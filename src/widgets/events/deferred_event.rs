@@ -0,0 +1,7 @@
+use crate::window::Root;
+
+/// A lib-user callback that was triggered while a widget handled an input by itself.
+/// The widget can't call it right away because it is still mutably borrowed by its container, so
+/// it hands it back and the window calls it afterwards, when widgets can be looked up freely.
+pub type DeferredEvent = Box<dyn FnOnce(&mut dyn Root) + Send>;
+pub type DeferredEvents = Vec<DeferredEvent>;
@@ -0,0 +1,47 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::widgets::events::{DeferredEvent, Event};
+use crate::widgets::{DialogResult, WidgetId};
+use crate::window::Root;
+
+/// Called while the dialog is still open, so the widgets inside it can be looked up.
+pub type DialogClosedCallback = fn(this: &mut dyn Root, wid: WidgetId, result: DialogResult);
+
+pub struct DialogClosed {
+    pub callback: Arc<DialogClosedCallback>,
+}
+
+impl DialogClosed {
+    pub(crate) fn empty_callback(_this: &mut dyn Root, _wid: WidgetId, _result: DialogResult) {}
+    pub fn defer(&self, wid: WidgetId, result: DialogResult) -> DeferredEvent {
+        let callback = self.clone_callback();
+        Box::new(move |root| (callback)(root, wid, result))
+    }
+}
+
+impl Event for DialogClosed {
+    fn class(&self) -> &str {
+        "DialogClosed"
+    }
+
+    type Callback = DialogClosedCallback;
+
+    fn clone_callback(&self) -> Arc<Self::Callback> {
+        Arc::clone(&self.callback)
+    }
+}
+
+impl Debug for DialogClosed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.dyn_fmt(f)
+    }
+}
+
+impl Default for DialogClosed {
+    fn default() -> Self {
+        DialogClosed {
+            callback: Arc::new(Self::empty_callback),
+        }
+    }
+}
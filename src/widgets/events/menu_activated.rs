@@ -0,0 +1,47 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::widgets::events::{DeferredEvent, Event};
+use crate::widgets::{MenuActionId, WidgetId};
+use crate::window::Root;
+
+/// wid is the MenuBar or the widget the context menu was opened on.
+pub type MenuActivatedCallback = fn(this: &mut dyn Root, wid: WidgetId, action: MenuActionId);
+
+pub struct MenuActivated {
+    pub callback: Arc<MenuActivatedCallback>,
+}
+
+impl MenuActivated {
+    pub(crate) fn empty_callback(_this: &mut dyn Root, _wid: WidgetId, _action: MenuActionId) {}
+    pub fn defer(&self, wid: WidgetId, action: MenuActionId) -> DeferredEvent {
+        let callback = self.clone_callback();
+        Box::new(move |root| (callback)(root, wid, action))
+    }
+}
+
+impl Event for MenuActivated {
+    fn class(&self) -> &str {
+        "MenuActivated"
+    }
+
+    type Callback = MenuActivatedCallback;
+
+    fn clone_callback(&self) -> Arc<Self::Callback> {
+        Arc::clone(&self.callback)
+    }
+}
+
+impl Debug for MenuActivated {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.dyn_fmt(f)
+    }
+}
+
+impl Default for MenuActivated {
+    fn default() -> Self {
+        MenuActivated {
+            callback: Arc::new(Self::empty_callback),
+        }
+    }
+}
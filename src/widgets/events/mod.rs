@@ -1,9 +1,25 @@
+pub use color_changed::*;
+pub use date_selected::*;
+pub use deferred_event::*;
+pub use dialog_closed::*;
 pub use event::Event;
 pub use has_events::*;
 pub use key_down::*;
+pub use menu_activated::*;
 pub use mouse_button_down::*;
+pub use selection_changed::*;
+pub use toggled::*;
+pub use value_changed::*;
 
+mod color_changed;
+mod date_selected;
+mod deferred_event;
+mod dialog_closed;
 mod event;
 mod has_events;
 mod key_down;
+mod menu_activated;
 mod mouse_button_down;
+mod selection_changed;
+mod toggled;
+mod value_changed;
@@ -0,0 +1,47 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::widgets::events::{DeferredEvent, Event};
+use crate::widgets::WidgetId;
+use crate::window::Root;
+
+/// `item` is whatever the widget selects, e.g. a node for TreeView.
+pub type SelectionChangedCallback = fn(this: &mut dyn Root, wid: WidgetId, item: usize);
+
+pub struct SelectionChanged {
+    pub callback: Arc<SelectionChangedCallback>,
+}
+
+impl SelectionChanged {
+    pub(crate) fn empty_callback(_this: &mut dyn Root, _wid: WidgetId, _item: usize) {}
+    pub fn defer(&self, wid: WidgetId, item: usize) -> DeferredEvent {
+        let callback = self.clone_callback();
+        Box::new(move |root| (callback)(root, wid, item))
+    }
+}
+
+impl Event for SelectionChanged {
+    fn class(&self) -> &str {
+        "SelectionChanged"
+    }
+
+    type Callback = SelectionChangedCallback;
+
+    fn clone_callback(&self) -> Arc<Self::Callback> {
+        Arc::clone(&self.callback)
+    }
+}
+
+impl Debug for SelectionChanged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.dyn_fmt(f)
+    }
+}
+
+impl Default for SelectionChanged {
+    fn default() -> Self {
+        SelectionChanged {
+            callback: Arc::new(Self::empty_callback),
+        }
+    }
+}
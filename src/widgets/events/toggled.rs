@@ -0,0 +1,47 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::widgets::events::{DeferredEvent, Event};
+use crate::widgets::WidgetId;
+use crate::window::Root;
+
+/// Given by Toggle when the user switches it, on is the new state.
+pub type ToggledCallback = fn(this: &mut dyn Root, wid: WidgetId, on: bool);
+
+pub struct Toggled {
+    pub callback: Arc<ToggledCallback>,
+}
+
+impl Toggled {
+    pub(crate) fn empty_callback(_this: &mut dyn Root, _wid: WidgetId, _on: bool) {}
+    pub fn defer(&self, wid: WidgetId, on: bool) -> DeferredEvent {
+        let callback = self.clone_callback();
+        Box::new(move |root| (callback)(root, wid, on))
+    }
+}
+
+impl Event for Toggled {
+    fn class(&self) -> &str {
+        "Toggled"
+    }
+
+    type Callback = ToggledCallback;
+
+    fn clone_callback(&self) -> Arc<Self::Callback> {
+        Arc::clone(&self.callback)
+    }
+}
+
+impl Debug for Toggled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.dyn_fmt(f)
+    }
+}
+
+impl Default for Toggled {
+    fn default() -> Self {
+        Toggled {
+            callback: Arc::new(Self::empty_callback),
+        }
+    }
+}
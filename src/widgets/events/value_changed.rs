@@ -0,0 +1,47 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::widgets::events::{DeferredEvent, Event};
+use crate::widgets::WidgetId;
+use crate::window::Root;
+
+/// Given by widgets that edit a number, after it was clamped to their range.
+pub type ValueChangedCallback = fn(this: &mut dyn Root, wid: WidgetId, value: f64);
+
+pub struct ValueChanged {
+    pub callback: Arc<ValueChangedCallback>,
+}
+
+impl ValueChanged {
+    pub(crate) fn empty_callback(_this: &mut dyn Root, _wid: WidgetId, _value: f64) {}
+    pub fn defer(&self, wid: WidgetId, value: f64) -> DeferredEvent {
+        let callback = self.clone_callback();
+        Box::new(move |root| (callback)(root, wid, value))
+    }
+}
+
+impl Event for ValueChanged {
+    fn class(&self) -> &str {
+        "ValueChanged"
+    }
+
+    type Callback = ValueChangedCallback;
+
+    fn clone_callback(&self) -> Arc<Self::Callback> {
+        Arc::clone(&self.callback)
+    }
+}
+
+impl Debug for ValueChanged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.dyn_fmt(f)
+    }
+}
+
+impl Default for ValueChanged {
+    fn default() -> Self {
+        ValueChanged {
+            callback: Arc::new(Self::empty_callback),
+        }
+    }
+}
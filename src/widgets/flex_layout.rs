@@ -0,0 +1,408 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::general::{Edges, Vector2D};
+use crate::widgets::{Direction, OwnedDynWidget, WidgetId};
+
+/// Where the children go along the direction when there is space left.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Justify {
+    #[default]
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+/// Where a child goes across the direction. Stretch only changes the size of
+/// widgets that can be resized, see Widget::set_layout_size().
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Align {
+    #[default]
+    Start,
+    End,
+    Center,
+    Stretch,
+}
+
+/// How a child takes part in a FlexLayout. Sizes are along the direction of the layout.
+/// The widget's own size constraints apply too.
+#[derive(Debug, Clone)]
+pub struct FlexItem {
+    /// Share of the space left, 0 keeps its size.
+    pub grow: f32,
+    /// Share of the space missing, weighted by its basis, 0 never shrinks it.
+    pub shrink: f32,
+    /// Size before growing or shrinking, None for the widget's own size.
+    pub basis: Option<f32>,
+    /// Overrides the layout's align_items.
+    pub align_self: Option<Align>,
+    pub min_size: Option<Vector2D<f32>>,
+    pub max_size: Option<Vector2D<f32>>,
+}
+
+impl Default for FlexItem {
+    fn default() -> Self {
+        Self {
+            grow: 0.0,
+            shrink: 1.0,
+            basis: None,
+            align_self: None,
+            min_size: None,
+            max_size: None,
+        }
+    }
+}
+
+impl FlexItem {
+    /// Takes the space left, e.g. the text box of an address bar.
+    pub fn new_grow(grow: f32) -> Self {
+        Self {
+            grow,
+            ..Default::default()
+        }
+    }
+}
+
+/// Like the CSS flexbox, but a single line.
+/// Without a size it's as big as its children and there is nothing to grow into.
+#[derive(Debug, Default, Clone)]
+pub struct FlexLayout {
+    pub direction: Direction,
+    pub gap: f32,
+    pub justify: Justify,
+    pub align_items: Align,
+    pub size: Option<Vector2D<f32>>,
+    // Asked by whoever holds the Compound, wins over size
+    layout_size: Option<Vector2D<f32>>,
+    items: HashMap<WidgetId, FlexItem>,
+    natural_sizes: NaturalSizes,
+}
+
+impl FlexLayout {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            ..Default::default()
+        }
+    }
+    pub fn item(&self, wid: WidgetId) -> Option<&FlexItem> {
+        self.items.get(&wid)
+    }
+    pub fn set_item(&mut self, wid: WidgetId, item: FlexItem) {
+        self.items.insert(wid, item);
+    }
+    pub(crate) fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.layout_size = size;
+    }
+    /// Gives the children it resized their own size back, e.g. when it's replaced.
+    pub(crate) fn release(&mut self, widgets: &mut BTreeMap<WidgetId, OwnedDynWidget>) {
+        self.natural_sizes.release(widgets);
+    }
+    /// (along, across) the direction.
+    fn split(&self, vector: &Vector2D<f32>) -> (f32, f32) {
+        match self.direction {
+            Direction::Horizontal => vector.unpack(),
+            Direction::Vertical => (vector.y(), vector.x()),
+        }
+    }
+    fn join(&self, main: f32, cross: f32) -> Vector2D<f32> {
+        match self.direction {
+            Direction::Horizontal => Vector2D::new(main, cross),
+            Direction::Vertical => Vector2D::new(cross, main),
+        }
+    }
+    /// Places and resizes the children, keeping their margins free, returns the size of the
+    /// layout.
+    pub fn arrange(
+        &mut self,
+        root: &Vector2D<f32>,
+        widgets: &mut BTreeMap<WidgetId, OwnedDynWidget>,
+        margins: &HashMap<WidgetId, Edges>,
+    ) -> Vector2D<f32> {
+        let mut slots = Vec::with_capacity(widgets.len());
+        for (wid, dyn_widget) in widgets.iter_mut() {
+            let margin = margins.get(wid).copied().unwrap_or_default();
+            let natural = self.natural_sizes.get(*wid, dyn_widget);
+            let item = self.items.get(wid).cloned().unwrap_or_default();
+            // The tighter of the item's limits and the widget's own
+            let constraints = dyn_widget.size_constraints();
+            let (widget_min_main, widget_min_cross) = self.split(&constraints.min_size());
+            let (widget_max_main, widget_max_cross) = self.split(&constraints.max_size());
+            let (min_main, min_cross) = item
+                .min_size
+                .as_ref()
+                .map_or((0.0, 0.0), |size| self.split(size));
+            let (min_main, min_cross) = (
+                min_main.max(widget_min_main),
+                min_cross.max(widget_min_cross),
+            );
+            let (max_main, max_cross) = item
+                .max_size
+                .as_ref()
+                .map_or((f32::INFINITY, f32::INFINITY), |size| self.split(size));
+            let (max_main, max_cross) = (
+                max_main.min(widget_max_main),
+                max_cross.min(widget_max_cross),
+            );
+            let (natural_main, natural_cross) = self.split(&natural);
+            let basis = item.basis.unwrap_or(natural_main);
+            slots.push(Slot {
+                wid: *wid,
+                natural,
+                grow: item.grow,
+                shrink: item.shrink * basis,
+                align: item.align_self.unwrap_or(self.align_items),
+                min: (min_main, min_cross),
+                max: (max_main.max(min_main), max_cross.max(min_cross)),
+                main: basis.clamp(min_main, max_main.max(min_main)),
+                cross: natural_cross.clamp(min_cross, max_cross.max(min_cross)),
+                lead: self.split(&Vector2D::new(margin.left, margin.top)),
+                margin: self.split(&Vector2D::new(margin.horizontal(), margin.vertical())),
+                frozen: false,
+            });
+        }
+        let gaps = self.gap * slots.len().saturating_sub(1) as f32;
+        let margins_main = slots.iter().map(|slot| slot.margin.0).sum::<f32>();
+        let content_main = slots.iter().map(|slot| slot.main).sum::<f32>() + margins_main + gaps;
+        let content_cross = slots
+            .iter()
+            .map(|slot| slot.cross + slot.margin.1)
+            .fold(0.0, f32::max);
+        let (size_main, size_cross) = match self.layout_size.as_ref().or(self.size.as_ref()) {
+            Some(size) => self.split(size),
+            None => (content_main, content_cross),
+        };
+
+        Self::flex(&mut slots, size_main - gaps - margins_main);
+
+        for slot in &mut slots {
+            if slot.align == Align::Stretch {
+                slot.cross = (size_cross - slot.margin.1).clamp(slot.min.1, slot.max.1);
+            }
+        }
+        for slot in &slots {
+            let size = self.join(slot.main, slot.cross);
+            let dyn_widget = widgets
+                .get_mut(&slot.wid)
+                .expect("flex_layout:FlexLayout:arrange wid");
+            self.natural_sizes
+                .resize(slot.wid, dyn_widget, &slot.natural, size);
+        }
+
+        let used = slots.iter().map(|slot| slot.main).sum::<f32>() + margins_main + gaps;
+        let left = (size_main - used).max(0.0);
+        let count = slots.len() as f32;
+        let (start, between) = match self.justify {
+            Justify::Start => (0.0, 0.0),
+            Justify::End => (left, 0.0),
+            Justify::Center => (left / 2.0, 0.0),
+            Justify::SpaceBetween if count > 1.0 => (0.0, left / (count - 1.0)),
+            Justify::SpaceBetween => (0.0, 0.0),
+            Justify::SpaceAround => (left / count / 2.0, left / count),
+            Justify::SpaceEvenly => (left / (count + 1.0), left / (count + 1.0)),
+        };
+        let (root_main, root_cross) = self.split(root);
+        let mut main = start;
+        for slot in &slots {
+            let dyn_widget = widgets
+                .get_mut(&slot.wid)
+                .expect("flex_layout:FlexLayout:arrange wid");
+            // Widgets that can't be resized keep their size inside the space they were given
+            let (_, cross) = self.split(&dyn_widget.size().clone());
+            let (lead_main, lead_cross) = slot.lead;
+            let cross_offset = match slot.align {
+                Align::Start | Align::Stretch => lead_cross,
+                Align::End => size_cross - cross - (slot.margin.1 - lead_cross),
+                Align::Center => lead_cross + (size_cross - slot.margin.1 - cross) / 2.0,
+            };
+            dyn_widget
+                .set_position(self.join(root_main + main + lead_main, root_cross + cross_offset));
+            main += slot.main + slot.margin.0 + self.gap + between;
+        }
+        self.join(size_main.max(used), size_cross)
+    }
+    /// Grows or shrinks the slots to fill the space, the ones that hit their limits are
+    /// frozen and what they couldn't take goes to the others.
+    fn flex(slots: &mut [Slot], space: f32) {
+        loop {
+            let used: f32 = slots.iter().map(|slot| slot.main).sum();
+            let free = space - used;
+            let growing = free > 0.0;
+            let factor = |slot: &Slot| if growing { slot.grow } else { slot.shrink };
+            let total: f32 = slots.iter().filter(|slot| !slot.frozen).map(factor).sum();
+            if free.abs() < 0.5 || total <= 0.0 {
+                return;
+            }
+            let mut clamped = false;
+            for slot in slots.iter_mut().filter(|slot| !slot.frozen) {
+                let wanted = slot.main + free * factor(slot) / total;
+                slot.main = wanted.clamp(slot.min.0, slot.max.0);
+                if slot.main != wanted {
+                    slot.frozen = true;
+                    clamped = true;
+                }
+            }
+            if !clamped {
+                return;
+            }
+        }
+    }
+}
+
+// A child while being laid out, main is along the direction and cross across it
+struct Slot {
+    wid: WidgetId,
+    natural: Vector2D<f32>,
+    grow: f32,
+    shrink: f32,
+    align: Align,
+    min: (f32, f32),
+    max: (f32, f32),
+    main: f32,
+    cross: f32,
+    // The margin before it and the whole margin, (main, cross)
+    lead: (f32, f32),
+    margin: (f32, f32),
+    frozen: bool,
+}
+
+/// The size each child had before a layout resized it and the size it ended with,
+/// so the child doesn't keep growing every time the layout runs.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct NaturalSizes(HashMap<WidgetId, (Vector2D<f32>, Vector2D<f32>)>);
+
+impl NaturalSizes {
+    /// The size of the child before it was resized, unless it changed by itself since.
+    pub(crate) fn get(&mut self, wid: WidgetId, dyn_widget: &mut OwnedDynWidget) -> Vector2D<f32> {
+        let current = dyn_widget.size().clone();
+        match self.0.get(&wid) {
+            Some((natural, given)) if given.unpack() == current.unpack() => natural.clone(),
+            _ => {
+                self.0.remove(&wid);
+                current
+            }
+        }
+    }
+    pub(crate) fn resize(
+        &mut self,
+        wid: WidgetId,
+        dyn_widget: &mut OwnedDynWidget,
+        natural: &Vector2D<f32>,
+        size: Vector2D<f32>,
+    ) {
+        if size.unpack() == natural.unpack() {
+            // Its own size again, e.g. it's no longer stretched
+            if self.0.remove(&wid).is_some() {
+                dyn_widget.set_layout_size(None);
+            }
+        } else if size.unpack() != dyn_widget.size().unpack() {
+            dyn_widget.set_layout_size(Some(size));
+            self.0
+                .insert(wid, (natural.clone(), dyn_widget.size().clone()));
+        }
+    }
+    pub(crate) fn release(&mut self, widgets: &mut BTreeMap<WidgetId, OwnedDynWidget>) {
+        for (wid, _) in self.0.drain() {
+            if let Some(dyn_widget) = widgets.get_mut(&wid) {
+                dyn_widget.set_layout_size(None);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // main is also the basis, shrink is already weighted by it
+    fn slot(main: f32, grow: f32, shrink: f32, (min, max): (f32, f32)) -> Slot {
+        Slot {
+            wid: 0,
+            natural: Vector2D::new(main, 0.0),
+            grow,
+            shrink,
+            align: Align::Start,
+            min: (min, 0.0),
+            max: (max, f32::INFINITY),
+            main,
+            cross: 0.0,
+            lead: (0.0, 0.0),
+            margin: (0.0, 0.0),
+            frozen: false,
+        }
+    }
+
+    fn mains(slots: &[Slot]) -> Vec<f32> {
+        slots.iter().map(|slot| slot.main).collect()
+    }
+
+    #[test]
+    fn grows_by_share() {
+        let no_limits = (0.0, f32::INFINITY);
+        let mut slots = [
+            slot(10.0, 1.0, 0.0, no_limits),
+            slot(10.0, 3.0, 0.0, no_limits),
+        ];
+        FlexLayout::flex(&mut slots, 100.0);
+        assert_eq!(mains(&slots), vec![30.0, 70.0]);
+    }
+
+    #[test]
+    fn frozen_slots_leave_the_rest_to_the_others() {
+        let mut slots = [
+            slot(10.0, 1.0, 0.0, (0.0, 20.0)),
+            slot(10.0, 1.0, 0.0, (0.0, f32::INFINITY)),
+        ];
+        FlexLayout::flex(&mut slots, 100.0);
+        assert_eq!(mains(&slots), vec![20.0, 80.0]);
+        assert!(slots[0].frozen);
+    }
+
+    #[test]
+    fn shrinks_by_basis_down_to_the_min() {
+        let no_limits = (0.0, f32::INFINITY);
+        let mut slots = [
+            slot(100.0, 0.0, 100.0, no_limits),
+            slot(50.0, 0.0, 50.0, no_limits),
+        ];
+        FlexLayout::flex(&mut slots, 90.0);
+        assert_eq!(mains(&slots), vec![60.0, 30.0]);
+
+        let mut slots = [
+            slot(100.0, 0.0, 100.0, (80.0, f32::INFINITY)),
+            slot(100.0, 0.0, 100.0, no_limits),
+        ];
+        FlexLayout::flex(&mut slots, 100.0);
+        assert_eq!(mains(&slots), vec![80.0, 20.0]);
+    }
+
+    #[test]
+    fn fixed_slots_take_their_size() {
+        let mut slots = [slot(10.0, 1.0, 0.0, (30.0, 30.0))];
+        FlexLayout::flex(&mut slots, 100.0);
+        assert_eq!(mains(&slots), vec![30.0]);
+    }
+
+    #[test]
+    fn nothing_to_grow_keeps_the_sizes() {
+        let mut slots = [slot(10.0, 0.0, 1.0, (0.0, f32::INFINITY))];
+        FlexLayout::flex(&mut slots, 100.0);
+        assert_eq!(mains(&slots), vec![10.0]);
+        FlexLayout::flex(&mut [], 100.0);
+    }
+
+    #[test]
+    fn empty_layout_is_as_big_as_its_size() {
+        let mut widgets = BTreeMap::new();
+        let mut flex = FlexLayout::new(Direction::Vertical);
+        flex.gap = 10.0;
+        let root = Vector2D::new(5.0, 5.0);
+        let size = flex.arrange(&root, &mut widgets, &HashMap::new());
+        assert_eq!(size.unpack(), (0.0, 0.0));
+        flex.size = Some(Vector2D::new(100.0, 20.0));
+        let size = flex.arrange(&root, &mut widgets, &HashMap::new());
+        assert_eq!(size.unpack(), (100.0, 20.0));
+    }
+}
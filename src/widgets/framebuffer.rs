@@ -0,0 +1,323 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use crate::general::{Color, Geometry, Polygon, Rect, SizeConstraints, Vector2D};
+use crate::texture::{FramePixels, SoftTexture, StreamingSoftTexture};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForFramebuffer, ThemeStyleForFramebuffer,
+};
+use crate::widgets::events::MouseButtonDownCallback;
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// How the picture fills the Framebuffer, it's always centered.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum FramebufferScaling {
+    /// Fills the whole widget, ignoring the aspect ratio.
+    Stretch,
+    /// As big as it fits keeping the aspect ratio.
+    #[default]
+    Fit,
+    /// The biggest whole multiple of the height that fits, so pixels stay crisp.
+    Integer,
+}
+
+/// Lets anything with a clone of it, e.g. an emulator thread, give the Framebuffer new frames.
+/// They show up on the next render, no need to touch the widget.
+#[derive(Debug, Clone)]
+pub struct FramebufferHandle {
+    pixels: Arc<Mutex<FramePixels>>,
+}
+
+impl FramebufferHandle {
+    /// Four bytes per pixel in R, G, B, A order, it has to match the resolution.
+    pub fn set_rgba(&self, pixels: &[u8]) -> Result<(), Box<dyn Error>> {
+        self.pixels
+            .lock()
+            .expect("framebuffer:FramebufferHandle:set_rgba")
+            .set_rgba(pixels)
+    }
+    /// One palette index per pixel, it has to match the resolution.
+    pub fn set_indexed(&self, indices: &[u8], palette: &[Color]) -> Result<(), Box<dyn Error>> {
+        self.pixels
+            .lock()
+            .expect("framebuffer:FramebufferHandle:set_indexed")
+            .set_indexed(indices, palette)
+    }
+    /// Width and height in pixels.
+    pub fn resolution(&self) -> (u32, u32) {
+        let pixels = self
+            .pixels
+            .lock()
+            .expect("framebuffer:FramebufferHandle:resolution");
+        (pixels.width(), pixels.height())
+    }
+}
+
+/// Shows a raw pixel buffer that can change every frame, like an emulator's screen.
+/// The widget has a fixed size, the picture is scaled inside it.
+#[derive(Debug)]
+pub struct Framebuffer {
+    common: CommonWidget,
+    size: Vector2D<f32>,
+    handle: FramebufferHandle,
+    // Only the widget holds it, it never leaves the render thread
+    texture: Arc<Mutex<StreamingSoftTexture>>,
+    scaling: FramebufferScaling,
+    aspect_ratio: Option<f32>,
+    geometry: Geometry,
+    translated_geometry: Geometry,
+}
+
+impl Framebuffer {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        size: Vector2D<f32>,
+        resolution: (u32, u32),
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Framebuffer, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForFramebuffer =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForFramebuffer> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let theme_size = theme.new_framebuffer(size.clone(), None, style, &mut prim_man);
+        let handle = Self::new_handle(resolution)?;
+        let mut framebuffer = Framebuffer {
+            common: CommonWidget::new(wid, Self::class_name(), theme_size, style_master, prim_man),
+            size,
+            texture: Self::new_texture(&handle),
+            handle,
+            scaling: Default::default(),
+            aspect_ratio: None,
+            geometry: Default::default(),
+            translated_geometry: Default::default(),
+        };
+        framebuffer.update_geometry();
+        Ok(framebuffer)
+    }
+    fn new_handle((width, height): (u32, u32)) -> Result<FramebufferHandle, Box<dyn Error>> {
+        if width == 0 || height == 0 {
+            return Err(format!("Framebuffer resolution can't be {}x{}", width, height).into());
+        }
+        Ok(FramebufferHandle {
+            pixels: Arc::new(Mutex::new(FramePixels::new(width, height))),
+        })
+    }
+    fn new_texture(handle: &FramebufferHandle) -> Arc<Mutex<StreamingSoftTexture>> {
+        Arc::new(Mutex::new(StreamingSoftTexture::new(handle.pixels.clone())))
+    }
+    pub fn handle(&self) -> FramebufferHandle {
+        self.handle.clone()
+    }
+    pub fn set_rgba(&mut self, pixels: &[u8]) -> Result<(), Box<dyn Error>> {
+        self.handle.set_rgba(pixels)
+    }
+    pub fn set_indexed(&mut self, indices: &[u8], palette: &[Color]) -> Result<(), Box<dyn Error>> {
+        self.handle.set_indexed(indices, palette)
+    }
+    pub fn resolution(&self) -> (u32, u32) {
+        self.handle.resolution()
+    }
+    /// Starts transparent again, handles given before keep writing to the old pixels.
+    pub fn set_resolution(&mut self, resolution: (u32, u32)) -> Result<(), Box<dyn Error>> {
+        self.handle = Self::new_handle(resolution)?;
+        self.texture = Self::new_texture(&self.handle);
+        self.common.set_needs_update(true);
+        Ok(())
+    }
+    pub fn scaling(&self) -> FramebufferScaling {
+        self.scaling
+    }
+    pub fn set_scaling(&mut self, scaling: FramebufferScaling) {
+        self.scaling = scaling;
+        self.common.set_needs_update(true);
+    }
+    pub fn aspect_ratio(&self) -> Option<f32> {
+        self.aspect_ratio
+    }
+    /// Width over height of the picture, for non square pixels, e.g. Some(4.0 / 3.0).
+    /// None keeps the resolution's own.
+    pub fn set_aspect_ratio(&mut self, aspect_ratio: Option<f32>) -> Result<(), Box<dyn Error>> {
+        if let Some(ratio) = aspect_ratio.filter(|ratio| !ratio.is_finite() || *ratio <= 0.0) {
+            return Err(format!("Framebuffer aspect ratio can't be {}", ratio).into());
+        }
+        self.aspect_ratio = aspect_ratio;
+        self.common.set_needs_update(true);
+        Ok(())
+    }
+    /// Its own size, a layout that stretches it and its size constraints still win.
+    pub fn resize(&mut self, size: Vector2D<f32>) {
+        self.size = size;
+        self.refresh();
+    }
+    /// Where the picture is inside the widget, e.g. to know which pixel was clicked.
+    pub fn picture_rect(&self) -> Rect<f32> {
+        let (width, height) = self.common.content_size().unpack();
+        let (columns, rows) = self.resolution();
+        let (columns, rows) = (columns as f32, rows as f32);
+        let aspect_ratio = self.aspect_ratio.unwrap_or(columns / rows);
+        let fit = || {
+            let picture_height = height.min(width / aspect_ratio);
+            (picture_height * aspect_ratio, picture_height)
+        };
+        let (picture_width, picture_height) = match self.scaling {
+            FramebufferScaling::Stretch => (width, height),
+            FramebufferScaling::Fit => fit(),
+            FramebufferScaling::Integer => {
+                let times = (height / rows).min(width / (rows * aspect_ratio)).floor();
+                if times >= 1.0 {
+                    (rows * times * aspect_ratio, rows * times)
+                } else {
+                    // Smaller than the resolution, there is nothing crisp to keep
+                    fit()
+                }
+            }
+        };
+        // Inside the border and padding
+        let (x, y) = self.common.content_position().unpack();
+        Rect::new(
+            (x - self.x() + (width - picture_width) / 2.0).floor(),
+            (y - self.y() + (height - picture_height) / 2.0).floor(),
+            picture_width.floor(),
+            picture_height.floor(),
+        )
+    }
+    fn refresh(&mut self) {
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForFramebuffer =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let own_size = &self.size;
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForFramebuffer> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            theme.set_framebuffer(own_size.clone(), size_for_clipping, style, prim_man)
+        });
+    }
+}
+
+impl Primitive for Framebuffer {
+    fn class_name() -> &'static str {
+        "Framebuffer"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for Framebuffer {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry();
+        let picture = self.picture_rect();
+        let poly = Polygon::new_rect_for_texture(
+            Rect::new(
+                picture.x() as u32,
+                picture.y() as u32,
+                picture.width() as u32,
+                picture.height() as u32,
+            ),
+            255,
+        );
+        let texture: Arc<Mutex<dyn SoftTexture>> = self.texture.clone();
+        let picture = Geometry::new_for_texture(Self::class_name(), texture, poly);
+        self.geometry = Geometry::new_from_geometries(
+            Self::class_name(),
+            vec![self.common.clone_geometry(), picture],
+        );
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.geometry.clone()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+
+impl HasEvents for Framebuffer {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Framebuffer {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Framebuffer {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+}
@@ -0,0 +1,228 @@
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, SizeConstraints, Vector2D};
+use crate::themes::StyleMaster;
+use crate::widgets::events::{DeferredEvents, KeyDownCallback, MouseButtonDownCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{
+    Compound, Container, Direction, DowncastableBorrowedWidget, GridCell, GridLayout, HasTooltip,
+    OwnedDynWidget, TrackSize, WidgetEnum, WidgetId,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// Widgets in rows and columns, e.g. a form with a column of labels and one of fields.
+#[derive(Debug)]
+pub struct Grid {
+    content: Compound,
+}
+
+impl Grid {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        columns: Vec<TrackSize>,
+        rows: Vec<TrackSize>,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut content = Compound::new(wid, Direction::Vertical, style_master)?;
+        content.set_grid_layout(GridLayout::new(columns, rows));
+        Ok(Self { content })
+    }
+    pub fn add_widget<T: Widget>(&mut self, widget: T, cell: GridCell) {
+        self.layout_mut().set_cell(widget.wid(), cell);
+        self.content.add_widget(widget);
+    }
+    pub fn layout(&self) -> &GridLayout {
+        self.content
+            .grid_layout()
+            .expect("grid:Grid:layout the content always has a GridLayout")
+    }
+    fn layout_mut(&mut self) -> &mut GridLayout {
+        self.content
+            .grid_layout_mut()
+            .expect("grid:Grid:layout_mut the content always has a GridLayout")
+    }
+    pub fn set_cell<WENUM: WidgetEnum>(&mut self, wid: WENUM, cell: GridCell) {
+        self.layout_mut().set_cell(wid.to_wid(), cell);
+        self.content.update_layout();
+    }
+    pub fn set_columns(&mut self, columns: Vec<TrackSize>) {
+        self.layout_mut().columns = columns;
+        self.content.update_layout();
+    }
+    pub fn set_rows(&mut self, rows: Vec<TrackSize>) {
+        self.layout_mut().rows = rows;
+        self.content.update_layout();
+    }
+    pub fn set_gap(&mut self, column_gap: f32, row_gap: f32) {
+        let layout = self.layout_mut();
+        layout.column_gap = column_gap;
+        layout.row_gap = row_gap;
+        self.content.update_layout();
+    }
+    /// Fraction tracks share what is left of it, None to fit the widgets.
+    pub fn set_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.layout_mut().size = size;
+        self.content.update_layout();
+    }
+}
+
+impl Primitive for Grid {
+    fn class_name() -> &'static str {
+        "Grid"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.content.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.content.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.content.x()
+    }
+    fn y(&self) -> f32 {
+        self.content.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.content.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.content.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.content.width()
+    }
+    fn height(&self) -> f32 {
+        self.content.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.content.size()
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        self.content.translate_geometry()
+    }
+    fn build_geometry(&mut self) -> Geometry {
+        self.content.build_geometry()
+    }
+}
+
+impl PrivatePrimitiveMethods for Grid {
+    fn update_geometry(&mut self) {
+        self.content.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.content.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.content.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.content.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.content.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.content.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.content.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.content.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for Grid {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.content.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.content.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<KeyDownCallback> {
+        self.content.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: KeyDownCallback) {
+        self.content.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Grid {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.content.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.content.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Grid {
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        self.content.handle_mouse_button_down(x, y)
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        self.content.handle_mouse_drag(x, y)
+    }
+    fn handle_mouse_button_up(&mut self, x: i32, y: i32) -> DeferredEvents {
+        self.content.handle_mouse_button_up(x, y)
+    }
+    fn handle_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) -> DeferredEvents {
+        self.content.handle_mouse_wheel(x, y, dx, dy)
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        self.content.handle_key_down(key, keymod)
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        self.content.wants_key(key)
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        self.content.handle_text_input(text)
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        self.content.handle_focus_changed(focused)
+    }
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.content.set_layout_size(size)
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.content.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.content.set_size_constraints(constraints)
+    }
+    fn as_container(&self) -> Option<&dyn Container> {
+        Some(self)
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        Some(self)
+    }
+}
+
+impl Container for Grid {
+    fn child_wids(&self) -> Vec<WidgetId> {
+        self.content.child_wids()
+    }
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget> {
+        self.content.child(wid)
+    }
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        self.content.child_that_accepts_click(x, y)
+    }
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+        self.content.borrow_child(wid)
+    }
+    fn return_borrowed_widgets(&mut self) {
+        Container::return_borrowed_widgets(&mut self.content)
+    }
+}
@@ -0,0 +1,402 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::general::{Edges, Vector2D};
+use crate::widgets::{Align, NaturalSizes, OwnedDynWidget, WidgetId};
+
+/// How wide a column or how tall a row is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackSize {
+    Fixed(f32),
+    /// As big as the biggest widget in it.
+    Auto,
+    /// A share of the space left by the others, with no size for the Grid it fits the widgets.
+    Fraction(f32),
+}
+
+/// Where a widget goes in a Grid and how it's aligned inside its cell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridCell {
+    pub row: usize,
+    pub column: usize,
+    // Never 0, set with with_span()
+    row_span: usize,
+    column_span: usize,
+    pub horizontal: Align,
+    pub vertical: Align,
+}
+
+impl GridCell {
+    pub fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            horizontal: Align::Start,
+            vertical: Align::Start,
+        }
+    }
+    pub fn with_span(mut self, row_span: usize, column_span: usize) -> Self {
+        self.row_span = row_span.max(1);
+        self.column_span = column_span.max(1);
+        self
+    }
+    pub fn row_span(&self) -> usize {
+        self.row_span
+    }
+    pub fn column_span(&self) -> usize {
+        self.column_span
+    }
+    pub fn with_align(mut self, horizontal: Align, vertical: Align) -> Self {
+        self.horizontal = horizontal;
+        self.vertical = vertical;
+        self
+    }
+}
+
+/// Rows and columns, widgets placed past the last defined ones get Auto tracks.
+#[derive(Debug, Default, Clone)]
+pub struct GridLayout {
+    pub columns: Vec<TrackSize>,
+    pub rows: Vec<TrackSize>,
+    pub column_gap: f32,
+    pub row_gap: f32,
+    pub size: Option<Vector2D<f32>>,
+    // Asked by whoever holds the Grid, wins over size
+    layout_size: Option<Vector2D<f32>>,
+    cells: HashMap<WidgetId, GridCell>,
+    natural_sizes: NaturalSizes,
+    // Where each column and row starts and how big it is, from the last arrange()
+    column_tracks: Vec<(f32, f32)>,
+    row_tracks: Vec<(f32, f32)>,
+}
+
+impl GridLayout {
+    pub fn new(columns: Vec<TrackSize>, rows: Vec<TrackSize>) -> Self {
+        Self {
+            columns,
+            rows,
+            ..Default::default()
+        }
+    }
+    pub fn cell(&self, wid: WidgetId) -> Option<&GridCell> {
+        self.cells.get(&wid)
+    }
+    pub fn set_cell(&mut self, wid: WidgetId, cell: GridCell) {
+        self.cells.insert(wid, cell);
+    }
+    pub(crate) fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.layout_size = size;
+    }
+    /// Gives the children it resized their own size back, e.g. when it's replaced.
+    pub(crate) fn release(&mut self, widgets: &mut BTreeMap<WidgetId, OwnedDynWidget>) {
+        self.natural_sizes.release(widgets);
+    }
+    /// Where the column starts, relative to the Grid, and how wide it is.
+    pub fn column_track(&self, column: usize) -> Option<(f32, f32)> {
+        self.column_tracks.get(column).copied()
+    }
+    /// Where the row starts, relative to the Grid, and how tall it is.
+    pub fn row_track(&self, row: usize) -> Option<(f32, f32)> {
+        self.row_tracks.get(row).copied()
+    }
+    /// Places and resizes the children, keeping their margins free in their cells, returns the
+    /// size of the layout.
+    pub fn arrange(
+        &mut self,
+        root: &Vector2D<f32>,
+        widgets: &mut BTreeMap<WidgetId, OwnedDynWidget>,
+        margins: &HashMap<WidgetId, Edges>,
+    ) -> Vector2D<f32> {
+        let mut placed = Vec::with_capacity(widgets.len());
+        for (wid, dyn_widget) in widgets.iter_mut() {
+            let natural = self.natural_sizes.get(*wid, dyn_widget);
+            let cell = self
+                .cells
+                .get(wid)
+                .copied()
+                .unwrap_or_else(|| GridCell::new(0, 0));
+            let margin = margins.get(wid).copied().unwrap_or_default();
+            placed.push((*wid, cell, natural, margin));
+        }
+        let size = self.layout_size.as_ref().or(self.size.as_ref());
+        let (width, height) = size.map_or((None, None), |size| {
+            let (width, height) = size.unpack();
+            (Some(width), Some(height))
+        });
+        let columns: Vec<Span> = placed
+            .iter()
+            .map(|(_, cell, natural, margin)| {
+                (
+                    cell.column,
+                    cell.column_span,
+                    natural.x() + margin.horizontal(),
+                )
+            })
+            .collect();
+        self.column_tracks = Self::tracks(&self.columns, &columns, self.column_gap, width);
+        let rows: Vec<Span> = placed
+            .iter()
+            .map(|(_, cell, natural, margin)| {
+                (cell.row, cell.row_span, natural.y() + margin.vertical())
+            })
+            .collect();
+        self.row_tracks = Self::tracks(&self.rows, &rows, self.row_gap, height);
+
+        let (root_x, root_y) = root.unpack();
+        for (wid, cell, natural, margin) in placed {
+            let (x, cell_width) =
+                Self::span_rect(&self.column_tracks, cell.column, cell.column_span);
+            let (y, cell_height) = Self::span_rect(&self.row_tracks, cell.row, cell.row_span);
+            let dyn_widget = widgets
+                .get_mut(&wid)
+                .expect("grid_layout:GridLayout:arrange wid");
+            let stretched = dyn_widget.size_constraints().clamp(&Vector2D::new(
+                match cell.horizontal {
+                    Align::Stretch => cell_width - margin.horizontal(),
+                    _ => natural.x(),
+                },
+                match cell.vertical {
+                    Align::Stretch => cell_height - margin.vertical(),
+                    _ => natural.y(),
+                },
+            ));
+            self.natural_sizes
+                .resize(wid, dyn_widget, &natural, stretched);
+            let (widget_width, widget_height) = dyn_widget.size().unpack();
+            // The margins are (before, after) the widget
+            let offset =
+                |align: Align, space: f32, size: f32, (before, after): (f32, f32)| match align {
+                    Align::Start | Align::Stretch => before,
+                    Align::End => space - size - after,
+                    Align::Center => before + (space - before - after - size) / 2.0,
+                };
+            dyn_widget.set_position(Vector2D::new(
+                root_x
+                    + x
+                    + offset(
+                        cell.horizontal,
+                        cell_width,
+                        widget_width,
+                        (margin.left, margin.right),
+                    ),
+                root_y
+                    + y
+                    + offset(
+                        cell.vertical,
+                        cell_height,
+                        widget_height,
+                        (margin.top, margin.bottom),
+                    ),
+            ));
+        }
+        let end = |tracks: &[(f32, f32)]| tracks.last().map_or(0.0, |(start, size)| start + size);
+        Vector2D::new(
+            width.unwrap_or(0.0).max(end(&self.column_tracks)),
+            height.unwrap_or(0.0).max(end(&self.row_tracks)),
+        )
+    }
+    /// Where the span starts and how big it is, gaps inside it included.
+    fn span_rect(tracks: &[(f32, f32)], first: usize, span: usize) -> (f32, f32) {
+        let (start, _) = tracks[first];
+        let (last_start, last_size) = tracks[(first + span - 1).min(tracks.len() - 1)];
+        (start, last_start + last_size - start)
+    }
+    /// Sizes the tracks along one direction, returns where each starts and how big it is.
+    fn tracks(
+        definitions: &[TrackSize],
+        spans: &[Span],
+        gap: f32,
+        space: Option<f32>,
+    ) -> Vec<(f32, f32)> {
+        let count = spans
+            .iter()
+            .map(|(first, span, _)| first + span)
+            .chain(Some(definitions.len()))
+            .max()
+            .unwrap_or(0);
+        let definition = |index: usize| definitions.get(index).copied().unwrap_or(TrackSize::Auto);
+        let mut sizes: Vec<f32> = (0..count)
+            .map(|index| match definition(index) {
+                TrackSize::Fixed(size) => size,
+                _ => 0.0,
+            })
+            .collect();
+
+        // What the widgets in a single track need
+        let mut needed = vec![0.0f32; count];
+        for &(first, span, size) in spans {
+            if span == 1 {
+                needed[first] = needed[first].max(size);
+            }
+        }
+        for (index, size) in sizes.iter_mut().enumerate() {
+            if definition(index) == TrackSize::Auto {
+                *size = needed[index];
+            }
+        }
+
+        let is_fraction = |index: usize| matches!(definition(index), TrackSize::Fraction(_));
+        let spanning = || spans.iter().filter(|(_, span, _)| *span > 1);
+        // Spanning widgets that don't fit make the last Auto track they span bigger, before the
+        // fraction tracks take what's left. Those that span a fraction track grow it instead.
+        for &(first, span, size) in spanning() {
+            let tracks = first..first + span;
+            if tracks.clone().any(is_fraction) {
+                continue;
+            }
+            let spanned = sizes[tracks.clone()].iter().sum::<f32>() + gap * (span - 1) as f32;
+            let auto = tracks
+                .rev()
+                .find(|&index| definition(index) == TrackSize::Auto);
+            if let (Some(auto), true) = (auto, size > spanned) {
+                sizes[auto] += size - spanned;
+            }
+        }
+
+        let gaps = gap * count.saturating_sub(1) as f32;
+        let fractions: f32 = (0..count)
+            .filter_map(|index| match definition(index) {
+                TrackSize::Fraction(fraction) => Some(fraction),
+                _ => None,
+            })
+            .sum();
+        if fractions > 0.0 {
+            let per_fraction = match space {
+                Some(space) => (space - gaps - sizes.iter().sum::<f32>()).max(0.0) / fractions,
+                // Big enough for the widgets of every fraction track, spanning ones included
+                None => (0..count)
+                    .filter_map(|index| match definition(index) {
+                        TrackSize::Fraction(fraction) if fraction > 0.0 => {
+                            Some(needed[index] / fraction)
+                        }
+                        _ => None,
+                    })
+                    .chain(spanning().filter_map(|&(first, span, size)| {
+                        let tracks = first..first + span;
+                        let fractions: f32 = tracks
+                            .clone()
+                            .filter_map(|index| match definition(index) {
+                                TrackSize::Fraction(fraction) => Some(fraction),
+                                _ => None,
+                            })
+                            .sum();
+                        let others: f32 = tracks
+                            .filter(|&index| !is_fraction(index))
+                            .map(|index| sizes[index])
+                            .sum();
+                        let left = size - others - gap * (span - 1) as f32;
+                        (fractions > 0.0).then(|| left.max(0.0) / fractions)
+                    }))
+                    .fold(0.0, f32::max),
+            };
+            for (index, size) in sizes.iter_mut().enumerate() {
+                if let TrackSize::Fraction(fraction) = definition(index) {
+                    *size = per_fraction * fraction;
+                }
+            }
+        }
+
+        let mut start = 0.0;
+        sizes
+            .into_iter()
+            .map(|size| {
+                let track = (start, size);
+                start += size + gap;
+                track
+            })
+            .collect()
+    }
+}
+
+// The first track, how many tracks and the size of the widget along them
+type Span = (usize, usize, f32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_are_never_0() {
+        let cell = GridCell::new(1, 2).with_span(0, 0);
+        assert_eq!((cell.row_span(), cell.column_span()), (1, 1));
+    }
+
+    #[test]
+    fn empty_layout_is_as_big_as_its_size() {
+        let mut widgets = BTreeMap::new();
+        let mut grid = GridLayout::default();
+        let root = Vector2D::new(5.0, 5.0);
+        let size = grid.arrange(&root, &mut widgets, &HashMap::new());
+        assert_eq!(size.unpack(), (0.0, 0.0));
+        grid.size = Some(Vector2D::new(40.0, 30.0));
+        let size = grid.arrange(&root, &mut widgets, &HashMap::new());
+        assert_eq!(size.unpack(), (40.0, 30.0));
+    }
+
+    #[test]
+    fn empty_tracks_keep_their_definition() {
+        let mut widgets = BTreeMap::new();
+        let mut grid = GridLayout::new(vec![TrackSize::Fixed(10.0); 2], vec![TrackSize::Auto]);
+        grid.column_gap = 4.0;
+        let size = grid.arrange(&Vector2D::default(), &mut widgets, &HashMap::new());
+        assert_eq!(size.unpack(), (24.0, 0.0));
+        assert_eq!(grid.column_track(1), Some((14.0, 10.0)));
+        assert_eq!(grid.row_track(1), None);
+    }
+
+    #[test]
+    fn auto_tracks_fit_their_biggest_widget() {
+        let spans = [(0, 1, 10.0), (1, 1, 30.0), (0, 1, 20.0)];
+        let tracks = GridLayout::tracks(&[TrackSize::Auto], &spans, 5.0, None);
+        assert_eq!(tracks, vec![(0.0, 20.0), (25.0, 30.0)]);
+    }
+
+    #[test]
+    fn fractions_share_the_space_left() {
+        let definitions = [
+            TrackSize::Fixed(50.0),
+            TrackSize::Fraction(1.0),
+            TrackSize::Fraction(3.0),
+        ];
+        let tracks = GridLayout::tracks(&definitions, &[], 10.0, Some(170.0));
+        assert_eq!(tracks, vec![(0.0, 50.0), (60.0, 25.0), (95.0, 75.0)]);
+    }
+
+    #[test]
+    fn fractions_get_0_when_nothing_is_left() {
+        let definitions = [TrackSize::Fixed(80.0), TrackSize::Fraction(1.0)];
+        let tracks = GridLayout::tracks(&definitions, &[], 0.0, Some(50.0));
+        assert_eq!(tracks, vec![(0.0, 80.0), (80.0, 0.0)]);
+    }
+
+    #[test]
+    fn fractions_without_a_size_fit_their_widgets() {
+        let definitions = [TrackSize::Fraction(1.0), TrackSize::Fraction(2.0)];
+        let spans = [(0, 1, 30.0), (1, 1, 20.0)];
+        let tracks = GridLayout::tracks(&definitions, &spans, 0.0, None);
+        assert_eq!(tracks, vec![(0.0, 30.0), (30.0, 60.0)]);
+    }
+
+    #[test]
+    fn spanning_widgets_grow_the_last_auto_track() {
+        let definitions = [TrackSize::Auto, TrackSize::Auto, TrackSize::Fixed(5.0)];
+        let spans = [(0, 2, 50.0), (0, 1, 10.0)];
+        let tracks = GridLayout::tracks(&definitions, &spans, 0.0, None);
+        assert_eq!(tracks, vec![(0.0, 10.0), (10.0, 40.0), (50.0, 5.0)]);
+    }
+
+    #[test]
+    fn spanning_widgets_grow_the_last_auto_track_first() {
+        let definitions = [TrackSize::Auto, TrackSize::Auto, TrackSize::Fraction(1.0)];
+        let spans = [(0, 2, 50.0), (0, 1, 10.0)];
+        let tracks = GridLayout::tracks(&definitions, &spans, 0.0, Some(100.0));
+        assert_eq!(tracks, vec![(0.0, 10.0), (10.0, 40.0), (50.0, 50.0)]);
+    }
+
+    #[test]
+    fn widgets_past_the_definitions_get_auto_tracks() {
+        let tracks = GridLayout::tracks(&[], &[(2, 1, 5.0)], 1.0, None);
+        assert_eq!(tracks, vec![(0.0, 0.0), (1.0, 0.0), (2.0, 5.0)]);
+    }
+}
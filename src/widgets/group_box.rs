@@ -0,0 +1,282 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForGroupBox, ThemeStyleForGroupBox,
+};
+use crate::widgets::events::{DeferredEvents, MouseButtonDownCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{
+    CommonWidget, Compound, Container, DowncastableBorrowedWidget, HasTooltip, OwnedDynWidget,
+    WidgetEnum, WidgetId,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// Where the theme placed the content, relative to the GroupBox.
+#[derive(Debug, Clone)]
+pub struct GroupBoxLayout {
+    pub size: Vector2D<f32>,
+    pub content: Rect<f32>,
+}
+
+/// A titled border around a Compound, it grows and shrinks with it.
+#[derive(Debug)]
+pub struct GroupBox {
+    common: CommonWidget,
+    title: String,
+    content: Compound,
+    layout: GroupBoxLayout,
+    translated_geometry: Geometry,
+}
+
+impl GroupBox {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        title: &str,
+        content: Compound,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<GroupBox, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForGroupBox =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForGroupBox> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let content_size = Vector2D::new(content.width(), content.height());
+        let layout = theme.new_group_box(title, &content_size, None, style, &mut prim_man);
+        let mut group_box = GroupBox {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                layout.size.clone(),
+                style_master,
+                prim_man,
+            ),
+            title: title.to_string(),
+            content,
+            layout,
+            translated_geometry: Default::default(),
+        };
+        group_box.set_content_position();
+        Ok(group_box)
+    }
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+    pub fn set_title(&mut self, title: &str) {
+        self.title = title.to_string();
+        self.refresh();
+    }
+    pub fn content(&self) -> &Compound {
+        &self.content
+    }
+    pub fn content_mut(&mut self) -> &mut Compound {
+        &mut self.content
+    }
+    fn content_resized(&self) -> bool {
+        let (width, height) = (self.layout.content.width(), self.layout.content.height());
+        self.content.width() != width || self.content.height() != height
+    }
+    fn set_content_position(&mut self) {
+        let (x, y) = self.common.content_position().unpack();
+        let (content_x, content_y) = (self.layout.content.x(), self.layout.content.y());
+        self.content
+            .set_position(Vector2D::new(x + content_x, y + content_y));
+    }
+    fn refresh(&mut self) {
+        let content_size = Vector2D::new(self.content.width(), self.content.height());
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForGroupBox =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForGroupBox> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout = theme.set_group_box(
+                &self.title,
+                &content_size,
+                size_for_clipping,
+                style,
+                prim_man,
+            );
+            self.layout.size.clone()
+        });
+        self.set_content_position();
+    }
+}
+
+impl Primitive for GroupBox {
+    fn class_name() -> &'static str {
+        "GroupBox"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position);
+        self.set_content_position();
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        let mut content_geometry = self.content.translate_geometry();
+        // Only cuts something when the size constraints made it smaller than its content
+        content_geometry.clip(&Rect::new(self.x(), self.y(), self.width(), self.height()));
+        let geometries = vec![self.common.translate_geometry(), content_geometry];
+        self.translated_geometry = Geometry::new_from_geometries(Self::class_name(), geometries);
+        self.translated_geometry.clone()
+    }
+}
+
+impl PrivatePrimitiveMethods for GroupBox {
+    fn update_geometry(&mut self) {
+        self.content.update_geometry();
+        // The border follows the content
+        if self.content_resized() {
+            self.refresh();
+        }
+        if self.common.needs_update() {
+            self.common.update_geometry();
+        }
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update() || self.content.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update);
+        self.content.set_needs_update(needs_update);
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation() || self.content.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation);
+        self.content.set_needs_translation(needs_translation);
+    }
+    fn clone_geometry(&self) -> Geometry {
+        let geometries = vec![self.common.clone_geometry(), self.content.clone_geometry()];
+        Geometry::new_from_geometries(Self::class_name(), geometries)
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+impl HasEvents for GroupBox {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for GroupBox {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for GroupBox {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        self.content.handle_mouse_button_down(x, y)
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        self.content.handle_mouse_drag(x, y)
+    }
+    fn handle_mouse_button_up(&mut self, x: i32, y: i32) -> DeferredEvents {
+        self.content.handle_mouse_button_up(x, y)
+    }
+    fn handle_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) -> DeferredEvents {
+        self.content.handle_mouse_wheel(x, y, dx, dy)
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        self.content.handle_key_down(key, keymod)
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        self.content.wants_key(key)
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        self.content.handle_text_input(text)
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        self.content.handle_focus_changed(focused)
+    }
+    fn as_container(&self) -> Option<&dyn Container> {
+        Some(self)
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        Some(self)
+    }
+}
+
+impl Container for GroupBox {
+    fn child_wids(&self) -> Vec<WidgetId> {
+        self.content.child_wids()
+    }
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget> {
+        self.content.child(wid)
+    }
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        self.content.child_that_accepts_click(x, y)
+    }
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+        self.content.borrow_child(wid)
+    }
+    fn return_borrowed_widgets(&mut self) {
+        self.content.return_borrowed_widgets();
+    }
+}
@@ -0,0 +1,7 @@
+use crate::widgets::OwnedDynWidget;
+
+/// Any widget can be shown near the pointer after hovering this one for a while, usually a Tooltip.
+pub trait HasTooltip {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget>;
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>);
+}
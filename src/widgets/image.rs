@@ -4,12 +4,12 @@ use std::fmt::Debug;
 use std::path::Path;
 use std::sync::Arc;
 
-use crate::general::{Geometry, Vector2D};
+use crate::general::{Geometry, SizeConstraints, Vector2D};
 use crate::themes::{PrimitivesManagerForThemes, StyleMaster, ThemeForImage, ThemeStyleForImage};
 use crate::widgets::events::MouseButtonDownCallback;
 use crate::widgets::primitives::private::PrivatePrimitiveMethods;
 use crate::widgets::primitives::Primitive;
-use crate::widgets::{CommonWidget, WidgetEnum};
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
 
 use super::events::HasEvents;
 use super::Widget;
@@ -17,6 +17,7 @@ use super::Widget;
 #[derive(Debug)]
 pub struct Image {
     common: CommonWidget,
+    path: Box<Path>,
 }
 
 impl Image {
@@ -31,11 +32,24 @@ impl Image {
         let style: Box<ThemeStyleForImage> =
             style_master.expect_style_for_widget_t(type_id, Self::class_name());
         let mut prim_man = PrimitivesManagerForThemes::new();
-        let size = theme.new_image(path, None, style, &mut prim_man);
+        let size = theme.new_image(path.clone(), None, style, &mut prim_man);
         Ok(Image {
             common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            path,
         })
     }
+    fn refresh(&mut self) {
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForImage =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let path = &self.path;
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForImage> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            theme.new_image(path.clone(), size_for_clipping, style, prim_man)
+        });
+    }
 }
 
 impl Primitive for Image {
@@ -117,4 +131,25 @@ impl HasEvents for Image {
     }
 }
 
-impl Widget for Image {}
+impl HasTooltip for Image {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Image {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+}
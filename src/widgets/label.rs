@@ -0,0 +1,218 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::general::{Geometry, SizeConstraints, TextAlign, Vector2D};
+use crate::themes::{PrimitivesManagerForThemes, StyleMaster, ThemeForLabel, ThemeStyleForLabel};
+use crate::widgets::events::MouseButtonDownCallback;
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// Read only text, it can span many lines.
+/// Without a max size it's as big as its text, with one it wraps to its width
+/// and what doesn't fit ends with an ellipsis.
+#[derive(Debug)]
+pub struct Label {
+    common: CommonWidget,
+    text: String,
+    align: TextAlign,
+    wrap: bool,
+    max_size: Option<Vector2D<f32>>,
+}
+
+impl Label {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        text: &str,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Label, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForLabel =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForLabel> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let size = theme.new_label(text, TextAlign::default(), true, None, style, &mut prim_man);
+        Ok(Label {
+            common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            text: text.to_string(),
+            align: TextAlign::default(),
+            wrap: true,
+            max_size: None,
+        })
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn set_text(&mut self, text: &str) {
+        if self.text != text {
+            self.text = text.to_string();
+            self.refresh();
+        }
+    }
+    pub fn align(&self) -> TextAlign {
+        self.align
+    }
+    pub fn set_align(&mut self, align: TextAlign) {
+        if self.align != align {
+            self.align = align;
+            self.refresh();
+        }
+    }
+    pub fn wrap(&self) -> bool {
+        self.wrap
+    }
+    /// Without wrapping, lines wider than the max size are cut with an ellipsis.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        if self.wrap != wrap {
+            self.wrap = wrap;
+            self.refresh();
+        }
+    }
+    pub fn max_size(&self) -> Option<&Vector2D<f32>> {
+        self.max_size.as_ref()
+    }
+    /// The label takes the whole width, use f32::MAX as height to only limit the width.
+    pub fn set_max_size(&mut self, max_size: Option<Vector2D<f32>>) {
+        self.max_size = max_size;
+        self.refresh();
+    }
+    fn refresh(&mut self) {
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForLabel =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let constraints = self.common.size_constraints();
+        let layout_size = self.common.layout_size().cloned();
+        // A narrower width can take more lines, they're only cut when the height is limited too
+        let limits_height = constraints.max_height.is_some() || layout_size.is_some();
+        let (text, align, wrap, max_size) = (&self.text, self.align, self.wrap, &self.max_size);
+        let prim_man = self.common.prim_man();
+        let size = constraints.fit(layout_size.as_ref(), |size_for_clipping| {
+            let size_for_clipping = match size_for_clipping {
+                Some(size) => {
+                    let (max_width, max_height) = max_size
+                        .as_ref()
+                        .map_or((f32::MAX, f32::MAX), Vector2D::unpack);
+                    let height = if limits_height { size.y() } else { f32::MAX };
+                    Some(Vector2D::new(
+                        size.x().min(max_width),
+                        height.min(max_height),
+                    ))
+                }
+                None => max_size.clone(),
+            };
+            let style: Box<ThemeStyleForLabel> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            theme.set_label(text, align, wrap, size_for_clipping, style, prim_man)
+        });
+        self.common.set_size(size);
+    }
+}
+
+impl Primitive for Label {
+    fn class_name() -> &'static str {
+        "Label"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for Label {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for Label {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Label {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Label {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+}
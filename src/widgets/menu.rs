@@ -0,0 +1,425 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{PrimitivesManagerForThemes, StyleMaster, ThemeForMenu, ThemeStyleForMenu};
+use crate::widgets::events::{MenuActivated, MenuActivatedCallback, MouseButtonDownCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// Whatever the lib-user wants to identify a menu entry with, it's given back when activated.
+pub type MenuActionId = usize;
+
+/// Declarative description of a menu entry.
+/// A '&' in the label marks the next letter as the mnemonic, "&&" is shown as '&'.
+#[derive(Debug, Clone, Default)]
+pub struct MenuItem {
+    label: String,
+    action: Option<MenuActionId>,
+    accelerator: Option<String>,
+    checked: Option<bool>,
+    disabled: bool,
+    separator: bool,
+    children: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    pub fn new(label: &str, action: MenuActionId) -> MenuItem {
+        MenuItem {
+            label: label.to_string(),
+            action: Some(action),
+            ..Default::default()
+        }
+    }
+    /// Toggled every time it's activated, before the activated event is called.
+    pub fn new_check(label: &str, action: MenuActionId, checked: bool) -> MenuItem {
+        MenuItem {
+            checked: Some(checked),
+            ..Self::new(label, action)
+        }
+    }
+    pub fn new_submenu(label: &str, children: Vec<MenuItem>) -> MenuItem {
+        MenuItem {
+            label: label.to_string(),
+            children,
+            ..Default::default()
+        }
+    }
+    pub fn new_separator() -> MenuItem {
+        MenuItem {
+            separator: true,
+            ..Default::default()
+        }
+    }
+    /// Only a hint shown next to the label, like "Ctrl+S".
+    pub fn with_accelerator(mut self, accelerator: &str) -> MenuItem {
+        self.accelerator = Some(accelerator.to_string());
+        self
+    }
+    pub fn disabled(mut self) -> MenuItem {
+        self.disabled = true;
+        self
+    }
+    /// The label without the mnemonic marks.
+    pub fn text(&self) -> String {
+        Self::parse_label(&self.label).0
+    }
+    /// The index in text() and the lowercase letter to press.
+    pub fn mnemonic(&self) -> Option<(usize, char)> {
+        Self::parse_label(&self.label).1
+    }
+    pub fn action(&self) -> Option<MenuActionId> {
+        self.action
+    }
+    pub fn accelerator(&self) -> Option<&str> {
+        self.accelerator.as_deref()
+    }
+    /// None if it isn't a check item.
+    pub fn is_checked(&self) -> Option<bool> {
+        self.checked
+    }
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = Some(checked);
+    }
+    pub fn is_enabled(&self) -> bool {
+        !self.disabled
+    }
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.disabled = !enabled;
+    }
+    pub fn is_separator(&self) -> bool {
+        self.separator
+    }
+    pub fn is_submenu(&self) -> bool {
+        !self.children.is_empty()
+    }
+    pub fn children(&self) -> &[MenuItem] {
+        &self.children
+    }
+    pub(crate) fn children_mut(&mut self) -> &mut [MenuItem] {
+        &mut self.children
+    }
+    /// Searches the whole tree, submenus included.
+    pub fn find_mut(items: &mut [MenuItem], action: MenuActionId) -> Option<&mut MenuItem> {
+        items.iter_mut().find_map(|item| {
+            if item.action == Some(action) {
+                Some(item)
+            } else {
+                Self::find_mut(&mut item.children, action)
+            }
+        })
+    }
+    /// The items of the submenu reached following the indexes in path.
+    pub(crate) fn items_at<'a>(items: &'a [MenuItem], path: &[usize]) -> &'a [MenuItem] {
+        path.iter()
+            .fold(items, |items, index| &items[*index].children)
+    }
+    pub(crate) fn items_at_mut<'a>(
+        items: &'a mut [MenuItem],
+        path: &[usize],
+    ) -> &'a mut [MenuItem] {
+        path.iter()
+            .fold(items, |items, index| &mut items[*index].children)
+    }
+    fn parse_label(label: &str) -> (String, Option<(usize, char)>) {
+        let mut text = String::with_capacity(label.len());
+        let mut mnemonic = None;
+        let mut chars = label.chars();
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                text.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('&') => text.push('&'),
+                Some(next) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some((text.chars().count(), next.to_ascii_lowercase()));
+                    }
+                    text.push(next);
+                }
+                None => {}
+            }
+        }
+        (text, mnemonic)
+    }
+}
+
+/// A visible entry, it's what themes get to draw.
+#[derive(Debug, Clone)]
+pub struct MenuRow {
+    pub text: String,
+    pub mnemonic: Option<usize>,
+    pub accelerator: Option<String>,
+    pub checked: Option<bool>,
+    pub enabled: bool,
+    pub separator: bool,
+    pub submenu: bool,
+    pub highlighted: bool,
+}
+
+/// Where the theme placed each row, relative to the menu.
+#[derive(Debug, Clone, Default)]
+pub struct MenuLayout {
+    pub size: Vector2D<f32>,
+    pub rows: Vec<Rect<f32>>,
+}
+
+/// A popup list of items, the window opens them for MenuBar and ContextMenu and handles their input.
+#[derive(Debug)]
+pub struct Menu {
+    common: CommonWidget,
+    rows: Vec<MenuRow>,
+    layout: MenuLayout,
+}
+
+impl Menu {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        items: &[MenuItem],
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Menu, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForMenu =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForMenu> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let rows = Self::rows_of(items);
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let layout = theme.new_menu(&rows, None, style, &mut prim_man);
+        let size = layout.size.clone();
+        Ok(Menu {
+            common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            rows,
+            layout,
+        })
+    }
+    pub fn highlighted(&self) -> Option<usize> {
+        self.rows.iter().position(|row| row.highlighted)
+    }
+    /// Separators can't be highlighted.
+    pub fn set_highlighted(&mut self, highlighted: Option<usize>) {
+        let highlighted = highlighted.filter(|index| !self.rows[*index].separator);
+        if self.highlighted() == highlighted {
+            return;
+        }
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            row.highlighted = highlighted == Some(index);
+        }
+        self.refresh();
+    }
+    /// Moves the highlight to the next row that isn't a separator, wrapping around.
+    pub fn highlight_next(&mut self, reverse: bool) {
+        let count = self.rows.len();
+        if count == 0 {
+            return;
+        }
+        let mut index = match self.highlighted() {
+            Some(index) => index,
+            None if reverse => 0,
+            None => count - 1,
+        };
+        for _ in 0..count {
+            index = if reverse {
+                (index + count - 1) % count
+            } else {
+                (index + 1) % count
+            };
+            if !self.rows[index].separator {
+                self.set_highlighted(Some(index));
+                return;
+            }
+        }
+    }
+    pub fn row_at(&self, x: i32, y: i32) -> Option<usize> {
+        let local = self.common.local(x, y);
+        self.layout
+            .rows
+            .iter()
+            .position(|row| row.contains_point(local.clone()))
+    }
+    /// In window coordinates.
+    pub fn row_rect(&self, index: usize) -> Rect<f32> {
+        let row = &self.layout.rows[index];
+        let position = self.common.content_position();
+        Rect::new(
+            position.x() + row.x(),
+            position.y() + row.y(),
+            row.width(),
+            row.height(),
+        )
+    }
+    fn rows_of(items: &[MenuItem]) -> Vec<MenuRow> {
+        items
+            .iter()
+            .map(|item| MenuRow {
+                text: item.text(),
+                mnemonic: item.mnemonic().map(|(index, _)| index),
+                accelerator: item.accelerator.clone(),
+                checked: item.checked,
+                enabled: item.is_enabled(),
+                separator: item.separator,
+                submenu: item.is_submenu(),
+                highlighted: false,
+            })
+            .collect()
+    }
+    fn refresh(&mut self) {
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForMenu =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForMenu> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout = theme.set_menu(&self.rows, size_for_clipping, style, prim_man);
+            self.layout.size.clone()
+        });
+    }
+}
+
+impl Primitive for Menu {
+    fn class_name() -> &'static str {
+        "Menu"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for Menu {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for Menu {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Menu {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Menu {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+}
+
+/// Items shown on right click over a widget, give it to WindowBuilder::set_context_menu().
+#[derive(Debug)]
+pub struct ContextMenu {
+    items: Vec<MenuItem>,
+    style_master: Arc<StyleMaster>,
+    event_activated: MenuActivated,
+}
+
+impl ContextMenu {
+    pub fn new(items: Vec<MenuItem>, style_master: Arc<StyleMaster>) -> ContextMenu {
+        ContextMenu {
+            items,
+            style_master,
+            event_activated: Default::default(),
+        }
+    }
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+    pub fn item_mut(&mut self, action: MenuActionId) -> Option<&mut MenuItem> {
+        MenuItem::find_mut(&mut self.items, action)
+    }
+    pub(crate) fn items_mut(&mut self) -> &mut [MenuItem] {
+        &mut self.items
+    }
+    pub(crate) fn style_master(&self) -> Arc<StyleMaster> {
+        self.style_master.clone()
+    }
+    pub fn event_activated(&self) -> Arc<MenuActivatedCallback> {
+        self.event_activated.callback.clone()
+    }
+    pub fn set_event_activated(&mut self, callback: MenuActivatedCallback) {
+        self.event_activated = MenuActivated {
+            callback: Arc::new(callback),
+        }
+    }
+}
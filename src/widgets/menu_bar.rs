@@ -0,0 +1,262 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForMenuBar, ThemeStyleForMenuBar,
+};
+use crate::widgets::events::{MenuActivated, MenuActivatedCallback, MouseButtonDownCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{
+    CommonWidget, HasTooltip, MenuActionId, MenuItem, OwnedDynWidget, WidgetEnum,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// A visible title, it's what themes get to draw.
+#[derive(Debug, Clone)]
+pub struct MenuBarTitle {
+    pub text: String,
+    pub mnemonic: Option<usize>,
+    pub enabled: bool,
+    pub open: bool,
+}
+
+/// Where the theme placed each title, relative to the bar.
+#[derive(Debug, Clone, Default)]
+pub struct MenuBarLayout {
+    pub size: Vector2D<f32>,
+    pub titles: Vec<Rect<f32>>,
+}
+
+/// Row of menus docked at the top of a window, give it to WindowBuilder::set_menu_bar().
+/// Each top level item should be a submenu, Alt plus its mnemonic opens it.
+#[derive(Debug)]
+pub struct MenuBar {
+    common: CommonWidget,
+    menus: Vec<MenuItem>,
+    width: f32,
+    open: Option<usize>,
+    layout: MenuBarLayout,
+    event_activated: MenuActivated,
+}
+
+impl MenuBar {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        menus: Vec<MenuItem>,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<MenuBar, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForMenuBar =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForMenuBar> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let titles = Self::titles_of(&menus, None);
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let layout = theme.new_menu_bar(&titles, 0.0, None, style, &mut prim_man);
+        let size = layout.size.clone();
+        Ok(MenuBar {
+            common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            menus,
+            width: 0.0,
+            open: None,
+            layout,
+            event_activated: Default::default(),
+        })
+    }
+    pub fn menus(&self) -> &[MenuItem] {
+        &self.menus
+    }
+    pub fn item_mut(&mut self, action: MenuActionId) -> Option<&mut MenuItem> {
+        MenuItem::find_mut(&mut self.menus, action)
+    }
+    /// Enables or disables a whole menu, by its index in the bar.
+    pub fn set_menu_enabled(&mut self, index: usize, enabled: bool) {
+        self.menus[index].set_enabled(enabled);
+        self.refresh();
+    }
+    pub fn event_activated(&self) -> Arc<MenuActivatedCallback> {
+        self.event_activated.callback.clone()
+    }
+    pub fn set_event_activated(&mut self, callback: MenuActivatedCallback) {
+        self.event_activated = MenuActivated {
+            callback: Arc::new(callback),
+        }
+    }
+    pub(crate) fn submenu_items_mut(&mut self, index: usize) -> &mut [MenuItem] {
+        self.menus[index].children_mut()
+    }
+    pub(crate) fn style_master(&self) -> Arc<StyleMaster> {
+        self.common.style_master()
+    }
+    /// The bar always spans the whole window.
+    pub(crate) fn set_width(&mut self, width: f32) {
+        self.width = width;
+        self.refresh();
+    }
+    pub(crate) fn set_open(&mut self, open: Option<usize>) {
+        if self.open != open {
+            self.open = open;
+            self.refresh();
+        }
+    }
+    pub(crate) fn title_at(&self, x: i32, y: i32) -> Option<usize> {
+        let local = self.common.local(x, y);
+        self.layout
+            .titles
+            .iter()
+            .position(|title| title.contains_point(local.clone()))
+    }
+    /// In window coordinates.
+    pub(crate) fn title_rect(&self, index: usize) -> Rect<f32> {
+        let title = &self.layout.titles[index];
+        let position = self.common.content_position();
+        Rect::new(
+            position.x() + title.x(),
+            position.y() + title.y(),
+            title.width(),
+            title.height(),
+        )
+    }
+    pub(crate) fn title_for_mnemonic(&self, letter: char) -> Option<usize> {
+        self.menus.iter().position(|menu| {
+            menu.is_enabled()
+                && menu
+                    .mnemonic()
+                    .is_some_and(|(_, mnemonic)| mnemonic == letter)
+        })
+    }
+    fn titles_of(menus: &[MenuItem], open: Option<usize>) -> Vec<MenuBarTitle> {
+        menus
+            .iter()
+            .enumerate()
+            .map(|(index, menu)| MenuBarTitle {
+                text: menu.text(),
+                mnemonic: menu.mnemonic().map(|(index, _)| index),
+                enabled: menu.is_enabled(),
+                open: open == Some(index),
+            })
+            .collect()
+    }
+    fn refresh(&mut self) {
+        let titles = Self::titles_of(&self.menus, self.open);
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForMenuBar =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForMenuBar> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout =
+                theme.set_menu_bar(&titles, self.width, size_for_clipping, style, prim_man);
+            self.layout.size.clone()
+        });
+    }
+}
+
+impl Primitive for MenuBar {
+    fn class_name() -> &'static str {
+        "MenuBar"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for MenuBar {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for MenuBar {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for MenuBar {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for MenuBar {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+}
@@ -1,5 +1,5 @@
 mod button;
-pub use button::Button;
+pub use button::{Button, IconPlacement};
 
 mod common_widget;
 pub use common_widget::CommonWidget;
@@ -7,6 +7,9 @@ pub use common_widget::CommonWidget;
 mod downcastable_borrowed_widget;
 pub use downcastable_borrowed_widget::*;
 
+mod has_tooltip;
+pub use has_tooltip::HasTooltip;
+
 mod image;
 pub use image::Image;
 
@@ -15,6 +18,51 @@ pub mod primitives;
 mod text_box;
 pub use text_box::TextBox;
 
+mod editable_text;
+pub use editable_text::EditableText;
+
+mod text_document;
+pub use text_document::*;
+
+mod text_area;
+pub use text_area::*;
+
+mod label;
+pub use label::*;
+
+mod painter;
+pub use painter::*;
+
+mod canvas;
+pub use canvas::*;
+
+mod framebuffer;
+pub use framebuffer::*;
+
+mod splitter;
+pub use splitter::*;
+
+mod color_picker;
+pub use color_picker::*;
+
+mod toggle;
+pub use toggle::*;
+
+mod group_box;
+pub use group_box::*;
+
+mod accordion;
+pub use accordion::*;
+
+mod spin_box;
+pub use spin_box::*;
+
+mod calendar;
+pub use calendar::*;
+
+mod date_picker;
+pub use date_picker::*;
+
 mod widget;
 pub use widget::*;
 
@@ -26,11 +74,47 @@ pub mod events;
 mod compound;
 pub use compound::*;
 
+mod container;
+pub use container::Container;
+
 mod direction;
 pub use direction::Direction;
 
 mod next_position_calculator;
 pub use next_position_calculator::*;
 
-mod base_widget;
-pub use base_widget::*;
\ No newline at end of file
+mod flex_layout;
+pub use flex_layout::*;
+
+mod grid_layout;
+pub use grid_layout::*;
+
+mod grid;
+pub use grid::Grid;
+
+mod tree_view;
+pub use tree_view::*;
+
+mod tab_view;
+pub use tab_view::*;
+
+mod dialog;
+pub use dialog::*;
+
+mod menu;
+pub use menu::*;
+
+mod menu_bar;
+pub use menu_bar::*;
+
+mod status_bar;
+pub use status_bar::*;
+
+mod toast;
+pub use toast::*;
+
+mod tooltip;
+pub use tooltip::Tooltip;
+
+mod scroll_view;
+pub use scroll_view::*;
@@ -1,6 +1,6 @@
 use core::f32;
 
-use crate::{general::Vector2D, widgets::{Direction, Widget}};
+use crate::{general::{Edges, Vector2D}, widgets::{Direction, Widget}};
 
 #[derive(Debug)]
 pub struct NextPositionCalculator {
@@ -13,6 +13,10 @@ pub struct NextPositionCalculator {
     direction: Direction,
     limit_x: f32,
     limit_y: f32,
+    column_gap: f32,
+    row_gap: f32,
+    // Across the direction, how big the row or column being filled is
+    line_size: f32,
 }
 
 impl NextPositionCalculator {
@@ -27,37 +31,42 @@ impl NextPositionCalculator {
             direction: direction,
             limit_x: f32::INFINITY,
             limit_y: f32::INFINITY,
+            column_gap: Default::default(),
+            row_gap: Default::default(),
+            line_size: Default::default(),
         }
     }
-    pub fn next(&mut self, widget: &mut Box<dyn Widget>) -> Vector2D<f32> {
-        let position = (self.root_x + self.next_x, self.root_y + self.next_y).into();
-        let widget_size = widget.size();
+    /// Where the widget goes, with its margin kept free around it.
+    pub fn next(&mut self, widget: &mut Box<dyn Widget>, margin: &Edges) -> Vector2D<f32> {
+        let (width, height) = widget.size().unpack();
+        let (width, height) = (width + margin.horizontal(), height + margin.vertical());
         if self.direction == Direction::Horizontal {
-            self.next_x += widget_size.x();
-            if self.next_x > self.limit_x {
+            // Wraps before placing it, unless it's the first of the row and wouldn't fit anyway
+            if self.next_x > 0.0 && self.next_x + width > self.limit_x {
                 self.next_x = 0.0;
-                self.next_y += self.size_y;
-            }
-            if self.next_x > self.size_x {
-                self.size_x = self.next_x;
-            }
-            if widget_size.y() > self.size_y {
-                self.size_y = widget_size.y();
+                self.next_y += self.line_size + self.row_gap;
+                self.line_size = 0.0;
             }
+        } else if self.next_y > 0.0 && self.next_y + height > self.limit_y {
+            self.next_y = 0.0;
+            self.next_x += self.line_size + self.column_gap;
+            self.line_size = 0.0;
+        }
+        let position = (
+            self.root_x + self.next_x + margin.left,
+            self.root_y + self.next_y + margin.top,
+        )
+            .into();
+        self.size_x = self.size_x.max(self.next_x + width);
+        self.size_y = self.size_y.max(self.next_y + height);
+        if self.direction == Direction::Horizontal {
+            self.next_x += width + self.column_gap;
+            self.line_size = self.line_size.max(height);
         } else {
-            self.next_y += widget_size.y();
-            if self.next_y > self.limit_y {
-                self.next_y = 0.0;
-                self.next_x += self.size_x;
-            }
-            if self.next_y > self.size_y {
-                self.size_y = self.next_y;
-            }
-            if widget_size.x() > self.size_x {
-                self.size_x = widget_size.x();
-            }
+            self.next_y += height + self.row_gap;
+            self.line_size = self.line_size.max(width);
         }
-        return position;
+        position
     }
     pub fn size(&self) -> Vector2D<f32> {
         Vector2D::new(self.size_x, self.size_y)
@@ -67,9 +76,92 @@ impl NextPositionCalculator {
         self.next_y = Default::default();
         self.size_x = Default::default();
         self.size_y = Default::default();
+        self.line_size = Default::default();
+    }
+    /// Starts a new row, or column when vertical, when the next widget would go past it.
+    /// None never wraps.
+    pub fn set_wrap_limit(&mut self, limit: Option<f32>) {
+        let limit = limit.unwrap_or(f32::INFINITY);
+        if self.direction == Direction::Horizontal {
+            self.limit_x = limit;
+        } else {
+            self.limit_y = limit;
+        }
+    }
+    /// Between the columns and between the rows, whatever the direction.
+    pub fn set_gaps(&mut self, column_gap: f32, row_gap: f32) {
+        self.column_gap = column_gap;
+        self.row_gap = row_gap;
     }
     pub fn set_root_position(&mut self, position: &Vector2D<f32>) {
         self.root_x = position.x();
         self.root_y = position.y();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::themes::{DarkSimpleTheme, StyleMaster};
+    use crate::widgets::{Compound, FlexLayout, WidgetId};
+
+    use super::*;
+
+    // An empty Compound is as big as its FlexLayout
+    fn widget(width: f32, height: f32) -> Box<dyn Widget> {
+        let style_master = StyleMaster::new(Box::new(DarkSimpleTheme))
+            .expect("next_position_calculator:tests:widget style master");
+        let wid: WidgetId = 0;
+        let mut compound = Compound::new(wid, Direction::Horizontal, Arc::new(style_master))
+            .expect("next_position_calculator:tests:widget compound");
+        let mut flex = FlexLayout::new(Direction::Horizontal);
+        flex.size = Some(Vector2D::new(width, height));
+        compound.set_flex_layout(Some(flex));
+        Box::new(compound)
+    }
+
+    fn place(calc: &mut NextPositionCalculator, width: f32, height: f32) -> (f32, f32) {
+        calc.next(&mut widget(width, height), &Edges::default()).unpack()
+    }
+
+    #[test]
+    fn wraps_past_the_limit_only() {
+        let mut calc = NextPositionCalculator::new(Direction::Horizontal);
+        calc.set_wrap_limit(Some(100.0));
+        assert_eq!(place(&mut calc, 50.0, 10.0), (0.0, 0.0));
+        // Ends right on the limit, stays on the row
+        assert_eq!(place(&mut calc, 50.0, 20.0), (50.0, 0.0));
+        assert_eq!(place(&mut calc, 1.0, 10.0), (0.0, 20.0));
+        assert_eq!(calc.size().unpack(), (100.0, 30.0));
+    }
+
+    #[test]
+    fn too_big_first_widget_keeps_its_row() {
+        let mut calc = NextPositionCalculator::new(Direction::Vertical);
+        calc.set_wrap_limit(Some(10.0));
+        calc.set_gaps(5.0, 0.0);
+        assert_eq!(place(&mut calc, 20.0, 30.0), (0.0, 0.0));
+        assert_eq!(place(&mut calc, 20.0, 5.0), (25.0, 0.0));
+    }
+
+    #[test]
+    fn starts_over_after_reset() {
+        let mut calc = NextPositionCalculator::new(Direction::Horizontal);
+        calc.set_root_position(&Vector2D::new(3.0, 4.0));
+        place(&mut calc, 10.0, 10.0);
+        calc.reset();
+        assert_eq!(calc.size().unpack(), (0.0, 0.0));
+        assert_eq!(place(&mut calc, 10.0, 10.0), (3.0, 4.0));
+    }
+
+    #[test]
+    fn keeps_the_margin_free() {
+        let mut calc = NextPositionCalculator::new(Direction::Horizontal);
+        let margin = Edges::new(1.0, 2.0, 3.0, 4.0);
+        let position = calc.next(&mut widget(10.0, 10.0), &margin);
+        assert_eq!(position.unpack(), (margin.left, margin.top));
+        assert_eq!(calc.size().unpack(), (16.0, 14.0));
+        assert_eq!(place(&mut calc, 10.0, 10.0), (16.0, 0.0));
+    }
+}
@@ -0,0 +1,180 @@
+use std::f32::consts::PI;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use glyph_brush::ab_glyph::FontArc;
+use log::warn;
+
+use crate::general::{Color, Rect, Vector2D};
+use crate::texture::SoftTexture;
+use crate::themes::{PrimEnum, PrimId, PrimitivesManagerForThemes};
+use crate::widgets::primitives::{Bitmap, Primitive, Shape, Text};
+
+// Above anything the theme puts in the Canvas, like its background
+const PAINTED_BASE: usize = 1 << 20;
+
+#[derive(Clone, Copy, Debug)]
+struct PaintedPrimEnum(usize);
+
+impl PrimEnum for PaintedPrimEnum {
+    fn to_prim_id(self) -> PrimId {
+        PAINTED_BASE + self.0
+    }
+}
+
+/// An image loaded with Canvas::load_image(), to paint it as many times as needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CanvasImage(pub(crate) usize);
+
+/// What a Canvas' draw callback paints with, in Canvas coordinates.
+/// Later calls paint over earlier ones and anything outside the Canvas is cut.
+pub struct Painter<'a> {
+    size: Vector2D<f32>,
+    font: FontArc,
+    images: &'a [Arc<Mutex<dyn SoftTexture>>],
+    prim_man: &'a mut PrimitivesManagerForThemes,
+    count: usize,
+}
+
+impl<'a> Painter<'a> {
+    pub(crate) fn new(
+        size: Vector2D<f32>,
+        font: FontArc,
+        images: &'a [Arc<Mutex<dyn SoftTexture>>],
+        prim_man: &'a mut PrimitivesManagerForThemes,
+    ) -> Painter<'a> {
+        Painter {
+            size,
+            font,
+            images,
+            prim_man,
+            count: 0,
+        }
+    }
+    pub fn size(&self) -> &Vector2D<f32> {
+        &self.size
+    }
+    pub fn line(&mut self, from: Vector2D<f32>, to: Vector2D<f32>, thickness: f32, color: Color) {
+        let ((x1, y1), (x2, y2)) = (from.unpack(), to.unpack());
+        let length = (x2 - x1).hypot(y2 - y1);
+        if length == 0.0 {
+            return;
+        }
+        // Half the thickness to each side
+        let (nx, ny) = (
+            -(y2 - y1) / length * thickness / 2.0,
+            (x2 - x1) / length * thickness / 2.0,
+        );
+        let corners = [
+            Vector2D::new(x1 + nx, y1 + ny),
+            Vector2D::new(x1 - nx, y1 - ny),
+            Vector2D::new(x2 - nx, y2 - ny),
+            Vector2D::new(x2 + nx, y2 + ny),
+        ];
+        self.polygon(&corners, color);
+    }
+    /// Lines joining the points one after the other.
+    pub fn polyline(&mut self, points: &[Vector2D<f32>], thickness: f32, color: Color) {
+        for pair in points.windows(2) {
+            self.line(pair[0].clone(), pair[1].clone(), thickness, color.clone());
+        }
+    }
+    pub fn fill_rect(&mut self, rect: Rect<f32>, color: Color) {
+        let mut shape = Shape::new_square(0, Vector2D::new(rect.width(), rect.height()), 0, color);
+        shape.set_position(Vector2D::new(rect.x(), rect.y()));
+        self.add(shape);
+    }
+    /// The border is inside the rect.
+    pub fn stroke_rect(&mut self, rect: Rect<f32>, thickness: f32, color: Color) {
+        let (x, y, w, h) = rect.unpack();
+        let thickness = thickness.min(w / 2.0).min(h / 2.0);
+        let sides = [
+            Rect::new(x, y, w, thickness),
+            Rect::new(x, y + h - thickness, w, thickness),
+            Rect::new(x, y + thickness, thickness, h - thickness * 2.0),
+            Rect::new(
+                x + w - thickness,
+                y + thickness,
+                thickness,
+                h - thickness * 2.0,
+            ),
+        ];
+        for side in sides {
+            self.fill_rect(side, color.clone());
+        }
+    }
+    pub fn fill_circle(&mut self, center: Vector2D<f32>, radius: f32, color: Color) {
+        let points = Self::circle_points(&center, radius);
+        self.polygon(&points, color);
+    }
+    pub fn stroke_circle(
+        &mut self,
+        center: Vector2D<f32>,
+        radius: f32,
+        thickness: f32,
+        color: Color,
+    ) {
+        let mut points = Self::circle_points(&center, radius);
+        points.push(points[0].clone());
+        self.polyline(&points, thickness, color);
+    }
+    /// Filled, it has to be convex.
+    pub fn polygon(&mut self, points: &[Vector2D<f32>], color: Color) {
+        if points.len() >= 3 {
+            self.add(Shape::new_convex(0, points, color));
+        }
+    }
+    /// With the Canvas' font, position is the top left corner.
+    pub fn text(&mut self, position: Vector2D<f32>, text: &str, font_size: f32, color: Color) {
+        if text.is_empty() {
+            return;
+        }
+        let mut text = Text::new(0, text, font_size, self.font.clone(), color);
+        text.set_position(position);
+        self.add(text);
+    }
+    /// How big text() would paint it, to place it before painting it.
+    pub fn text_size(&self, text: &str, font_size: f32) -> Vector2D<f32> {
+        let width = Text::char_widths(text, font_size, self.font.clone())
+            .iter()
+            .sum();
+        Vector2D::new(width, Text::line_height(font_size, self.font.clone()))
+    }
+    /// At its own size, position is the top left corner. Images of another Canvas are skipped.
+    pub fn image(&mut self, position: Vector2D<f32>, image: CanvasImage) {
+        let Some(arc_tex) = self.images.get(image.0).cloned() else {
+            warn!("painter:Painter:image {:?} is from another Canvas", image);
+            return;
+        };
+        let mut bitmap = Bitmap::from_texture(0, arc_tex);
+        bitmap.set_position(position);
+        self.add(bitmap);
+    }
+    fn add<P: Primitive>(&mut self, primitive: P) {
+        let prim_enum = PaintedPrimEnum(self.count);
+        self.prim_man
+            .insert(prim_enum, primitive, prim_enum.to_prim_id() as isize);
+        self.count += 1;
+    }
+    fn circle_points(center: &Vector2D<f32>, radius: f32) -> Vec<Vector2D<f32>> {
+        // Enough sides to look round at any size
+        let sides = (radius.abs() as usize).clamp(12, 96);
+        let (x, y) = center.unpack();
+        (0..sides)
+            .map(|side| {
+                let angle = 2.0 * PI * side as f32 / sides as f32;
+                Vector2D::new(x + radius * angle.cos(), y + radius * angle.sin())
+            })
+            .collect()
+    }
+}
+
+impl Debug for Painter<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Painter {{ size: {:?}, painted: {} }}",
+            self.size, self.count
+        )
+    }
+}
@@ -36,6 +36,24 @@ impl Bitmap {
             size,
         })
     }
+    /// Shares the texture, many Bitmaps can show the same one.
+    pub fn from_texture(nid: usize, arc_tex: Arc<Mutex<dyn SoftTexture>>) -> Bitmap {
+        let (size, poly) = {
+            let tex = arc_tex.lock().expect("bitmap:Bitmap:from_texture");
+            let size = Vector2D::new(tex.width() as f32, tex.height() as f32);
+            (size, tex.poly().clone())
+        };
+        Bitmap {
+            nid,
+            arc_tex: arc_tex.clone(),
+            geometry: Geometry::new_for_texture("Bitmap", arc_tex, poly),
+            needs_update: false,
+            position: Default::default(),
+            needs_translation: true,
+            translated_geometry: Default::default(),
+            size,
+        }
+    }
 }
 
 impl PrivatePrimitiveMethods for Bitmap {
@@ -99,6 +117,7 @@ impl Primitive for Bitmap {
     }
     fn set_position(&mut self, position: Vector2D<f32>) {
         self.position = position;
+        self.needs_translation = true;
     }
     fn width(&self) -> f32 {
         self.size.x()
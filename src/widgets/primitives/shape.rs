@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use crate::general::{Color, Geometry, Polygon, TexturedPolygon, Vector2D};
+use crate::general::{ArrowDirection, Color, Geometry, Polygon, TexturedPolygon, Vector2D};
 use crate::widgets::primitives::private::PrivatePrimitiveMethods;
 use crate::widgets::primitives::Primitive;
 
@@ -34,6 +34,44 @@ impl Shape {
         let poly = Polygon::new_square(size.clone(), radius as f32, color);
         Self::new(nid, size, poly)
     }
+    /// A gradient, colors go top left, bottom left, bottom right and top right.
+    pub fn new_square_with_colors(
+        nid: usize,
+        size: Vector2D<f32>,
+        colors: (Color, Color, Color, Color),
+    ) -> Shape {
+        let poly = Polygon::new_square_with_colors(size.clone(), 0.0, colors);
+        Self::new(nid, size, poly)
+    }
+    pub fn new_arrow(
+        nid: usize,
+        size: Vector2D<f32>,
+        direction: ArrowDirection,
+        color: Color,
+    ) -> Shape {
+        let poly = Polygon::new_arrow(size.clone(), direction, color);
+        Self::new(nid, size, poly)
+    }
+    /// Placed at the top left corner of the points, so they can be anywhere.
+    pub fn new_convex(nid: usize, points: &[Vector2D<f32>], color: Color) -> Shape {
+        let (mut min_x, mut min_y) = (f32::MAX, f32::MAX);
+        let (mut max_x, mut max_y) = (f32::MIN, f32::MIN);
+        for (x, y) in points.iter().map(Vector2D::unpack) {
+            (min_x, min_y) = (min_x.min(x), min_y.min(y));
+            (max_x, max_y) = (max_x.max(x), max_y.max(y));
+        }
+        let relative: Vec<Vector2D<f32>> = points
+            .iter()
+            .map(|point| Vector2D::new(point.x() - min_x, point.y() - min_y))
+            .collect();
+        let poly = Polygon::new_convex(&relative, color);
+        let size = Vector2D::new((max_x - min_x).max(0.0), (max_y - min_y).max(0.0));
+        let mut shape = Self::new(nid, size, poly);
+        if !points.is_empty() {
+            shape.set_position(Vector2D::new(min_x, min_y));
+        }
+        shape
+    }
     #[allow(dead_code)]
     pub fn new_reg_poly(nid: usize, size: Vector2D<f32>, sides: u32, rotate: f32) -> Shape {
         let poly = Polygon::new_reg_poly(size.clone(), sides, rotate);
@@ -42,7 +80,11 @@ impl Shape {
     pub fn geometry_out_of_poly(poly: Polygon) -> Geometry {
         Geometry {
             _class: "Shape".to_string(),
-            polygons: vec![TexturedPolygon { poly, tex: None }],
+            polygons: vec![TexturedPolygon {
+                poly,
+                tex: None,
+                clip: None,
+            }],
         }
     }
 }
@@ -102,6 +144,7 @@ impl Primitive for Shape {
     }
     fn set_position(&mut self, position: Vector2D<f32>) {
         self.position = position;
+        self.needs_translation = true;
     }
     fn width(&self) -> f32 {
         self.size.x()
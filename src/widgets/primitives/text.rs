@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::ops::Range;
 use std::sync::{Arc, Mutex};
 
 use glyph_brush::ab_glyph::{Font, FontArc, ScaleFont};
@@ -26,6 +27,7 @@ pub struct Text {
 }
 
 impl Text {
+    pub const ELLIPSIS: &str = "...";
     pub fn new(nid: usize, text: &str, font_size: f32, font: FontArc, color: Color) -> Text {
         let (arc_tex, geometry, size) =
             Self::get_tex_geometry_and_size(text, font_size, font.clone(), color.clone());
@@ -61,6 +63,67 @@ impl Text {
         self.text = text.to_string();
         self.needs_update = true;
     }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    /// How wide each char is, without rasterizing anything. They add up to the Text's width.
+    pub fn char_widths(text: &str, font_size: f32, font: FontArc) -> Vec<f32> {
+        let scaled_font = font.as_scaled(font_size);
+        text.chars()
+            .map(|c| {
+                scaled_font
+                    .glyph_bounds(&scaled_font.scaled_glyph(c))
+                    .width()
+            })
+            .collect()
+    }
+    /// Char ranges of the rows that fit in max_width, breaking after spaces when it can.
+    /// widths come from char_widths(), a single char wider than max_width gets its own row.
+    pub fn wrap(text: &str, widths: &[f32], max_width: f32) -> Vec<Range<usize>> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut ranges = vec![];
+        let (mut start, mut x, mut last_space) = (0, 0.0, None);
+        for (col, width) in widths.iter().enumerate() {
+            if x + width > max_width && col > start {
+                // At the last space if there is one, otherwise in the middle of the word
+                let end = last_space.map_or(col, |space: usize| space + 1);
+                ranges.push(start..end);
+                start = end;
+                x = widths[start..col].iter().sum();
+                last_space = None;
+            }
+            x += width;
+            if chars[col] == ' ' {
+                last_space = Some(col);
+            }
+        }
+        ranges.push(start..chars.len());
+        ranges
+    }
+    /// Cuts the text so it fits in max_width with the ellipsis after it.
+    pub fn ellipsize(text: &str, max_width: f32, font_size: f32, font: FontArc) -> String {
+        let room = max_width
+            - Self::char_widths(Self::ELLIPSIS, font_size, font.clone())
+                .iter()
+                .sum::<f32>();
+        let widths = Self::char_widths(text, font_size, font);
+        let mut x = 0.0;
+        let fitting = widths
+            .iter()
+            .take_while(|width| {
+                x += *width;
+                x <= room
+            })
+            .count();
+        let mut cut: String = text.chars().take(fitting).collect();
+        cut.truncate(cut.trim_end().len());
+        cut.push_str(Self::ELLIPSIS);
+        cut
+    }
+    /// The height of any Text with this font and size.
+    pub fn line_height(font_size: f32, font: FontArc) -> f32 {
+        font.as_scaled(font_size).height().ceil()
+    }
     fn text_to_alpha_data(text: &str, font_size: f32, font: FontArc) -> (Vec<u8>, u32, u32) {
         let bounds = Self::get_texture_bounds(text, font_size, font.clone());
         debug!("{}() {:?}", stringify!(text_to_alpha), bounds);
@@ -179,6 +242,7 @@ impl Primitive for Text {
     }
     fn set_position(&mut self, position: Vector2D<f32>) {
         self.position = position;
+        self.needs_translation = true;
     }
     fn width(&self) -> f32 {
         self.size.x()
@@ -194,3 +258,37 @@ impl Primitive for Text {
         &self.size
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every char 1 wide
+    fn wrap(text: &str, max_width: f32) -> Vec<Range<usize>> {
+        Text::wrap(text, &vec![1.0; text.chars().count()], max_width)
+    }
+
+    #[test]
+    fn breaks_after_the_last_space() {
+        assert_eq!(wrap("hello world", 7.0), vec![0..6, 6..11]);
+        assert_eq!(wrap("ab  cd", 3.0), vec![0..3, 3..6]);
+    }
+
+    #[test]
+    fn fills_the_row_up_to_the_limit() {
+        assert_eq!(wrap("abcd", 4.0), vec![0..4]);
+        assert_eq!(wrap("abcde", 4.0), vec![0..4, 4..5]);
+    }
+
+    #[test]
+    fn too_wide_chars_get_their_own_row() {
+        assert_eq!(Text::wrap("ab", &[5.0, 5.0], 3.0), vec![0..1, 1..2]);
+        assert_eq!(wrap("", 3.0), vec![0..0]);
+    }
+
+    #[test]
+    fn ranges_are_in_chars() {
+        assert_eq!(wrap("héllo wörld", 7.0), vec![0..6, 6..11]);
+        assert_eq!(wrap("日本語の文", 2.0), vec![0..2, 2..4, 4..5]);
+    }
+}
@@ -0,0 +1,566 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForScrollView, ThemeStyleForScrollView,
+};
+use crate::widgets::events::{DeferredEvents, MouseButtonDownCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{
+    CommonWidget, Compound, Container, Direction, DowncastableBorrowedWidget, HasTooltip,
+    OwnedDynWidget, WidgetEnum, WidgetId,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// Pixels moved by one wheel notch or arrow key.
+const SCROLL_STEP: f32 = 40.0;
+
+/// A scroll bar placed by the ScrollView, relative to it, themes only have to draw it.
+#[derive(Debug, Clone)]
+pub struct ScrollBar {
+    pub direction: Direction,
+    pub track: Rect<f32>,
+    pub thumb: Rect<f32>,
+}
+
+impl ScrollBar {
+    fn along(&self, point: &Vector2D<f32>) -> f32 {
+        match self.direction {
+            Direction::Horizontal => point.x(),
+            Direction::Vertical => point.y(),
+        }
+    }
+    fn track_start_and_len(&self) -> (f32, f32) {
+        match self.direction {
+            Direction::Horizontal => (self.track.x(), self.track.width()),
+            Direction::Vertical => (self.track.y(), self.track.height()),
+        }
+    }
+    fn thumb_start_and_len(&self) -> (f32, f32) {
+        match self.direction {
+            Direction::Horizontal => (self.thumb.x(), self.thumb.width()),
+            Direction::Vertical => (self.thumb.y(), self.thumb.height()),
+        }
+    }
+}
+
+/// Shows a part of a Compound that can be bigger than the ScrollView, the rest is clipped.
+/// Scroll bars only appear in the directions the content doesn't fit.
+#[derive(Debug)]
+pub struct ScrollView {
+    common: CommonWidget,
+    // Its own size, see area_size()
+    size: Vector2D<f32>,
+    content: Compound,
+    offset: Vector2D<f32>,
+    content_size: Vector2D<f32>,
+    thickness: f32,
+    bars: Vec<ScrollBar>,
+    dragged_thumb: Option<(Direction, f32)>, // direction, where the thumb was grabbed
+    content_focused: bool,
+    pending_scroll_to: Option<WidgetId>,
+    scrolled: bool,
+    translated_geometry: Geometry,
+}
+
+impl ScrollView {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        size: Vector2D<f32>,
+        mut content: Compound,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<ScrollView, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForScrollView =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForScrollView> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let content_size = content.size().clone();
+        let thickness = style.thickness;
+        let viewport = style.box_model.content_size(&size);
+        let bars = Self::compute_bars(&viewport, &content_size, &Default::default(), thickness);
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        theme.new_scroll_view(&bars, Some(size.clone()), style, &mut prim_man);
+        Ok(ScrollView {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                size.clone(),
+                style_master,
+                prim_man,
+            ),
+            size,
+            content,
+            offset: Default::default(),
+            content_size,
+            thickness,
+            bars,
+            dragged_thumb: None,
+            content_focused: false,
+            pending_scroll_to: None,
+            scrolled: true,
+            translated_geometry: Default::default(),
+        })
+    }
+    pub fn content(&self) -> &Compound {
+        &self.content
+    }
+    pub fn content_mut(&mut self) -> &mut Compound {
+        &mut self.content
+    }
+    /// How much the content is scrolled, 0,0 shows its top left corner.
+    pub fn offset(&self) -> &Vector2D<f32> {
+        &self.offset
+    }
+    /// The offset is clamped so the content never leaves an empty area.
+    pub fn set_offset(&mut self, offset: Vector2D<f32>) {
+        let max = self.max_offset();
+        let offset = Vector2D::new(
+            offset.x().min(max.x()).max(0.0),
+            offset.y().min(max.y()).max(0.0),
+        );
+        if offset.unpack() != self.offset.unpack() {
+            self.offset = offset;
+            self.scrolled = true;
+            self.refresh();
+        }
+    }
+    pub fn scroll_by(&mut self, dx: f32, dy: f32) {
+        self.set_offset(Vector2D::new(self.offset.x() + dx, self.offset.y() + dy));
+    }
+    /// Scrolls the least needed to show the content's widget, done on the next build because
+    /// the widget's position is only known after the content is laid out.
+    pub fn scroll_to(&mut self, wid: WidgetId) {
+        self.pending_scroll_to = Some(wid);
+        self.scrolled = true;
+    }
+    fn compute_bars(
+        size: &Vector2D<f32>,
+        content_size: &Vector2D<f32>,
+        offset: &Vector2D<f32>,
+        thickness: f32,
+    ) -> Vec<ScrollBar> {
+        let visible = Self::compute_visible_size(size, content_size, thickness);
+        let max_x = (content_size.x() - visible.x()).max(0.0);
+        let max_y = (content_size.y() - visible.y()).max(0.0);
+        let thumb = |track_len: f32, visible: f32, content: f32, offset: f32, max: f32| {
+            let len = (track_len * visible / content)
+                .max(thickness)
+                .min(track_len);
+            let start = if max > 0.0 {
+                (track_len - len) * offset / max
+            } else {
+                0.0
+            };
+            (start, len)
+        };
+        let mut bars = vec![];
+        if visible.y() < size.y() {
+            let track = Rect::new(0.0, visible.y(), visible.x(), thickness);
+            let (start, len) = thumb(
+                visible.x(),
+                visible.x(),
+                content_size.x(),
+                offset.x(),
+                max_x,
+            );
+            bars.push(ScrollBar {
+                direction: Direction::Horizontal,
+                track,
+                thumb: Rect::new(start, visible.y(), len, thickness),
+            });
+        }
+        if visible.x() < size.x() {
+            let track = Rect::new(visible.x(), 0.0, thickness, visible.y());
+            let (start, len) = thumb(
+                visible.y(),
+                visible.y(),
+                content_size.y(),
+                offset.y(),
+                max_y,
+            );
+            bars.push(ScrollBar {
+                direction: Direction::Vertical,
+                track,
+                thumb: Rect::new(visible.x(), start, thickness, len),
+            });
+        }
+        bars
+    }
+    /// Size of the ScrollView without the scroll bars.
+    fn compute_visible_size(
+        size: &Vector2D<f32>,
+        content_size: &Vector2D<f32>,
+        thickness: f32,
+    ) -> Vector2D<f32> {
+        let mut vertical = content_size.y() > size.y();
+        let horizontal = content_size.x() > size.x() - if vertical { thickness } else { 0.0 };
+        // The horizontal bar can take the space the content needed to fit vertically
+        vertical = vertical || (horizontal && content_size.y() > size.y() - thickness);
+        Vector2D::new(
+            size.x() - if vertical { thickness } else { 0.0 },
+            size.y() - if horizontal { thickness } else { 0.0 },
+        )
+    }
+    fn visible_size(&self) -> Vector2D<f32> {
+        let size = self.common.content_size();
+        Self::compute_visible_size(&size, &self.content_size, self.thickness)
+    }
+    /// The visible area in window coordinates.
+    fn visible_rect(&self) -> Rect<f32> {
+        let (x, y) = self.common.content_position().unpack();
+        let (width, height) = self.visible_size().unpack();
+        Rect::new(x, y, width, height)
+    }
+    fn max_offset(&self) -> Vector2D<f32> {
+        let visible = self.visible_size();
+        Vector2D::new(
+            (self.content_size.x() - visible.x()).max(0.0),
+            (self.content_size.y() - visible.y()).max(0.0),
+        )
+    }
+    fn refresh(&mut self) {
+        let size = self.area_size();
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForScrollView =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForScrollView> =
+            binding.expect_style_for_widget_t(type_id, Self::class_name());
+        // The bars go inside the border and padding, like the content
+        let viewport = style.box_model.content_size(&size);
+        self.bars = Self::compute_bars(&viewport, &self.content_size, &self.offset, self.thickness);
+        theme.set_scroll_bars(
+            &self.bars,
+            Some(size.clone()),
+            style,
+            self.common.prim_man(),
+        );
+        self.common.set_size(size);
+    }
+    /// Its own size, unless a layout stretches it or its size constraints don't let it.
+    fn area_size(&self) -> Vector2D<f32> {
+        let size = self.common.layout_size().unwrap_or(&self.size);
+        self.common.size_constraints().clamp(size)
+    }
+    /// The offset that shows the content's widget, None if the content has no such widget.
+    /// Nested widgets are found too, their position is absolute like the content's.
+    fn offset_to_show(&self, wid: WidgetId) -> Option<Vector2D<f32>> {
+        let child = self.content.child(wid)?;
+        let x = child.x() - self.content.x();
+        let y = child.y() - self.content.y();
+        let visible = self.visible_size();
+        let scroll = |start: f32, len: f32, offset: f32, visible: f32| {
+            if start < offset {
+                start
+            } else if start + len > offset + visible {
+                start + len - visible
+            } else {
+                offset
+            }
+        };
+        Some(Vector2D::new(
+            scroll(x, child.width(), self.offset.x(), visible.x()),
+            scroll(y, child.height(), self.offset.y(), visible.y()),
+        ))
+    }
+    fn translate_content(&mut self) -> Geometry {
+        let (x, y) = self.common.content_position().unpack();
+        let position = Vector2D::new(x - self.offset.x(), y - self.offset.y());
+        self.content.set_position(position);
+        self.content.translate_geometry()
+    }
+    fn set_offset_along(&mut self, direction: Direction, value: f32) {
+        let offset = match direction {
+            Direction::Horizontal => Vector2D::new(value, self.offset.y()),
+            Direction::Vertical => Vector2D::new(self.offset.x(), value),
+        };
+        self.set_offset(offset);
+    }
+    fn click_on_bars(&mut self, local: &Vector2D<f32>) {
+        let Some(bar) = self
+            .bars
+            .iter()
+            .find(|bar| bar.track.contains_point(local.clone()))
+            .cloned()
+        else {
+            return;
+        };
+        let along = bar.along(local);
+        let (thumb_start, thumb_len) = bar.thumb_start_and_len();
+        if along >= thumb_start && along < thumb_start + thumb_len {
+            self.dragged_thumb = Some((bar.direction, along - thumb_start));
+            return;
+        }
+        // Clicking the track moves a page towards the click
+        let visible = self.visible_size();
+        let (page, offset) = match bar.direction {
+            Direction::Horizontal => (visible.x(), self.offset.x()),
+            Direction::Vertical => (visible.y(), self.offset.y()),
+        };
+        let page = if along < thumb_start { -page } else { page };
+        self.set_offset_along(bar.direction, offset + page);
+    }
+    fn drag_thumb(&mut self, direction: Direction, grab: f32, local: &Vector2D<f32>) {
+        let Some(bar) = self.bars.iter().find(|bar| bar.direction == direction) else {
+            return;
+        };
+        let (track_start, track_len) = bar.track_start_and_len();
+        let (_, thumb_len) = bar.thumb_start_and_len();
+        let free_len = track_len - thumb_len;
+        if free_len <= 0.0 {
+            return;
+        }
+        let ratio = (bar.along(local) - grab - track_start) / free_len;
+        let max = match direction {
+            Direction::Horizontal => self.max_offset().x(),
+            Direction::Vertical => self.max_offset().y(),
+        };
+        self.set_offset_along(direction, ratio * max);
+    }
+}
+
+impl Primitive for ScrollView {
+    fn class_name() -> &'static str {
+        "ScrollView"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position);
+        self.scrolled = true;
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        let mut content_geometry = self.translate_content();
+        let content_size = self.content.size().clone();
+        let target = self
+            .pending_scroll_to
+            .take()
+            .and_then(|wid| self.offset_to_show(wid));
+        if content_size.unpack() != self.content_size.unpack() || target.is_some() {
+            let previous_offset = self.offset.clone();
+            self.content_size = content_size;
+            self.refresh();
+            self.set_offset(target.unwrap_or_else(|| previous_offset.clone()));
+            self.common.update_geometry();
+            self.common.set_needs_update(false);
+            if self.offset.unpack() != previous_offset.unpack() {
+                content_geometry = self.translate_content();
+            }
+        }
+        content_geometry.clip(&self.visible_rect());
+        let geometries = vec![self.common.translate_geometry(), content_geometry];
+        self.scrolled = false;
+        self.translated_geometry = Geometry::new_from_geometries(Self::class_name(), geometries);
+        self.translated_geometry.clone()
+    }
+}
+
+impl PrivatePrimitiveMethods for ScrollView {
+    fn update_geometry(&mut self) {
+        if self.common.needs_update() {
+            self.common.update_geometry();
+        }
+        self.content.update_geometry();
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update() || self.content.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update);
+        self.content.set_needs_update(needs_update);
+    }
+    fn needs_translation(&self) -> bool {
+        self.scrolled || self.common.needs_translation() || self.content.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation);
+        self.content.set_needs_translation(needs_translation);
+    }
+    fn clone_geometry(&self) -> Geometry {
+        let geometries = vec![self.common.clone_geometry(), self.content.clone_geometry()];
+        Geometry::new_from_geometries(Self::class_name(), geometries)
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+
+impl HasEvents for ScrollView {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for ScrollView {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for ScrollView {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        // The visible content, a click on the border or the padding isn't one on the content
+        if self
+            .visible_rect()
+            .contains_point(Vector2D::new(x as f32, y as f32))
+        {
+            self.content_focused = self.child_that_accepts_click(x, y).is_some();
+            return self.content.handle_mouse_button_down(x, y);
+        }
+        let deferred_events = if self.content_focused {
+            self.content.handle_focus_changed(false)
+        } else {
+            vec![]
+        };
+        self.content_focused = false;
+        self.click_on_bars(&self.common.local(x, y));
+        deferred_events
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        if let Some((direction, grab)) = self.dragged_thumb {
+            let local = self.common.local(x, y);
+            self.drag_thumb(direction, grab, &local);
+            return vec![];
+        }
+        if self.content_focused {
+            return self.content.handle_mouse_drag(x, y);
+        }
+        vec![]
+    }
+    fn handle_mouse_button_up(&mut self, x: i32, y: i32) -> DeferredEvents {
+        if self.dragged_thumb.take().is_some() {
+            return vec![];
+        }
+        if self.content_focused {
+            return self.content.handle_mouse_button_up(x, y);
+        }
+        vec![]
+    }
+    fn handle_mouse_wheel(&mut self, _x: i32, _y: i32, dx: i32, dy: i32) -> DeferredEvents {
+        self.scroll_by(dx as f32 * SCROLL_STEP, -dy as f32 * SCROLL_STEP);
+        vec![]
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        if self.content_focused {
+            return self.content.handle_key_down(key, keymod);
+        }
+        let page = self.visible_size().y();
+        match key {
+            Keycode::Up => self.scroll_by(0.0, -SCROLL_STEP),
+            Keycode::Down => self.scroll_by(0.0, SCROLL_STEP),
+            Keycode::Left => self.scroll_by(-SCROLL_STEP, 0.0),
+            Keycode::Right => self.scroll_by(SCROLL_STEP, 0.0),
+            Keycode::PageUp => self.scroll_by(0.0, -page),
+            Keycode::PageDown => self.scroll_by(0.0, page),
+            Keycode::Home => self.set_offset_along(Direction::Vertical, 0.0),
+            Keycode::End => self.set_offset_along(Direction::Vertical, self.max_offset().y()),
+            _ => {}
+        }
+        vec![]
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        self.content_focused && self.content.wants_key(key)
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        if self.content_focused {
+            return self.content.handle_text_input(text);
+        }
+        vec![]
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        if self.content_focused {
+            return self.content.handle_focus_changed(focused);
+        }
+        vec![]
+    }
+    fn as_container(&self) -> Option<&dyn Container> {
+        Some(self)
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        Some(self)
+    }
+}
+
+/// Clicks only reach the content inside the visible area.
+impl Container for ScrollView {
+    fn child_wids(&self) -> Vec<WidgetId> {
+        self.content.child_wids()
+    }
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget> {
+        self.content.child(wid)
+    }
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        let point = Vector2D::new(x as f32, y as f32);
+        if !self.visible_rect().contains_point(point) {
+            return None;
+        }
+        self.content.child_that_accepts_click(x, y)
+    }
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+        self.content.borrow_child(wid)
+    }
+    fn return_borrowed_widgets(&mut self) {
+        self.content.return_borrowed_widgets();
+    }
+}
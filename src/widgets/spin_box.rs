@@ -0,0 +1,425 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForSpinBox, ThemeStyleForSpinBox,
+};
+use crate::widgets::events::{
+    DeferredEvents, MouseButtonDownCallback, ValueChanged, ValueChangedCallback,
+};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CommonWidget, EditableText, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// Turns the value into the text shown, e.g. hex for memory addresses.
+pub type SpinBoxFormatter = fn(value: f64) -> String;
+/// The other way around, None when the text isn't a valid number.
+pub type SpinBoxParser = fn(text: &str) -> Option<f64>;
+
+/// What themes get to draw. width_hint is the widest text the value can have.
+#[derive(Debug, Clone)]
+pub struct SpinBoxFrame {
+    pub text: String,
+    pub width_hint: String,
+    pub caret: Option<usize>,
+    pub valid: bool,
+}
+
+/// Where the theme placed the buttons, relative to the spin box.
+#[derive(Debug, Clone)]
+pub struct SpinBoxLayout {
+    pub size: Vector2D<f32>,
+    pub up: Rect<f32>,
+    pub down: Rect<f32>,
+}
+
+/// Number input for integers or floats, always within its range.
+/// The text is only applied with Enter, the arrows, the buttons or when it loses the focus.
+#[derive(Debug)]
+pub struct SpinBox {
+    common: CommonWidget,
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    decimals: usize,
+    formatter: Option<SpinBoxFormatter>,
+    parser: Option<SpinBoxParser>,
+    editor: EditableText,
+    focused: bool,
+    layout: SpinBoxLayout,
+    event_value_changed: ValueChanged,
+}
+
+impl SpinBox {
+    /// Values are kept as f64, so the ones past it wouldn't be exact.
+    pub const MAX_SAFE_INT: i64 = 1 << 53;
+    /// value, min and max must be within MAX_SAFE_INT either way.
+    pub fn new_int<WENUM: WidgetEnum>(
+        wid: WENUM,
+        value: i64,
+        min: i64,
+        max: i64,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<SpinBox, Box<dyn Error>> {
+        let safe = -Self::MAX_SAFE_INT..=Self::MAX_SAFE_INT;
+        if let Some(unsafe_int) = [value, min, max]
+            .into_iter()
+            .find(|int| !safe.contains(int))
+        {
+            return Err(Box::from(format!(
+                "spin_box:SpinBox:new_int {} is past MAX_SAFE_INT",
+                unsafe_int
+            )));
+        }
+        Self::new(wid, value as f64, min as f64, max as f64, 0, style_master)
+    }
+    /// decimals is how many are shown and kept, the step defaults to the smallest of them.
+    pub fn new_float<WENUM: WidgetEnum>(
+        wid: WENUM,
+        value: f64,
+        min: f64,
+        max: f64,
+        decimals: usize,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<SpinBox, Box<dyn Error>> {
+        Self::new(wid, value, min, max, decimals, style_master)
+    }
+    fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        value: f64,
+        min: f64,
+        max: f64,
+        decimals: usize,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<SpinBox, Box<dyn Error>> {
+        if min > max {
+            return Err(Box::from(format!(
+                "spin_box:SpinBox:new min {} is greater than max {}",
+                min, max
+            )));
+        }
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForSpinBox =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForSpinBox> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut spin_box = SpinBox {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                Vector2D::new(0.0, 0.0),
+                style_master.clone(),
+                PrimitivesManagerForThemes::new(),
+            ),
+            value: 0.0,
+            min,
+            max,
+            step: 10f64.powi(-(decimals as i32)),
+            decimals,
+            formatter: None,
+            parser: None,
+            editor: EditableText::default(),
+            focused: false,
+            layout: SpinBoxLayout {
+                size: Vector2D::new(0.0, 0.0),
+                up: Rect::new(0.0, 0.0, 0.0, 0.0),
+                down: Rect::new(0.0, 0.0, 0.0, 0.0),
+            },
+            event_value_changed: Default::default(),
+        };
+        spin_box.value = spin_box.constrain(value);
+        spin_box.editor.set_text(&spin_box.format(spin_box.value));
+        let frame = spin_box.frame();
+        spin_box.layout = theme.new_spin_box(&frame, None, style, spin_box.common.prim_man());
+        spin_box.common.set_size(spin_box.layout.size.clone());
+        Ok(spin_box)
+    }
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+    pub fn value_i64(&self) -> i64 {
+        self.value.round() as i64
+    }
+    /// Clamped to the range, doesn't call the value changed callback.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = self.constrain(value);
+        self.revert_text();
+    }
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+    pub fn set_range(&mut self, min: f64, max: f64) -> Result<(), Box<dyn Error>> {
+        if min > max {
+            return Err(Box::from(format!(
+                "spin_box:SpinBox:set_range min {} is greater than max {}",
+                min, max
+            )));
+        }
+        self.min = min;
+        self.max = max;
+        self.set_value(self.value);
+        Ok(())
+    }
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+    /// How much the arrows, the buttons and the wheel add, PageUp and PageDown add ten times it.
+    pub fn set_step(&mut self, step: f64) {
+        self.step = step;
+    }
+    /// Both should agree, whatever the formatter gives the parser must take.
+    pub fn set_formatter(&mut self, formatter: SpinBoxFormatter, parser: SpinBoxParser) {
+        self.formatter = Some(formatter);
+        self.parser = Some(parser);
+        self.revert_text();
+    }
+    pub fn event_value_changed(&self) -> Arc<ValueChangedCallback> {
+        self.event_value_changed.callback.clone()
+    }
+    pub fn set_event_value_changed(&mut self, callback: ValueChangedCallback) {
+        self.event_value_changed = ValueChanged {
+            callback: Arc::new(callback),
+        }
+    }
+    fn format(&self, value: f64) -> String {
+        match self.formatter {
+            Some(formatter) => formatter(value),
+            None => format!("{:.*}", self.decimals, value),
+        }
+    }
+    fn parse(&self, text: &str) -> Option<f64> {
+        match self.parser {
+            Some(parser) => parser(text),
+            None => text.trim().parse::<f64>().ok(),
+        }
+        .filter(|value| value.is_finite())
+    }
+    /// Rounded to the decimals and clamped to the range.
+    fn constrain(&self, value: f64) -> f64 {
+        let scale = 10f64.powi(self.decimals as i32);
+        ((value * scale).round() / scale).clamp(self.min, self.max)
+    }
+    /// Parses and is in the range, out of range values are clamped when applied though.
+    fn is_valid(&self) -> bool {
+        self.parse(self.editor.text())
+            .is_some_and(|value| value >= self.min && value <= self.max)
+    }
+    fn frame(&self) -> SpinBoxFrame {
+        let (min, max) = (self.format(self.min), self.format(self.max));
+        SpinBoxFrame {
+            text: self.editor.text().to_string(),
+            width_hint: if min.chars().count() > max.chars().count() {
+                min
+            } else {
+                max
+            },
+            caret: self.focused.then_some(self.editor.caret()),
+            valid: self.is_valid(),
+        }
+    }
+    fn revert_text(&mut self) {
+        self.editor.set_text(&self.format(self.value));
+        self.refresh();
+    }
+    /// Takes the typed text, or goes back to the value if it doesn't parse.
+    fn apply_text(&mut self) -> DeferredEvents {
+        match self.parse(self.editor.text()) {
+            Some(value) => self.change_value(value),
+            None => {
+                self.revert_text();
+                vec![]
+            }
+        }
+    }
+    fn step_by(&mut self, steps: f64) -> DeferredEvents {
+        let mut deferred_events = self.apply_text();
+        deferred_events.extend(self.change_value(self.value + self.step * steps));
+        deferred_events
+    }
+    fn change_value(&mut self, value: f64) -> DeferredEvents {
+        let old_value = self.value;
+        self.set_value(value);
+        if self.value == old_value {
+            return vec![];
+        }
+        vec![self.event_value_changed.defer(self.wid(), self.value)]
+    }
+    fn refresh(&mut self) {
+        let frame = self.frame();
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForSpinBox =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForSpinBox> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout = theme.set_spin_box(&frame, size_for_clipping, style, prim_man);
+            self.layout.size.clone()
+        });
+    }
+}
+
+impl Primitive for SpinBox {
+    fn class_name() -> &'static str {
+        "SpinBox"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for SpinBox {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for SpinBox {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for SpinBox {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for SpinBox {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let local = self.common.local(x, y);
+        if self.layout.up.contains_point(local.clone()) {
+            self.step_by(1.0)
+        } else if self.layout.down.contains_point(local) {
+            self.step_by(-1.0)
+        } else {
+            vec![]
+        }
+    }
+    fn handle_mouse_wheel(&mut self, _x: i32, _y: i32, _dx: i32, dy: i32) -> DeferredEvents {
+        if dy == 0 {
+            return vec![];
+        }
+        self.step_by(dy.signum() as f64)
+    }
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        matches!(key, Keycode::Return | Keycode::KpEnter)
+    }
+    fn handle_key_down(&mut self, key: Keycode, _keymod: Mod) -> DeferredEvents {
+        match key {
+            Keycode::Up => self.step_by(1.0),
+            Keycode::Down => self.step_by(-1.0),
+            Keycode::PageUp => self.step_by(10.0),
+            Keycode::PageDown => self.step_by(-10.0),
+            Keycode::Return | Keycode::KpEnter => self.apply_text(),
+            _ => {
+                if self.editor.handle_key(key) {
+                    self.refresh();
+                }
+                vec![]
+            }
+        }
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        self.editor.insert(text);
+        self.refresh();
+        vec![]
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        self.focused = focused;
+        if focused {
+            self.refresh();
+            vec![]
+        } else {
+            self.apply_text()
+        }
+    }
+}
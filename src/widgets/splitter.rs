@@ -0,0 +1,608 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    BoxModel, PrimitivesManagerForThemes, StyleMaster, ThemeForSplitter, ThemeStyleForSplitter,
+};
+use crate::widgets::events::{DeferredEvents, MouseButtonDownCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{
+    CommonWidget, Compound, Container, Direction, DowncastableBorrowedWidget, HasTooltip,
+    OwnedDynWidget, WidgetEnum, WidgetId,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// Two clicks on the same divider closer than this are a double click.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+#[derive(Debug)]
+struct SplitterPane {
+    content: Compound,
+    ratio: f32,
+    min: f32,
+    max: f32,
+    collapsed: bool,
+    rect: Rect<f32>, // Relative to the Splitter
+}
+
+/// Splits its area between panes, side by side when Horizontal, stacked when Vertical.
+/// Dividers can be dragged and double clicking one collapses the pane before it, or brings
+/// back a collapsed neighbour. Pane contents bigger than their pane are clipped.
+#[derive(Debug)]
+pub struct Splitter {
+    common: CommonWidget,
+    direction: Direction,
+    size: Vector2D<f32>,
+    thickness: f32,
+    // The panes go inside its border and padding
+    box_model: BoxModel,
+    panes: Vec<SplitterPane>,
+    dividers: Vec<Rect<f32>>,
+    dragged: Option<(usize, f32)>, // divider, where it was grabbed
+    last_click: Option<(usize, Instant)>,
+    focused_pane: Option<usize>,
+    laid_out: bool,
+    translated_geometry: Geometry,
+}
+
+impl Splitter {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        size: Vector2D<f32>,
+        direction: Direction,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Splitter, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForSplitter =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForSplitter> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let thickness = style.thickness;
+        let box_model = style.box_model.clone();
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        theme.new_splitter(direction, &[], None, None, style, &mut prim_man);
+        Ok(Splitter {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                size.clone(),
+                style_master,
+                prim_man,
+            ),
+            direction,
+            size,
+            thickness,
+            box_model,
+            panes: vec![],
+            dividers: vec![],
+            dragged: None,
+            last_click: None,
+            focused_pane: None,
+            laid_out: true,
+            translated_geometry: Default::default(),
+        })
+    }
+    /// Every pane gets the same share after adding one, returns its index.
+    pub fn add_pane(&mut self, content: Compound) -> usize {
+        self.panes.push(SplitterPane {
+            content,
+            ratio: 0.0,
+            min: 0.0,
+            max: f32::MAX,
+            collapsed: false,
+            rect: Rect::new_zero(),
+        });
+        let ratio = 1.0 / self.panes.len() as f32;
+        for pane in &mut self.panes {
+            pane.ratio = ratio;
+        }
+        self.refresh();
+        self.panes.len() - 1
+    }
+    pub fn pane_count(&self) -> usize {
+        self.panes.len()
+    }
+    pub fn pane(&self, index: usize) -> Option<&Compound> {
+        self.panes.get(index).map(|pane| &pane.content)
+    }
+    pub fn pane_mut(&mut self, index: usize) -> Option<&mut Compound> {
+        self.panes.get_mut(index).map(|pane| &mut pane.content)
+    }
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+    /// The share of each pane, they add up to 1. Collapsed panes keep the share they
+    /// come back to, so save is_collapsed() too.
+    pub fn ratios(&self) -> Vec<f32> {
+        self.panes.iter().map(|pane| pane.ratio).collect()
+    }
+    /// e.g. what ratios() gave when the app was closed, they don't need to add up to 1.
+    /// Extra values are ignored and panes without one keep theirs.
+    pub fn set_ratios(&mut self, ratios: &[f32]) {
+        for (pane, ratio) in self.panes.iter_mut().zip(ratios) {
+            pane.ratio = ratio.max(0.0);
+        }
+        self.normalize_ratios();
+        self.refresh();
+    }
+    /// In pixels, dragging stops at them. max can be f32::MAX.
+    pub fn set_pane_limits(&mut self, index: usize, min: f32, max: f32) {
+        if let Some(pane) = self.panes.get_mut(index) {
+            pane.min = min.max(0.0);
+            pane.max = max.max(pane.min);
+            self.refresh();
+        }
+    }
+    pub fn is_collapsed(&self, index: usize) -> bool {
+        self.panes.get(index).is_some_and(|pane| pane.collapsed)
+    }
+    /// Collapsed panes take no space, the rest share it. The last open pane can't collapse.
+    pub fn set_collapsed(&mut self, index: usize, collapsed: bool) {
+        let open = self.panes.iter().filter(|pane| !pane.collapsed).count();
+        let Some(pane) = self.panes.get_mut(index) else {
+            return;
+        };
+        if collapsed && !pane.collapsed && open <= 1 {
+            return;
+        }
+        pane.collapsed = collapsed;
+        self.refresh();
+    }
+    /// Its own size, a layout that stretches it and its size constraints still win.
+    pub fn resize(&mut self, size: Vector2D<f32>) {
+        self.size = size;
+        self.refresh();
+    }
+    /// Where the pane is, relative to the Splitter.
+    pub fn pane_rect(&self, index: usize) -> Option<Rect<f32>> {
+        self.panes.get(index).map(|pane| pane.rect)
+    }
+    fn along(&self, point: &Vector2D<f32>) -> f32 {
+        match self.direction {
+            Direction::Horizontal => point.x(),
+            Direction::Vertical => point.y(),
+        }
+    }
+    /// Where the rect starts and how long it is, along the direction.
+    fn along_rect(&self, rect: &Rect<f32>) -> (f32, f32) {
+        match self.direction {
+            Direction::Horizontal => (rect.x(), rect.width()),
+            Direction::Vertical => (rect.y(), rect.height()),
+        }
+    }
+    fn available(&self) -> f32 {
+        let dividers = self.panes.len().saturating_sub(1) as f32 * self.thickness;
+        (self.along(&self.content_size()) - dividers).max(0.0)
+    }
+    fn normalize_ratios(&mut self) {
+        let total: f32 = self.panes.iter().map(|pane| pane.ratio).sum();
+        let count = self.panes.len() as f32;
+        for pane in &mut self.panes {
+            pane.ratio = if total > 0.0 {
+                pane.ratio / total
+            } else {
+                1.0 / count
+            };
+        }
+    }
+    /// Pane lengths from their ratios, within their limits as long as they fit.
+    fn pane_lengths(&self) -> Vec<f32> {
+        let available = self.available();
+        let mut lengths = vec![0.0; self.panes.len()];
+        let mut free: Vec<usize> = (0..self.panes.len())
+            .filter(|index| !self.panes[*index].collapsed)
+            .collect();
+        let mut left = available;
+        // Panes stuck at a limit are taken out and the rest share what's left, until none is
+        while !free.is_empty() {
+            let shares: f32 = free.iter().map(|index| self.panes[*index].ratio).sum();
+            let mut stuck = vec![];
+            for index in &free {
+                let pane = &self.panes[*index];
+                let share = if shares > 0.0 {
+                    pane.ratio / shares
+                } else {
+                    1.0 / free.len() as f32
+                };
+                let length = left * share;
+                lengths[*index] = length.clamp(pane.min, pane.max);
+                if lengths[*index] != length {
+                    stuck.push(*index);
+                }
+            }
+            if stuck.is_empty() {
+                break;
+            }
+            left -= stuck.iter().map(|index| lengths[*index]).sum::<f32>();
+            free.retain(|index| !stuck.contains(index));
+        }
+        lengths
+    }
+    fn layout(&mut self) {
+        let lengths = self.pane_lengths();
+        let (width, height) = self.content_size().unpack();
+        let mut start = 0.0;
+        self.dividers.clear();
+        for (index, length) in lengths.into_iter().enumerate() {
+            let rect_at = |start: f32, length: f32| match self.direction {
+                Direction::Horizontal => Rect::new(start, 0.0, length, height),
+                Direction::Vertical => Rect::new(0.0, start, width, length),
+            };
+            self.panes[index].rect = rect_at(start, length);
+            start += length;
+            if index + 1 < self.panes.len() {
+                self.dividers.push(rect_at(start, self.thickness));
+                start += self.thickness;
+            }
+        }
+        self.set_panes_position();
+    }
+    /// Tells the pane that had the focus when it loses it.
+    fn focus_pane(&mut self, pane: Option<usize>) -> DeferredEvents {
+        let old_pane = std::mem::replace(&mut self.focused_pane, pane);
+        match old_pane.filter(|&old_pane| Some(old_pane) != pane) {
+            Some(old_pane) => self.panes[old_pane].content.handle_focus_changed(false),
+            None => vec![],
+        }
+    }
+    fn refresh(&mut self) {
+        self.layout();
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForSplitter =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForSplitter> =
+            binding.expect_style_for_widget_t(type_id, Self::class_name());
+        let size = self.area_size();
+        theme.set_splitter(
+            self.direction,
+            &self.dividers,
+            self.dragged.map(|(divider, _)| divider),
+            Some(size.clone()),
+            style,
+            self.common.prim_man(),
+        );
+        self.common.set_size(size);
+        self.laid_out = true;
+    }
+    /// Its own size, unless a layout stretches it or its size constraints don't let it.
+    fn area_size(&self) -> Vector2D<f32> {
+        let size = self.common.layout_size().unwrap_or(&self.size);
+        self.common.size_constraints().clamp(size)
+    }
+    /// area_size() inside the border and padding, where the panes are.
+    fn content_size(&self) -> Vector2D<f32> {
+        self.box_model.content_size(&self.area_size())
+    }
+    fn set_panes_position(&mut self) {
+        let (x, y) = self.common.content_position().unpack();
+        for pane in &mut self.panes {
+            let position = Vector2D::new(x + pane.rect.x(), y + pane.rect.y());
+            pane.content.set_position(position);
+        }
+    }
+    fn divider_at(&self, local: &Vector2D<f32>) -> Option<usize> {
+        self.dividers
+            .iter()
+            .position(|divider| divider.contains_point(local.clone()))
+    }
+    fn pane_at(&self, local: &Vector2D<f32>) -> Option<usize> {
+        self.panes
+            .iter()
+            .position(|pane| !pane.collapsed && pane.rect.contains_point(local.clone()))
+    }
+    fn toggle_collapse(&mut self, divider: usize) {
+        // A collapsed neighbour comes back, otherwise the pane before collapses
+        if self.panes[divider].collapsed {
+            self.set_collapsed(divider, false);
+        } else if self.panes[divider + 1].collapsed {
+            self.set_collapsed(divider + 1, false);
+        } else {
+            self.set_collapsed(divider, true);
+        }
+    }
+    fn drag_divider(&mut self, divider: usize, grab: f32, local: &Vector2D<f32>) {
+        let (before, after) = (divider, divider + 1);
+        let (start, length_before) = self.along_rect(&self.panes[before].rect);
+        let pair = length_before + self.along_rect(&self.panes[after].rect).1;
+        // Dragging a collapsed pane open, the limits of both panes stop the divider
+        let (min_before, max_before) = (self.panes[before].min, self.panes[before].max);
+        let (min_after, max_after) = (self.panes[after].min, self.panes[after].max);
+        let length = (self.along(local) - grab - start)
+            .min(max_before)
+            .min(pair - min_after)
+            .max(min_before)
+            .max(pair - max_after)
+            .clamp(0.0, pair);
+        let available = self.available();
+        if available <= 0.0 {
+            return;
+        }
+        let lengths = self.pane_lengths();
+        let open_share: f32 = self
+            .panes
+            .iter()
+            .filter(|pane| !pane.collapsed)
+            .map(|pane| pane.ratio)
+            .sum();
+        let open_length: f32 = lengths.iter().sum();
+        for (index, pane) in self.panes.iter_mut().enumerate() {
+            let pane_length = match index {
+                _ if index == before => length,
+                _ if index == after => pair - length,
+                _ if pane.collapsed => continue,
+                _ => lengths[index],
+            };
+            pane.collapsed = false;
+            pane.ratio = pane_length / open_length.max(1.0) * open_share;
+        }
+        self.normalize_ratios();
+        self.refresh();
+    }
+}
+
+impl Primitive for Splitter {
+    fn class_name() -> &'static str {
+        "Splitter"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position);
+        self.set_panes_position();
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        let (x, y) = self.common.content_position().unpack();
+        let mut geometries = vec![];
+        for pane in &mut self.panes {
+            if pane.collapsed {
+                continue;
+            }
+            let mut geometry = pane.content.translate_geometry();
+            let (width, height) = (pane.rect.width(), pane.rect.height());
+            geometry.clip(&Rect::new(
+                x + pane.rect.x(),
+                y + pane.rect.y(),
+                width,
+                height,
+            ));
+            geometries.push(geometry);
+        }
+        // Dividers above the panes' contents
+        geometries.push(self.common.translate_geometry());
+        self.laid_out = false;
+        self.translated_geometry = Geometry::new_from_geometries(Self::class_name(), geometries);
+        self.translated_geometry.clone()
+    }
+}
+
+impl PrivatePrimitiveMethods for Splitter {
+    fn update_geometry(&mut self) {
+        if self.common.needs_update() {
+            self.common.update_geometry();
+        }
+        for pane in &mut self.panes {
+            pane.content.update_geometry();
+        }
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update() || self.panes.iter().any(|pane| pane.content.needs_update())
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update);
+        for pane in &mut self.panes {
+            pane.content.set_needs_update(needs_update);
+        }
+    }
+    fn needs_translation(&self) -> bool {
+        self.laid_out
+            || self.common.needs_translation()
+            || self
+                .panes
+                .iter()
+                .any(|pane| pane.content.needs_translation())
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation);
+        for pane in &mut self.panes {
+            pane.content.set_needs_translation(needs_translation);
+        }
+    }
+    fn clone_geometry(&self) -> Geometry {
+        let mut geometries: Vec<Geometry> = self
+            .panes
+            .iter()
+            .filter(|pane| !pane.collapsed)
+            .map(|pane| pane.content.clone_geometry())
+            .collect();
+        geometries.push(self.common.clone_geometry());
+        Geometry::new_from_geometries(Self::class_name(), geometries)
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+
+impl HasEvents for Splitter {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Splitter {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Splitter {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let local = self.common.local(x, y);
+        if let Some(divider) = self.divider_at(&local) {
+            let deferred_events = self.focus_pane(None);
+            let now = Instant::now();
+            let double_click = self
+                .last_click
+                .is_some_and(|(last, at)| last == divider && now.duration_since(at) < DOUBLE_CLICK);
+            if double_click {
+                self.last_click = None;
+                self.toggle_collapse(divider);
+                return deferred_events;
+            }
+            self.last_click = Some((divider, now));
+            let grab = self.along(&local) - self.along_rect(&self.dividers[divider]).0;
+            self.dragged = Some((divider, grab));
+            self.refresh();
+            return deferred_events;
+        }
+        let pane = self.pane_at(&local);
+        let mut deferred_events = self.focus_pane(pane);
+        if let Some(index) = pane {
+            deferred_events.extend(self.panes[index].content.handle_mouse_button_down(x, y));
+        }
+        deferred_events
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        if let Some((divider, grab)) = self.dragged {
+            let local = self.common.local(x, y);
+            self.drag_divider(divider, grab, &local);
+            return vec![];
+        }
+        match self.focused_pane {
+            Some(index) => self.panes[index].content.handle_mouse_drag(x, y),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_button_up(&mut self, x: i32, y: i32) -> DeferredEvents {
+        if self.dragged.take().is_some() {
+            self.refresh();
+            return vec![];
+        }
+        match self.focused_pane {
+            Some(index) => self.panes[index].content.handle_mouse_button_up(x, y),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) -> DeferredEvents {
+        match self.pane_at(&self.common.local(x, y)) {
+            Some(index) => self.panes[index].content.handle_mouse_wheel(x, y, dx, dy),
+            None => vec![],
+        }
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        match self.focused_pane {
+            Some(index) => self.panes[index].content.handle_key_down(key, keymod),
+            None => vec![],
+        }
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        self.focused_pane
+            .is_some_and(|index| self.panes[index].content.wants_key(key))
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        match self.focused_pane {
+            Some(index) => self.panes[index].content.handle_text_input(text),
+            None => vec![],
+        }
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        match self.focused_pane {
+            Some(index) => self.panes[index].content.handle_focus_changed(focused),
+            None => vec![],
+        }
+    }
+    fn as_container(&self) -> Option<&dyn Container> {
+        Some(self)
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        Some(self)
+    }
+}
+
+/// Clicks only reach what is inside an open pane.
+impl Container for Splitter {
+    fn child_wids(&self) -> Vec<WidgetId> {
+        self.panes
+            .iter()
+            .flat_map(|pane| pane.content.child_wids())
+            .collect()
+    }
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget> {
+        self.panes.iter().find_map(|pane| pane.content.child(wid))
+    }
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        let index = self.pane_at(&self.common.local(x, y))?;
+        self.panes[index].content.child_that_accepts_click(x, y)
+    }
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+        self.panes
+            .iter_mut()
+            .find_map(|pane| pane.content.borrow_child(wid))
+    }
+    fn return_borrowed_widgets(&mut self) {
+        for pane in &mut self.panes {
+            pane.content.return_borrowed_widgets();
+        }
+    }
+}
@@ -0,0 +1,533 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::debug;
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForTabView, ThemeStyleForTabView,
+};
+use crate::widgets::events::{
+    DeferredEvents, MouseButtonDownCallback, SelectionChanged, SelectionChangedCallback,
+};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{
+    CommonWidget, Compound, Container, DowncastableBorrowedWidget, HasTooltip, OwnedDynWidget,
+    WidgetEnum, WidgetId,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+/// What themes get to draw for each tab.
+#[derive(Debug, Clone)]
+pub struct TabViewTab {
+    pub title: String,
+    pub closable: bool,
+    pub active: bool,
+}
+
+/// Where the theme placed each tab, relative to the TabView, so clicks can be matched to them.
+#[derive(Debug, Clone, Default)]
+pub struct TabStripLayout {
+    pub size: Vector2D<f32>,
+    pub tabs: Vec<Rect<f32>>,
+    pub close_buttons: Vec<Option<Rect<f32>>>,
+}
+
+#[derive(Debug)]
+struct TabPage {
+    title: String,
+    closable: bool,
+    content: Compound,
+}
+
+/// A tab strip on top of several pages, only the active page is built and receives events.
+/// Add the pages before adding the TabView to the window, so their widgets can be looked up.
+#[derive(Debug)]
+pub struct TabView {
+    common: CommonWidget,
+    pages: Vec<TabPage>,
+    active: usize,
+    layout: TabStripLayout,
+    size: Vector2D<f32>,
+    dragged_tab: Option<usize>,
+    active_changed: bool,
+    translated_geometry: Geometry,
+    event_tab_changed: SelectionChanged,
+    event_tab_closed: SelectionChanged,
+}
+
+impl TabView {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<TabView, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForTabView =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForTabView> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let layout = theme.new_tab_view(&[], None, style, &mut prim_man);
+        let size = layout.size.clone();
+        Ok(TabView {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                size.clone(),
+                style_master,
+                prim_man,
+            ),
+            pages: vec![],
+            active: 0,
+            layout,
+            size,
+            dragged_tab: None,
+            active_changed: false,
+            translated_geometry: Default::default(),
+            event_tab_changed: Default::default(),
+            event_tab_closed: Default::default(),
+        })
+    }
+    /// Returns the index of the new tab.
+    pub fn add_tab(&mut self, title: &str, page: Compound) -> usize {
+        self.insert_page(title, false, page)
+    }
+    /// Same as add_tab() but the tab shows a button to close it.
+    pub fn add_closable_tab(&mut self, title: &str, page: Compound) -> usize {
+        self.insert_page(title, true, page)
+    }
+    /// Removes the tab and gives its page back.
+    pub fn close_tab(&mut self, index: usize) -> Option<Compound> {
+        if index >= self.pages.len() {
+            return None;
+        }
+        let page = self.pages.remove(index);
+        if index < self.active || self.active >= self.pages.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+        self.active_changed = true;
+        self.refresh();
+        Some(page.content)
+    }
+    /// Moves a tab to another index, the same page stays active.
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        if from >= self.pages.len() || to >= self.pages.len() || from == to {
+            return;
+        }
+        let active_wid = self.page_wid(self.active);
+        let page = self.pages.remove(from);
+        self.pages.insert(to, page);
+        if let Some(active_wid) = active_wid {
+            self.active = self.index_of(active_wid).unwrap_or_default();
+        }
+        self.refresh();
+    }
+    /// Activates without triggering the tab changed event.
+    pub fn set_active(&mut self, index: usize) {
+        if index < self.pages.len() && index != self.active {
+            self.active = index;
+            self.active_changed = true;
+            self.refresh();
+        }
+    }
+    pub fn active(&self) -> usize {
+        self.active
+    }
+    pub fn tab_count(&self) -> usize {
+        self.pages.len()
+    }
+    pub fn title(&self, index: usize) -> Option<&str> {
+        self.pages.get(index).map(|page| page.title.as_str())
+    }
+    /// The wid of the Compound that was given as page.
+    pub fn page_wid(&self, index: usize) -> Option<WidgetId> {
+        self.pages.get(index).map(|page| page.content.wid())
+    }
+    pub fn index_of(&self, page_wid: WidgetId) -> Option<usize> {
+        self.pages
+            .iter()
+            .position(|page| page.content.wid() == page_wid)
+    }
+    pub fn event_tab_changed(&self) -> Arc<SelectionChangedCallback> {
+        self.event_tab_changed.callback.clone()
+    }
+    /// The callback receives the wid of the page that became active.
+    pub fn set_event_tab_changed(&mut self, callback: SelectionChangedCallback) {
+        self.event_tab_changed = SelectionChanged {
+            callback: Arc::new(callback),
+        }
+    }
+    pub fn event_tab_closed(&self) -> Arc<SelectionChangedCallback> {
+        self.event_tab_closed.callback.clone()
+    }
+    /// The callback receives the wid of the page that was closed.
+    pub fn set_event_tab_closed(&mut self, callback: SelectionChangedCallback) {
+        self.event_tab_closed = SelectionChanged {
+            callback: Arc::new(callback),
+        }
+    }
+    fn insert_page(&mut self, title: &str, closable: bool, page: Compound) -> usize {
+        self.pages.push(TabPage {
+            title: title.to_string(),
+            closable,
+            content: page,
+        });
+        self.active_changed = true;
+        self.refresh();
+        self.pages.len() - 1
+    }
+    fn refresh(&mut self) {
+        let tabs: Vec<TabViewTab> = self
+            .pages
+            .iter()
+            .enumerate()
+            .map(|(index, page)| TabViewTab {
+                title: page.title.clone(),
+                closable: page.closable,
+                active: index == self.active,
+            })
+            .collect();
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForTabView =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForTabView> =
+            binding.expect_style_for_widget_t(type_id, Self::class_name());
+        self.layout = theme.set_tabs(&tabs, None, style, self.common.prim_man());
+        self.update_size();
+        // The tabs are as wide as the whole TabView, cut when it's narrower
+        if self.size.x() != self.layout.size.x() {
+            let size_for_clipping = Vector2D::new(self.size.x(), self.layout.size.y());
+            let style: Box<ThemeStyleForTabView> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            self.layout = theme.set_tabs(
+                &tabs,
+                Some(size_for_clipping),
+                style,
+                self.common.prim_man(),
+            );
+        }
+        self.common.set_size(self.layout.size.clone());
+        self.set_pages_position();
+    }
+    fn set_pages_position(&mut self) {
+        let position = Vector2D::new(self.x(), self.y() + self.layout.size.y());
+        for page in &mut self.pages {
+            page.content.set_position(position.clone());
+        }
+    }
+    /// The tabs and the biggest page, unless a layout or the size constraints say otherwise.
+    fn update_size(&mut self) {
+        let (mut width, mut height) = (self.layout.size.x(), 0.0_f32);
+        for page in &mut self.pages {
+            let (page_width, page_height) = page.content.size().unpack();
+            width = width.max(page_width);
+            height = height.max(page_height);
+        }
+        let natural = Vector2D::new(width, self.layout.size.y() + height);
+        let size = self.common.layout_size().unwrap_or(&natural);
+        self.size = self.common.size_constraints().clamp(size);
+    }
+    fn active_page(&self) -> Option<&Compound> {
+        self.pages.get(self.active).map(|page| &page.content)
+    }
+    fn active_page_mut(&mut self) -> Option<&mut Compound> {
+        self.pages
+            .get_mut(self.active)
+            .map(|page| &mut page.content)
+    }
+    fn tab_at(&self, local_x: f32) -> Option<usize> {
+        self.layout
+            .tabs
+            .iter()
+            .position(|tab| tab.x() <= local_x && local_x < tab.right())
+    }
+    fn activate_by_user(&mut self, index: usize) -> DeferredEvents {
+        if index == self.active {
+            return vec![];
+        }
+        self.set_active(index);
+        match self.page_wid(index) {
+            Some(page_wid) => vec![self.event_tab_changed.defer(self.wid(), page_wid)],
+            None => vec![],
+        }
+    }
+    fn close_by_user(&mut self, index: usize) -> DeferredEvents {
+        let previous_active = self.page_wid(self.active);
+        let Some(closed) = self.close_tab(index) else {
+            return vec![];
+        };
+        debug!("TabView: closed tab {}", index);
+        let mut deferred_events = vec![self.event_tab_closed.defer(self.wid(), closed.wid())];
+        let active = self.page_wid(self.active);
+        if active != previous_active {
+            if let Some(active) = active {
+                deferred_events.push(self.event_tab_changed.defer(self.wid(), active));
+            }
+        }
+        deferred_events
+    }
+}
+
+impl Primitive for TabView {
+    fn class_name() -> &'static str {
+        "TabView"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position);
+        self.set_pages_position();
+    }
+    fn width(&self) -> f32 {
+        self.size.x()
+    }
+    fn height(&self) -> f32 {
+        self.size.y()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        &self.size
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        let mut geometries = vec![self.common.translate_geometry()];
+        let page_geometry = self.active_page_mut().map(|page| page.translate_geometry());
+        self.active_changed = false;
+        self.update_size();
+        if let Some(mut geometry) = page_geometry {
+            // Only cuts something when the size constraints made it smaller than the page
+            geometry.clip(&Rect::new(self.x(), self.y(), self.size.x(), self.size.y()));
+            geometries.push(geometry);
+        }
+        self.translated_geometry = Geometry::new_from_geometries(Self::class_name(), geometries);
+        self.translated_geometry.clone()
+    }
+}
+
+impl PrivatePrimitiveMethods for TabView {
+    fn update_geometry(&mut self) {
+        if self.common.needs_update() {
+            self.common.update_geometry();
+        }
+        if let Some(page) = self.active_page_mut() {
+            page.update_geometry();
+        }
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update() || self.active_page().is_some_and(|page| page.needs_update())
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update);
+        if let Some(page) = self.active_page_mut() {
+            page.set_needs_update(needs_update);
+        }
+    }
+    fn needs_translation(&self) -> bool {
+        self.active_changed
+            || self.common.needs_translation()
+            || self
+                .active_page()
+                .is_some_and(|page| page.needs_translation())
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation);
+        for page in &mut self.pages {
+            page.content.set_needs_translation(needs_translation);
+        }
+    }
+    fn clone_geometry(&self) -> Geometry {
+        let mut geometries = vec![self.common.clone_geometry()];
+        if let Some(page) = self.active_page() {
+            geometries.push(page.clone_geometry());
+        }
+        Geometry::new_from_geometries(Self::class_name(), geometries)
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+
+impl HasEvents for TabView {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for TabView {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for TabView {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let local = self.common.local(x, y);
+        if local.y() >= self.layout.size.y() {
+            return match self.active_page_mut() {
+                Some(page) => page.handle_mouse_button_down(x, y),
+                None => vec![],
+            };
+        }
+        let Some(index) = self.tab_at(local.x()) else {
+            return vec![];
+        };
+        let clicked_close = self.layout.close_buttons[index]
+            .is_some_and(|close| close.x() <= local.x() && local.x() < close.right());
+        if clicked_close {
+            return self.close_by_user(index);
+        }
+        self.dragged_tab = Some(index);
+        self.activate_by_user(index)
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let Some(dragged_tab) = self.dragged_tab else {
+            return match self.active_page_mut() {
+                Some(page) => page.handle_mouse_drag(x, y),
+                None => vec![],
+            };
+        };
+        if let Some(index) = self.tab_at(self.common.local(x, y).x()) {
+            if index != dragged_tab {
+                self.move_tab(dragged_tab, index);
+                self.dragged_tab = Some(index);
+            }
+        }
+        vec![]
+    }
+    fn handle_mouse_button_up(&mut self, x: i32, y: i32) -> DeferredEvents {
+        if self.dragged_tab.take().is_some() {
+            return vec![];
+        }
+        match self.active_page_mut() {
+            Some(page) => page.handle_mouse_button_up(x, y),
+            None => vec![],
+        }
+    }
+    fn handle_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) -> DeferredEvents {
+        match self.active_page_mut() {
+            Some(page) => page.handle_mouse_wheel(x, y, dx, dy),
+            None => vec![],
+        }
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        let ctrl = keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD);
+        let shift = keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+        let count = self.pages.len();
+        if count == 0 {
+            return vec![];
+        }
+        match key {
+            Keycode::Tab if ctrl && shift => {
+                self.activate_by_user((self.active + count - 1) % count)
+            }
+            Keycode::Tab if ctrl => self.activate_by_user((self.active + 1) % count),
+            Keycode::PageUp if ctrl && shift && self.active > 0 => {
+                self.move_tab(self.active, self.active - 1);
+                vec![]
+            }
+            Keycode::PageDown if ctrl && shift && self.active + 1 < count => {
+                self.move_tab(self.active, self.active + 1);
+                vec![]
+            }
+            _ => match self.active_page_mut() {
+                Some(page) => page.handle_key_down(key, keymod),
+                None => vec![],
+            },
+        }
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        self.active_page().is_some_and(|page| page.wants_key(key))
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        match self.active_page_mut() {
+            Some(page) => page.handle_text_input(text),
+            None => vec![],
+        }
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        match self.active_page_mut() {
+            Some(page) => page.handle_focus_changed(focused),
+            None => vec![],
+        }
+    }
+    fn as_container(&self) -> Option<&dyn Container> {
+        Some(self)
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        Some(self)
+    }
+}
+
+/// Children of every page are reachable for lookups, but only the active page gets clicks.
+impl Container for TabView {
+    fn child_wids(&self) -> Vec<WidgetId> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.content.child_wids())
+            .collect()
+    }
+    fn child(&self, wid: WidgetId) -> Option<&OwnedDynWidget> {
+        self.pages.iter().find_map(|page| page.content.child(wid))
+    }
+    fn child_that_accepts_click(&mut self, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        self.active_page_mut()?.child_that_accepts_click(x, y)
+    }
+    fn borrow_child(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+        self.pages
+            .iter_mut()
+            .find_map(|page| page.content.borrow_child(wid))
+    }
+    fn return_borrowed_widgets(&mut self) {
+        for page in &mut self.pages {
+            page.content.return_borrowed_widgets();
+        }
+    }
+}
@@ -0,0 +1,646 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, Rect, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForTextArea, ThemeStyleForTextArea,
+};
+use crate::widgets::events::{DeferredEvents, MouseButtonDownCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::{Primitive, Text};
+use crate::widgets::{
+    CommonWidget, HasTooltip, LineSplice, OwnedDynWidget, TextDocument, TextPosition, WidgetEnum,
+};
+
+use super::events::HasEvents;
+use super::Widget;
+
+const WHEEL_ROWS: f32 = 3.0;
+
+/// A visible row of text, a whole line or a part of it when it's wrapped.
+/// The key only changes when the row's text does.
+#[derive(Debug, Clone)]
+pub struct TextAreaRow {
+    pub key: usize,
+    pub text: String,
+    pub position: Vector2D<f32>,
+}
+
+/// What themes get to draw, everything is relative to the text area and already scrolled.
+#[derive(Debug, Clone)]
+pub struct TextAreaFrame {
+    pub size: Vector2D<f32>,
+    pub rows: Vec<TextAreaRow>,
+    pub selection: Vec<Rect<f32>>,
+    pub caret: Option<Rect<f32>>,
+    pub scroll_thumb: Option<Rect<f32>>,
+}
+
+/// Part of a line that fits in the width, offsets are where each char starts plus the end.
+#[derive(Debug, Clone)]
+struct WrappedRow {
+    key: usize,
+    start: usize,
+    end: usize,
+    offsets: Vec<f32>,
+}
+
+/// Multi-line text editing with soft wrapping, a vertical scroll bar, selection and undo.
+/// Ctrl+Z undoes, Ctrl+Y or Ctrl+Shift+Z redo, Ctrl+A selects everything.
+#[derive(Debug)]
+pub struct TextArea {
+    common: CommonWidget,
+    size: Vector2D<f32>,
+    document: TextDocument,
+    wraps: Vec<Option<Vec<WrappedRow>>>, // None when the line has to be wrapped again
+    wrap_width: f32,
+    next_key: usize,
+    caret: TextPosition,
+    anchor: Option<TextPosition>, // The other end of the selection
+    preferred_x: Option<f32>,     // Kept while moving up and down
+    scroll: f32,
+    scroll_to_caret: bool,
+    focused: bool,
+    dragging: bool,
+    scroll_thumb: Option<Rect<f32>>,
+    translated_geometry: Geometry,
+}
+
+impl TextArea {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        text: &str,
+        size: Vector2D<f32>,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<TextArea, Box<dyn Error>> {
+        let document = TextDocument::new(text);
+        let mut text_area = TextArea {
+            common: CommonWidget::new(
+                wid,
+                Self::class_name(),
+                size.clone(),
+                style_master,
+                PrimitivesManagerForThemes::new(),
+            ),
+            size,
+            wraps: vec![None; document.lines().len()],
+            document,
+            wrap_width: 0.0,
+            next_key: 0,
+            caret: Default::default(),
+            anchor: None,
+            preferred_x: None,
+            scroll: 0.0,
+            scroll_to_caret: false,
+            focused: false,
+            dragging: false,
+            scroll_thumb: None,
+            translated_geometry: Default::default(),
+        };
+        let type_id = TypeId::of::<Self>();
+        let binding = text_area.common.style_master();
+        let theme: &dyn ThemeForTextArea =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForTextArea> =
+            binding.expect_style_for_widget_t(type_id, Self::class_name());
+        let frame = text_area.frame(&style);
+        let size = theme.new_text_area(&frame, None, style, text_area.common.prim_man());
+        text_area.common.set_size(size);
+        Ok(text_area)
+    }
+    pub fn text(&self) -> String {
+        self.document.text()
+    }
+    /// Replaces everything, it can't be undone.
+    pub fn set_text(&mut self, text: &str) {
+        self.document = TextDocument::new(text);
+        self.wraps = vec![None; self.document.lines().len()];
+        self.caret = Default::default();
+        self.anchor = None;
+        self.scroll = 0.0;
+        self.refresh();
+    }
+    pub fn document(&self) -> &TextDocument {
+        &self.document
+    }
+    pub fn caret(&self) -> TextPosition {
+        self.caret
+    }
+    pub fn set_caret(&mut self, caret: TextPosition) {
+        self.move_caret(caret, false);
+        self.refresh();
+    }
+    /// Ordered, None when nothing is selected.
+    pub fn selection(&self) -> Option<(TextPosition, TextPosition)> {
+        let anchor = self.anchor.filter(|anchor| *anchor != self.caret)?;
+        Some((anchor.min(self.caret), anchor.max(self.caret)))
+    }
+    pub fn selected_text(&self) -> String {
+        self.selection()
+            .map(|(start, end)| self.document.slice(start, end))
+            .unwrap_or_default()
+    }
+    pub fn select_all(&mut self) {
+        self.anchor = Some(TextPosition::default());
+        self.caret = self.document.end();
+        self.refresh();
+    }
+    pub fn undo(&mut self) {
+        if let Some((caret, splice)) = self.document.undo() {
+            self.after_edit(caret, splice);
+        }
+    }
+    pub fn redo(&mut self) {
+        if let Some((caret, splice)) = self.document.redo() {
+            self.after_edit(caret, splice);
+        }
+    }
+    /// Replaces the selection, or inserts at the caret.
+    pub fn insert(&mut self, text: &str) {
+        self.replace_selection(text, false);
+    }
+    fn replace_selection(&mut self, text: &str, typing: bool) {
+        let (start, end) = self.selection().unwrap_or((self.caret, self.caret));
+        let (caret, splice) = self.document.replace(start, end, text, typing);
+        self.after_edit(caret, splice);
+    }
+    fn after_edit(&mut self, caret: TextPosition, splice: LineSplice) {
+        let range = splice.start..splice.start + splice.removed;
+        self.wraps.splice(range, (0..splice.inserted).map(|_| None));
+        self.anchor = None;
+        self.preferred_x = None;
+        self.caret = caret;
+        self.scroll_to_caret = true;
+        self.refresh();
+    }
+    /// With extend the selection goes from where the caret was.
+    fn move_caret(&mut self, caret: TextPosition, extend: bool) {
+        if extend {
+            self.anchor.get_or_insert(self.caret);
+        } else {
+            self.anchor = None;
+        }
+        self.caret = self.document.clamp(caret);
+        self.scroll_to_caret = true;
+    }
+    fn line_height(&self, style: &ThemeStyleForTextArea) -> f32 {
+        Text::line_height(style.font_size, style.font.clone())
+    }
+    /// Wraps the lines that changed, and all of them if the width did.
+    fn wrap(&mut self, style: &ThemeStyleForTextArea) {
+        let wrap_width = self.content_size(style).x() - style.spacing * 2.0 - style.thickness;
+        if wrap_width != self.wrap_width {
+            self.wrap_width = wrap_width;
+            self.wraps.iter_mut().for_each(|wrap| *wrap = None);
+        }
+        for index in 0..self.wraps.len() {
+            if self.wraps[index].is_some() {
+                continue;
+            }
+            let line = &self.document.lines()[index];
+            let widths = Text::char_widths(line, style.font_size, style.font.clone());
+            let ranges = Text::wrap(line, &widths, wrap_width);
+            let rows = ranges
+                .into_iter()
+                .map(|range| {
+                    let mut offsets = vec![0.0];
+                    for width in &widths[range.clone()] {
+                        offsets.push(offsets.last().copied().unwrap_or_default() + width);
+                    }
+                    self.next_key += 1;
+                    WrappedRow {
+                        key: self.next_key,
+                        start: range.start,
+                        end: range.end,
+                        offsets,
+                    }
+                })
+                .collect();
+            self.wraps[index] = Some(rows);
+        }
+    }
+    fn rows_of(&self, line: usize) -> &[WrappedRow] {
+        self.wraps[line]
+            .as_deref()
+            .expect("text_area:TextArea:rows_of line not wrapped")
+    }
+    /// Index of the visual row among all of them, and the row itself.
+    fn visual_row(&self, position: TextPosition) -> (usize, &WrappedRow) {
+        let before: usize = (0..position.line)
+            .map(|line| self.rows_of(line).len())
+            .sum();
+        let rows = self.rows_of(position.line);
+        // A col at the end of a wrapped row is shown at the start of the next one
+        let index = rows
+            .iter()
+            .position(|row| position.col < row.end)
+            .unwrap_or(rows.len() - 1);
+        (before + index, &rows[index])
+    }
+    /// The line and the row for a visual row index, clamped to the last one.
+    fn row_at(&self, visual_row: usize) -> (usize, &WrappedRow) {
+        let mut remaining = visual_row;
+        for line in 0..self.wraps.len() {
+            let rows = self.rows_of(line);
+            if remaining < rows.len() {
+                return (line, &rows[remaining]);
+            }
+            remaining -= rows.len();
+        }
+        let last = self.wraps.len() - 1;
+        let rows = self.rows_of(last);
+        (last, &rows[rows.len() - 1])
+    }
+    fn row_count(&self) -> usize {
+        (0..self.wraps.len())
+            .map(|line| self.rows_of(line).len())
+            .sum()
+    }
+    fn caret_x(&self) -> f32 {
+        let (_, row) = self.visual_row(self.caret);
+        row.offsets[self.caret.col.clamp(row.start, row.end) - row.start]
+    }
+    /// The nearest place to x in a visual row.
+    fn position_in_row(&self, visual_row: usize, x: f32) -> TextPosition {
+        let (line, row) = self.row_at(visual_row);
+        let last_row = row.end == self.document.line_len(line);
+        let col = row
+            .offsets
+            .windows(2)
+            .position(|pair| x < (pair[0] + pair[1]) / 2.0)
+            .map_or(row.end, |index| row.start + index);
+        // Past the end of a wrapped row would be the start of the next one
+        let col = if !last_row && col == row.end && row.end > row.start {
+            row.end - 1
+        } else {
+            col
+        };
+        TextPosition::new(line, col)
+    }
+    /// From window coordinates.
+    fn position_at(&mut self, x: i32, y: i32) -> TextPosition {
+        let style = self.style();
+        let line_height = self.line_height(&style);
+        let local = self.common.local(x, y);
+        let local_y = local.y() - style.spacing + self.scroll;
+        let visual_row = (local_y / line_height).max(0.0) as usize;
+        self.position_in_row(visual_row, local.x() - style.spacing)
+    }
+    fn move_vertically(&mut self, rows: isize, extend: bool) {
+        let x = *self.preferred_x.get_or_insert(self.caret_x());
+        let (visual_row, _) = self.visual_row(self.caret);
+        let target = visual_row as isize + rows;
+        let caret = if target < 0 {
+            TextPosition::default()
+        } else if target as usize >= self.row_count() {
+            self.document.end()
+        } else {
+            self.position_in_row(target as usize, x)
+        };
+        self.move_caret(caret, extend);
+        self.preferred_x = Some(x);
+    }
+    fn visible_rows(&self) -> isize {
+        let style = self.style();
+        let height = self.content_size(&style).y();
+        ((height - style.spacing * 2.0) / self.line_height(&style)).max(1.0) as isize
+    }
+    fn style(&self) -> Box<ThemeStyleForTextArea> {
+        self.common
+            .style_master()
+            .expect_style_for_widget_t(TypeId::of::<Self>(), Self::class_name())
+    }
+    fn frame(&mut self, style: &ThemeStyleForTextArea) -> TextAreaFrame {
+        self.wrap(style);
+        let spacing = style.spacing;
+        let line_height = self.line_height(style);
+        let size = self.content_size(style);
+        let view_height = size.y() - spacing * 2.0;
+        let content_height = self.row_count() as f32 * line_height;
+        let (caret_row, _) = self.visual_row(self.caret);
+        if std::mem::take(&mut self.scroll_to_caret) {
+            let caret_y = caret_row as f32 * line_height;
+            if caret_y < self.scroll {
+                self.scroll = caret_y;
+            } else if caret_y + line_height > self.scroll + view_height {
+                self.scroll = caret_y + line_height - view_height;
+            }
+        }
+        self.scroll = self
+            .scroll
+            .clamp(0.0, (content_height - view_height).max(0.0));
+
+        let first = (self.scroll / line_height) as usize;
+        let last = ((self.scroll + view_height) / line_height).ceil() as usize;
+        let selection = self.selection();
+        let mut frame = TextAreaFrame {
+            size: size.clone(),
+            rows: vec![],
+            selection: vec![],
+            caret: None,
+            scroll_thumb: None,
+        };
+        let mut visual_row = 0;
+        for line in 0..self.wraps.len() {
+            for row in self.rows_of(line) {
+                if visual_row >= first && visual_row <= last {
+                    let y = spacing + visual_row as f32 * line_height - self.scroll;
+                    let text: String = self.document.lines()[line]
+                        .chars()
+                        .skip(row.start)
+                        .take(row.end - row.start)
+                        .collect();
+                    frame.rows.push(TextAreaRow {
+                        key: row.key,
+                        text,
+                        position: Vector2D::new(spacing, y),
+                    });
+                    if let Some((start, end)) = selection {
+                        let row_start = TextPosition::new(line, row.start);
+                        let row_end = TextPosition::new(line, row.end);
+                        if start <= row_end && end >= row_start {
+                            let from = if start > row_start {
+                                start.col
+                            } else {
+                                row.start
+                            };
+                            let to = if end < row_end { end.col } else { row.end };
+                            let x = row.offsets[from - row.start];
+                            // The line break is selected too, shown as a bit of space
+                            let line_break =
+                                end > row_end && row.end == self.document.line_len(line);
+                            let width = row.offsets[to - row.start] - x
+                                + if line_break { line_height / 3.0 } else { 0.0 };
+                            frame
+                                .selection
+                                .push(Rect::new(spacing + x, y, width, line_height));
+                        }
+                    }
+                }
+                visual_row += 1;
+            }
+        }
+        if self.focused {
+            let y = spacing + caret_row as f32 * line_height - self.scroll;
+            frame.caret = Some(Rect::new(spacing + self.caret_x(), y, 2.0, line_height));
+        }
+        if content_height > view_height {
+            let track = size.y();
+            let thumb_height = (track * view_height / content_height).max(style.thickness * 2.0);
+            let thumb_y = (track - thumb_height) * self.scroll / (content_height - view_height);
+            frame.scroll_thumb = Some(Rect::new(
+                size.x() - style.thickness,
+                thumb_y,
+                style.thickness,
+                thumb_height,
+            ));
+        }
+        self.scroll_thumb = frame.scroll_thumb;
+        frame
+    }
+    fn refresh(&mut self) {
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForTextArea =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForTextArea> =
+            binding.expect_style_for_widget_t(type_id, Self::class_name());
+        let frame = self.frame(&style);
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForTextArea> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            theme.set_text_area(&frame, size_for_clipping, style, prim_man)
+        });
+    }
+    /// area_size() inside the border and padding, where the text is.
+    fn content_size(&self, style: &ThemeStyleForTextArea) -> Vector2D<f32> {
+        style.box_model.content_size(&self.area_size())
+    }
+    /// Its own size, unless a layout stretches it or its size constraints don't let it.
+    fn area_size(&self) -> Vector2D<f32> {
+        let size = self.common.layout_size().unwrap_or(&self.size);
+        self.common.size_constraints().clamp(size)
+    }
+}
+
+impl Primitive for TextArea {
+    fn class_name() -> &'static str {
+        "TextArea"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+    fn translate_geometry(&mut self) -> Geometry {
+        // Rows scrolled half out are cut at the edges
+        let mut geometry = self.common.translate_geometry();
+        geometry.clip(&self.get_rect());
+        self.translated_geometry = geometry.clone();
+        geometry
+    }
+}
+
+impl PrivatePrimitiveMethods for TextArea {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.translated_geometry = translated_geometry
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.translated_geometry.clone()
+    }
+}
+
+impl HasEvents for TextArea {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for TextArea {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for TextArea {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let style = self.style();
+        let local = self.common.local(x, y);
+        let on_scroll_bar = local.x() >= self.content_size(&style).x() - style.thickness;
+        if let Some(thumb) = self.scroll_thumb.filter(|_| on_scroll_bar) {
+            // Pages towards where the track was clicked
+            let page = self.visible_rows() as f32 * self.line_height(&style);
+            if local.y() < thumb.y() + thumb.height() / 2.0 {
+                self.scroll -= page;
+            } else {
+                self.scroll += page;
+            }
+        } else {
+            let caret = self.position_at(x, y);
+            self.move_caret(caret, false);
+            self.anchor = Some(caret);
+            self.preferred_x = None;
+            self.dragging = true;
+        }
+        self.refresh();
+        vec![]
+    }
+    fn handle_mouse_drag(&mut self, x: i32, y: i32) -> DeferredEvents {
+        if self.dragging {
+            let caret = self.position_at(x, y);
+            self.move_caret(caret, true);
+            self.refresh();
+        }
+        vec![]
+    }
+    fn handle_mouse_button_up(&mut self, _x: i32, _y: i32) -> DeferredEvents {
+        self.dragging = false;
+        vec![]
+    }
+    fn handle_mouse_wheel(&mut self, _x: i32, _y: i32, _dx: i32, dy: i32) -> DeferredEvents {
+        let line_height = self.line_height(&self.style());
+        // Away from the user goes up, towards the first line
+        self.scroll -= dy as f32 * WHEEL_ROWS * line_height;
+        self.refresh();
+        vec![]
+    }
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        matches!(key, Keycode::Return | Keycode::KpEnter)
+    }
+    fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> DeferredEvents {
+        let shift = keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+        let ctrl = keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD);
+        let caret = self.caret;
+        match key {
+            Keycode::Z if ctrl && shift => self.redo(),
+            Keycode::Z if ctrl => self.undo(),
+            Keycode::Y if ctrl => self.redo(),
+            Keycode::A if ctrl => self.select_all(),
+            Keycode::Return | Keycode::KpEnter => self.replace_selection("\n", false),
+            Keycode::Backspace | Keycode::Delete => {
+                if self.selection().is_none() {
+                    let other = self.document.step(caret, key == Keycode::Delete);
+                    if other == caret {
+                        return vec![];
+                    }
+                    self.anchor = Some(other);
+                }
+                self.replace_selection("", false);
+            }
+            Keycode::Up | Keycode::Down | Keycode::PageUp | Keycode::PageDown => {
+                let rows = match key {
+                    Keycode::Up => -1,
+                    Keycode::Down => 1,
+                    Keycode::PageUp => -self.visible_rows(),
+                    _ => self.visible_rows(),
+                };
+                self.move_vertically(rows, shift);
+                self.refresh();
+            }
+            Keycode::Left | Keycode::Right | Keycode::Home | Keycode::End => {
+                let forward = key == Keycode::Right || key == Keycode::End;
+                let target = match (key, self.selection()) {
+                    // Without shift the selection collapses to the side of the arrow
+                    (Keycode::Left, Some((start, _))) if !shift => start,
+                    (Keycode::Right, Some((_, end))) if !shift => end,
+                    (Keycode::Left | Keycode::Right, _) => self.document.step(caret, forward),
+                    (_, _) if ctrl && forward => self.document.end(),
+                    (_, _) if ctrl => TextPosition::default(),
+                    (_, _) if forward => {
+                        TextPosition::new(caret.line, self.document.line_len(caret.line))
+                    }
+                    (_, _) => TextPosition::new(caret.line, 0),
+                };
+                self.move_caret(target, shift);
+                self.preferred_x = None;
+                self.refresh();
+            }
+            _ => {}
+        }
+        vec![]
+    }
+    fn handle_text_input(&mut self, text: &str) -> DeferredEvents {
+        self.replace_selection(text, true);
+        vec![]
+    }
+    fn handle_focus_changed(&mut self, focused: bool) -> DeferredEvents {
+        self.focused = focused;
+        self.dragging = false;
+        self.refresh();
+        vec![]
+    }
+}
@@ -3,14 +3,14 @@ use std::error::Error;
 use std::fmt::Debug;
 use std::sync::Arc;
 
-use crate::general::{Geometry, Vector2D};
+use crate::general::{Geometry, SizeConstraints, Vector2D};
 use crate::themes::{
     PrimitivesManagerForThemes, StyleMaster, ThemeForTextBox, ThemeStyleForTextBox,
 };
 use crate::widgets::events::MouseButtonDownCallback;
 use crate::widgets::primitives::private::PrivatePrimitiveMethods;
 use crate::widgets::primitives::Primitive;
-use crate::widgets::{CommonWidget, WidgetEnum};
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
 
 use super::events::HasEvents;
 use super::Widget;
@@ -18,6 +18,7 @@ use super::Widget;
 #[derive(Debug)]
 pub struct TextBox {
     common: CommonWidget,
+    text: String,
 }
 
 impl TextBox {
@@ -37,17 +38,31 @@ impl TextBox {
             CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man);
         Ok(TextBox {
             common: common_widget,
+            text: text.to_string(),
         })
     }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
     pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.refresh();
+    }
+    /// With size constraints or a size from a layout what doesn't fit is clipped.
+    fn refresh(&mut self) {
         let binding = self.common.style_master();
         let type_id = TypeId::of::<Self>();
         let theme: &dyn ThemeForTextBox = binding.expect_theme_for_widget_t(type_id, Self::class_name());
-        let style: Box<ThemeStyleForTextBox> =
-            binding.expect_style_for_widget_t(type_id, Self::class_name());
-        let size = theme.set_text(text, None, style, self.common.prim_man());
+        let constraints = self.common.size_constraints();
+        let layout_size = self.common.layout_size().cloned();
+        let text = &self.text;
+        let prim_man = self.common.prim_man();
+        let size = constraints.fit(layout_size.as_ref(), |size_for_clipping| {
+            let style: Box<ThemeStyleForTextBox> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            theme.set_text(text, size_for_clipping, style, prim_man)
+        });
         self.common.set_size(size);
-        // self.set_needs_update(true);
     }
 }
 
@@ -130,4 +145,25 @@ impl HasEvents for TextBox {
     }
 }
 
-impl Widget for TextBox {}
+impl HasTooltip for TextBox {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for TextBox {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+}
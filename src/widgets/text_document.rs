@@ -0,0 +1,309 @@
+/// A place in a TextDocument, col is in chars, not bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct TextPosition {
+    pub line: usize,
+    pub col: usize,
+}
+
+impl TextPosition {
+    pub fn new(line: usize, col: usize) -> TextPosition {
+        TextPosition { line, col }
+    }
+}
+
+/// Which lines a replace() changed: `removed` lines from `start` became `inserted` lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineSplice {
+    pub start: usize,
+    pub removed: usize,
+    pub inserted: usize,
+}
+
+#[derive(Debug, Clone)]
+struct TextEdit {
+    start: TextPosition,
+    removed: String,
+    inserted: String,
+    typing: bool, // Consecutive typing is undone at once
+}
+
+/// Lines of text with undo and redo, for widgets that edit more than one line.
+/// It never has zero lines, an empty document is one empty line.
+#[derive(Debug, Clone)]
+pub struct TextDocument {
+    lines: Vec<String>,
+    undo: Vec<TextEdit>,
+    redo: Vec<TextEdit>,
+}
+
+impl TextDocument {
+    pub fn new(text: &str) -> TextDocument {
+        TextDocument {
+            lines: text.split('\n').map(str::to_string).collect(),
+            undo: vec![],
+            redo: vec![],
+        }
+    }
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+    pub fn line_len(&self, line: usize) -> usize {
+        self.lines[line].chars().count()
+    }
+    pub fn end(&self) -> TextPosition {
+        let line = self.lines.len() - 1;
+        TextPosition::new(line, self.line_len(line))
+    }
+    /// Keeps it inside the document.
+    pub fn clamp(&self, position: TextPosition) -> TextPosition {
+        let line = position.line.min(self.lines.len() - 1);
+        TextPosition::new(line, position.col.min(self.line_len(line)))
+    }
+    /// One char back or forward, across lines.
+    pub fn step(&self, position: TextPosition, forward: bool) -> TextPosition {
+        let position = self.clamp(position);
+        match forward {
+            true if position.col < self.line_len(position.line) => {
+                TextPosition::new(position.line, position.col + 1)
+            }
+            true if position.line + 1 < self.lines.len() => TextPosition::new(position.line + 1, 0),
+            false if position.col > 0 => TextPosition::new(position.line, position.col - 1),
+            false if position.line > 0 => {
+                TextPosition::new(position.line - 1, self.line_len(position.line - 1))
+            }
+            _ => position,
+        }
+    }
+    pub fn slice(&self, start: TextPosition, end: TextPosition) -> String {
+        let (start, end) = (self.clamp(start.min(end)), self.clamp(start.max(end)));
+        if start.line == end.line {
+            return Self::chars(&self.lines[start.line], start.col, end.col).to_string();
+        }
+        let mut text = Self::chars(&self.lines[start.line], start.col, usize::MAX).to_string();
+        for line in &self.lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(Self::chars(&self.lines[end.line], 0, end.col));
+        text
+    }
+    /// Replaces what is between both positions, in any order, and can be undone.
+    /// typing merges it with the previous edit if that was typing right before it.
+    pub fn replace(
+        &mut self,
+        start: TextPosition,
+        end: TextPosition,
+        text: &str,
+        typing: bool,
+    ) -> (TextPosition, LineSplice) {
+        let (start, end) = (self.clamp(start.min(end)), self.clamp(start.max(end)));
+        let removed = self.slice(start, end);
+        let (caret, splice) = self.apply(start, end, text);
+        self.redo.clear();
+        let merged = match self.undo.last_mut() {
+            Some(last)
+                if typing
+                    && last.typing
+                    && removed.is_empty()
+                    && !text.contains('\n')
+                    && Self::end_of(last.start, &last.inserted) == start =>
+            {
+                last.inserted.push_str(text);
+                true
+            }
+            _ => false,
+        };
+        if !merged {
+            self.undo.push(TextEdit {
+                start,
+                removed,
+                inserted: text.to_string(),
+                typing,
+            });
+        }
+        (caret, splice)
+    }
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+    /// Gives where the caret should go and what changed, None if there is nothing to undo.
+    pub fn undo(&mut self) -> Option<(TextPosition, LineSplice)> {
+        let edit = self.undo.pop()?;
+        let end = Self::end_of(edit.start, &edit.inserted);
+        let result = self.apply(edit.start, end, &edit.removed);
+        self.redo.push(edit);
+        Some(result)
+    }
+    pub fn redo(&mut self) -> Option<(TextPosition, LineSplice)> {
+        let edit = self.redo.pop()?;
+        let end = Self::end_of(edit.start, &edit.removed);
+        let result = self.apply(edit.start, end, &edit.inserted);
+        // Redone typing isn't merged with what is typed after it
+        self.undo.push(TextEdit {
+            typing: false,
+            ..edit
+        });
+        Some(result)
+    }
+    fn apply(
+        &mut self,
+        start: TextPosition,
+        end: TextPosition,
+        text: &str,
+    ) -> (TextPosition, LineSplice) {
+        let before = Self::chars(&self.lines[start.line], 0, start.col).to_string();
+        let after = Self::chars(&self.lines[end.line], end.col, usize::MAX).to_string();
+        let mut new_lines: Vec<String> = text.split('\n').map(str::to_string).collect();
+        let last = new_lines.len() - 1;
+        let caret = TextPosition::new(
+            start.line + last,
+            new_lines[last].chars().count() + if last == 0 { start.col } else { 0 },
+        );
+        new_lines[0].insert_str(0, &before);
+        new_lines[last].push_str(&after);
+        let splice = LineSplice {
+            start: start.line,
+            removed: end.line - start.line + 1,
+            inserted: new_lines.len(),
+        };
+        self.lines.splice(start.line..=end.line, new_lines);
+        (caret, splice)
+    }
+    /// Where the caret ends after inserting text at start.
+    fn end_of(start: TextPosition, text: &str) -> TextPosition {
+        match text.rsplit_once('\n') {
+            Some((before, last)) => TextPosition::new(
+                start.line + before.matches('\n').count() + 1,
+                last.chars().count(),
+            ),
+            None => TextPosition::new(start.line, start.col + text.chars().count()),
+        }
+    }
+    /// The chars from start to end, end can be past the last one.
+    fn chars(line: &str, start: usize, end: usize) -> &str {
+        let byte_index = |col: usize| {
+            line.char_indices()
+                .nth(col)
+                .map_or(line.len(), |(index, _)| index)
+        };
+        &line[byte_index(start)..byte_index(end.max(start))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(line: usize, col: usize) -> TextPosition {
+        TextPosition::new(line, col)
+    }
+
+    fn type_text(document: &mut TextDocument, position: TextPosition, text: &str) -> TextPosition {
+        document.replace(position, position, text, true).0
+    }
+
+    #[test]
+    fn never_has_zero_lines() {
+        let document = TextDocument::new("");
+        assert_eq!(document.lines().len(), 1);
+        assert_eq!(document.end(), at(0, 0));
+        assert_eq!(TextDocument::new("a\n\nb").lines().len(), 3);
+        assert_eq!(TextDocument::new("a\n\nb").text(), "a\n\nb");
+    }
+
+    #[test]
+    fn cols_are_in_chars() {
+        let mut document = TextDocument::new("héllo\nwörld");
+        assert_eq!(document.line_len(0), 5);
+        assert_eq!(document.end(), at(1, 5));
+        assert_eq!(document.clamp(at(7, 9)), at(1, 5));
+        assert_eq!(document.slice(at(0, 1), at(0, 2)), "é");
+        assert_eq!(document.step(at(0, 5), true), at(1, 0));
+        assert_eq!(document.step(at(1, 0), false), at(0, 5));
+        assert_eq!(document.step(at(1, 5), true), at(1, 5));
+        document.replace(at(1, 1), at(1, 2), "o", false);
+        assert_eq!(document.text(), "héllo\nworld");
+    }
+
+    #[test]
+    fn selection_across_lines() {
+        let mut document = TextDocument::new("héllo\nwörld\n!");
+        assert_eq!(document.slice(at(1, 2), at(0, 3)), "lo\nwö");
+        assert_eq!(document.slice(at(0, 4), at(2, 1)), "o\nwörld\n!");
+        // In any order
+        let (caret, splice) = document.replace(at(1, 2), at(0, 3), "X\nY", false);
+        assert_eq!(document.text(), "hélX\nYrld\n!");
+        assert_eq!(caret, at(1, 1));
+        assert_eq!(
+            splice,
+            LineSplice {
+                start: 0,
+                removed: 2,
+                inserted: 2
+            }
+        );
+        let (caret, _) = document.undo().expect("text_document:tests undo");
+        assert_eq!(document.text(), "héllo\nwörld\n!");
+        assert_eq!(caret, at(1, 2));
+    }
+
+    #[test]
+    fn typing_is_undone_at_once() {
+        let mut document = TextDocument::new("ab");
+        let caret = type_text(&mut document, at(0, 2), "c");
+        let caret = type_text(&mut document, caret, "d");
+        assert_eq!(caret, at(0, 4));
+        assert_eq!(document.text(), "abcd");
+        document.undo();
+        assert_eq!(document.text(), "ab");
+        assert!(!document.can_undo());
+
+        // Not merged when it's somewhere else or not typing
+        type_text(&mut document, at(0, 2), "c");
+        type_text(&mut document, at(0, 0), "x");
+        document.replace(at(0, 4), at(0, 4), "y", false);
+        document.undo();
+        assert_eq!(document.text(), "xabc");
+        document.undo();
+        assert_eq!(document.text(), "abc");
+    }
+
+    #[test]
+    fn a_new_line_starts_a_new_group() {
+        let mut document = TextDocument::new("ab");
+        let caret = type_text(&mut document, at(0, 2), "c");
+        let caret = type_text(&mut document, caret, "\n");
+        type_text(&mut document, caret, "d");
+        assert_eq!(document.text(), "abc\nd");
+        document.undo();
+        assert_eq!(document.text(), "abc");
+        document.undo();
+        assert_eq!(document.text(), "ab");
+    }
+
+    #[test]
+    fn redo() {
+        let mut document = TextDocument::new("ab");
+        assert!(document.redo().is_none());
+        type_text(&mut document, at(0, 2), "cd");
+        document.undo();
+        assert!(document.can_redo());
+        let (caret, _) = document.redo().expect("text_document:tests redo");
+        assert_eq!((document.text().as_str(), caret), ("abcd", at(0, 4)));
+        // What's typed after a redo is undone on its own
+        type_text(&mut document, caret, "e");
+        document.undo();
+        assert_eq!(document.text(), "abcd");
+        // A new edit forgets what could be redone
+        document.replace(at(0, 0), at(0, 1), "", false);
+        assert!(!document.can_redo());
+        assert_eq!(document.text(), "bcd");
+    }
+}
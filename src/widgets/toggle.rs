@@ -0,0 +1,236 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, SizeConstraints, Vector2D};
+use crate::themes::{PrimitivesManagerForThemes, StyleMaster, ThemeForToggle, ThemeStyleForToggle};
+use crate::widgets::events::{DeferredEvents, MouseButtonDownCallback, Toggled, ToggledCallback};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+// How long the thumb takes to go from one side to the other
+const SLIDE: Duration = Duration::from_millis(150);
+
+/// On/off switch, with an optional text after it. The thumb slides when the user switches it.
+#[derive(Debug)]
+pub struct Toggle {
+    common: CommonWidget,
+    text: String,
+    on: bool,
+    // Where the thumb started sliding from, and when
+    slide: Option<(f32, Instant)>,
+    event_toggled: Toggled,
+}
+
+impl Toggle {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        text: &str,
+        on: bool,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<Toggle, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForToggle =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForToggle> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let position = if on { 1.0 } else { 0.0 };
+        let size = theme.new_toggle(text, position, None, style, &mut prim_man);
+        Ok(Toggle {
+            common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            text: text.to_string(),
+            on,
+            slide: None,
+            event_toggled: Default::default(),
+        })
+    }
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+    /// Doesn't call the toggled callback and the thumb jumps instead of sliding.
+    pub fn set_on(&mut self, on: bool) {
+        self.on = on;
+        self.slide = None;
+        self.refresh();
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.refresh();
+    }
+    pub fn event_toggled(&self) -> Arc<ToggledCallback> {
+        self.event_toggled.callback.clone()
+    }
+    pub fn set_event_toggled(&mut self, callback: ToggledCallback) {
+        self.event_toggled = Toggled {
+            callback: Arc::new(callback),
+        }
+    }
+    fn switch(&mut self) -> DeferredEvents {
+        // Turning around halfway starts from where the thumb is
+        self.slide = Some((self.thumb_position(), Instant::now()));
+        self.on = !self.on;
+        vec![self.event_toggled.defer(self.wid(), self.on)]
+    }
+    /// From 0 when off to 1 when on.
+    fn thumb_position(&self) -> f32 {
+        let target = if self.on { 1.0 } else { 0.0 };
+        match self.slide {
+            Some((from, since)) => {
+                let progress = (since.elapsed().as_secs_f32() / SLIDE.as_secs_f32()).min(1.0);
+                from + (target - from) * progress
+            }
+            None => target,
+        }
+    }
+    fn refresh(&mut self) {
+        let position = self.thumb_position();
+        if self
+            .slide
+            .is_some_and(|(_, since)| since.elapsed() >= SLIDE)
+        {
+            self.slide = None;
+        }
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForToggle =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        let text = &self.text;
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForToggle> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            theme.set_toggle(text, position, size_for_clipping, style, prim_man)
+        });
+    }
+}
+
+impl Primitive for Toggle {
+    fn class_name() -> &'static str {
+        "Toggle"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for Toggle {
+    fn update_geometry(&mut self) {
+        if self.slide.is_some() {
+            self.refresh();
+        }
+        self.common.update_geometry()
+    }
+    /// Every frame while the thumb slides.
+    fn needs_update(&self) -> bool {
+        self.common.needs_update() || self.slide.is_some()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+impl HasEvents for Toggle {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for Toggle {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for Toggle {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, _x: i32, _y: i32) -> DeferredEvents {
+        self.switch()
+    }
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+    fn wants_key(&self, key: Keycode) -> bool {
+        matches!(key, Keycode::Return | Keycode::KpEnter)
+    }
+    fn handle_key_down(&mut self, key: Keycode, _keymod: Mod) -> DeferredEvents {
+        match key {
+            Keycode::Space | Keycode::Return | Keycode::KpEnter => self.switch(),
+            _ => vec![],
+        }
+    }
+}
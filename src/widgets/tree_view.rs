@@ -0,0 +1,469 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use log::{debug, info};
+use sdl2::keyboard::{Keycode, Mod};
+
+use crate::general::{Geometry, SizeConstraints, Vector2D};
+use crate::themes::{
+    PrimitivesManagerForThemes, StyleMaster, ThemeForTreeView, ThemeStyleForTreeView,
+};
+use crate::widgets::events::{
+    DeferredEvents, MouseButtonDownCallback, SelectionChanged, SelectionChangedCallback,
+};
+use crate::widgets::primitives::private::PrivatePrimitiveMethods;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{CommonWidget, HasTooltip, OwnedDynWidget, WidgetEnum};
+
+use super::events::HasEvents;
+use super::Widget;
+
+pub type TreeNodeId = usize;
+/// Receives the key of the node being expanded and returns its children.
+pub type TreeLoadChildrenCallback = fn(key: &str) -> Vec<TreeNode>;
+
+/// Declarative description of a node, TreeView turns it into its own internal nodes.
+#[derive(Debug, Clone, Default)]
+pub struct TreeNode {
+    label: String,
+    key: String,
+    children: Vec<TreeNode>,
+    lazy: bool,
+}
+
+impl TreeNode {
+    /// The key defaults to the label.
+    pub fn new(label: &str) -> TreeNode {
+        TreeNode {
+            label: label.to_string(),
+            key: label.to_string(),
+            ..Default::default()
+        }
+    }
+    pub fn with_key(mut self, key: &str) -> TreeNode {
+        self.key = key.to_string();
+        self
+    }
+    pub fn with_children(mut self, children: Vec<TreeNode>) -> TreeNode {
+        self.children = children;
+        self
+    }
+    /// Children will be asked to the TreeLoadChildrenCallback the first time the node is expanded.
+    pub fn with_lazy_children(mut self) -> TreeNode {
+        self.lazy = true;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TreeViewExpander {
+    None,
+    Collapsed,
+    Expanded,
+}
+
+/// A visible row, it's what themes get to draw.
+#[derive(Debug, Clone)]
+pub struct TreeViewRow {
+    pub node: TreeNodeId,
+    pub label: String,
+    pub depth: usize,
+    pub expander: TreeViewExpander,
+    pub selected: bool,
+}
+
+#[derive(Debug)]
+struct TreeItem {
+    label: String,
+    key: String,
+    parent: Option<TreeNodeId>,
+    children: Vec<TreeNodeId>,
+    expanded: bool,
+    pending_load: bool,
+}
+
+#[derive(Debug)]
+pub struct TreeView {
+    common: CommonWidget,
+    items: Vec<TreeItem>,
+    roots: Vec<TreeNodeId>,
+    rows: Vec<TreeViewRow>,
+    selected: Option<TreeNodeId>,
+    row_height: f32,
+    indent: f32,
+    load_children: Option<TreeLoadChildrenCallback>,
+    event_selection_changed: SelectionChanged,
+}
+
+impl TreeView {
+    pub fn new<WENUM: WidgetEnum>(
+        wid: WENUM,
+        nodes: Vec<TreeNode>,
+        style_master: Arc<StyleMaster>,
+    ) -> Result<TreeView, Box<dyn Error>> {
+        let type_id = TypeId::of::<Self>();
+        let theme: &dyn ThemeForTreeView =
+            style_master.expect_theme_for_widget_t(type_id, Self::class_name());
+        let style: Box<ThemeStyleForTreeView> =
+            style_master.expect_style_for_widget_t(type_id, Self::class_name());
+        let mut items = vec![];
+        let roots: Vec<TreeNodeId> = nodes
+            .into_iter()
+            .map(|node| Self::insert_node(&mut items, None, node))
+            .collect();
+        let row_height = style.row_height;
+        let indent = style.indent;
+        let rows = Self::visible_rows(&items, &roots, None);
+        let mut prim_man = PrimitivesManagerForThemes::new();
+        let size = theme.new_tree_view(&rows, None, style, &mut prim_man);
+        Ok(TreeView {
+            common: CommonWidget::new(wid, Self::class_name(), size, style_master, prim_man),
+            items,
+            roots,
+            rows,
+            selected: None,
+            row_height,
+            indent,
+            load_children: None,
+            event_selection_changed: Default::default(),
+        })
+    }
+    /// Adds a node, with all of its children, at the end of `parent` or as a root if None.
+    pub fn add_node(&mut self, parent: Option<TreeNodeId>, node: TreeNode) -> TreeNodeId {
+        let id = Self::insert_node(&mut self.items, parent, node);
+        match parent {
+            Some(parent) => {
+                let item = &mut self.items[parent];
+                item.children.push(id);
+                item.pending_load = false;
+            }
+            None => self.roots.push(id),
+        }
+        self.refresh();
+        id
+    }
+    pub fn set_load_children(&mut self, load_children: TreeLoadChildrenCallback) {
+        self.load_children = Some(load_children);
+    }
+    pub fn expand(&mut self, node: TreeNodeId) {
+        if self.items[node].pending_load {
+            self.load_children_of(node);
+        }
+        self.items[node].expanded = true;
+        self.refresh();
+    }
+    pub fn collapse(&mut self, node: TreeNodeId) {
+        self.items[node].expanded = false;
+        self.refresh();
+    }
+    pub fn is_expanded(&self, node: TreeNodeId) -> bool {
+        self.items[node].expanded
+    }
+    /// Selects without triggering the selection changed event.
+    pub fn select(&mut self, node: Option<TreeNodeId>) {
+        self.selected = node;
+        self.refresh();
+    }
+    pub fn selected(&self) -> Option<TreeNodeId> {
+        self.selected
+    }
+    pub fn label(&self, node: TreeNodeId) -> &str {
+        &self.items[node].label
+    }
+    pub fn key(&self, node: TreeNodeId) -> &str {
+        &self.items[node].key
+    }
+    pub fn parent(&self, node: TreeNodeId) -> Option<TreeNodeId> {
+        self.items[node].parent
+    }
+    pub fn children(&self, node: TreeNodeId) -> &[TreeNodeId] {
+        &self.items[node].children
+    }
+    pub fn event_selection_changed(&self) -> Arc<SelectionChangedCallback> {
+        self.event_selection_changed.callback.clone()
+    }
+    pub fn set_event_selection_changed(&mut self, callback: SelectionChangedCallback) {
+        self.event_selection_changed = SelectionChanged {
+            callback: Arc::new(callback),
+        }
+    }
+    fn insert_node(
+        items: &mut Vec<TreeItem>,
+        parent: Option<TreeNodeId>,
+        node: TreeNode,
+    ) -> TreeNodeId {
+        let id = items.len();
+        items.push(TreeItem {
+            label: node.label,
+            key: node.key,
+            parent,
+            children: vec![],
+            expanded: false,
+            pending_load: node.lazy && node.children.is_empty(),
+        });
+        for child in node.children {
+            let child_id = Self::insert_node(items, Some(id), child);
+            items[id].children.push(child_id);
+        }
+        id
+    }
+    fn visible_rows(
+        items: &[TreeItem],
+        roots: &[TreeNodeId],
+        selected: Option<TreeNodeId>,
+    ) -> Vec<TreeViewRow> {
+        let mut rows = vec![];
+        let mut stack: Vec<(TreeNodeId, usize)> = roots.iter().rev().map(|id| (*id, 0)).collect();
+        while let Some((id, depth)) = stack.pop() {
+            let item = &items[id];
+            let expander = if item.children.is_empty() && !item.pending_load {
+                TreeViewExpander::None
+            } else if item.expanded {
+                TreeViewExpander::Expanded
+            } else {
+                TreeViewExpander::Collapsed
+            };
+            rows.push(TreeViewRow {
+                node: id,
+                label: item.label.clone(),
+                depth,
+                expander,
+                selected: selected == Some(id),
+            });
+            if item.expanded {
+                stack.extend(item.children.iter().rev().map(|child| (*child, depth + 1)));
+            }
+        }
+        rows
+    }
+    fn load_children_of(&mut self, node: TreeNodeId) {
+        let Some(load_children) = self.load_children else {
+            info!("TreeView: node {} has lazy children but no loader", node);
+            return;
+        };
+        let children = load_children(&self.items[node].key);
+        debug!("TreeView: loaded {} children for {}", children.len(), node);
+        for child in children {
+            let child_id = Self::insert_node(&mut self.items, Some(node), child);
+            self.items[node].children.push(child_id);
+        }
+        self.items[node].pending_load = false;
+    }
+    fn refresh(&mut self) {
+        self.rows = Self::visible_rows(&self.items, &self.roots, self.selected);
+        let type_id = TypeId::of::<Self>();
+        let binding = self.common.style_master();
+        let theme: &dyn ThemeForTreeView =
+            binding.expect_theme_for_widget_t(type_id, Self::class_name());
+        self.common.fit(|size_for_clipping, prim_man| {
+            let style: Box<ThemeStyleForTreeView> =
+                binding.expect_style_for_widget_t(type_id, Self::class_name());
+            theme.set_rows(&self.rows, size_for_clipping, style, prim_man)
+        });
+    }
+    fn row_index_of(&self, node: TreeNodeId) -> Option<usize> {
+        self.rows.iter().position(|row| row.node == node)
+    }
+    fn toggle(&mut self, node: TreeNodeId) {
+        if self.items[node].expanded {
+            self.collapse(node);
+        } else {
+            self.expand(node);
+        }
+    }
+    /// Selects as if the user did it, returning the lib-user's event if the selection changed.
+    fn select_by_user(&mut self, node: TreeNodeId) -> DeferredEvents {
+        if self.selected == Some(node) {
+            return vec![];
+        }
+        self.select(Some(node));
+        vec![self.event_selection_changed.defer(self.wid(), node)]
+    }
+    fn selection_is_hidden_under(&self, node: TreeNodeId) -> bool {
+        let mut current = self
+            .selected
+            .and_then(|selected| self.items[selected].parent);
+        while let Some(id) = current {
+            if id == node {
+                return true;
+            }
+            current = self.items[id].parent;
+        }
+        false
+    }
+}
+
+impl Primitive for TreeView {
+    fn class_name() -> &'static str {
+        "TreeView"
+    }
+    // TODO change to macro
+    fn class(&self) -> &'static str {
+        Self::class_name()
+    }
+    fn wid(&self) -> usize {
+        self.common.wid()
+    }
+    fn set_wid(&mut self, nid: usize) {
+        self.common.set_wid(nid)
+    }
+    fn x(&self) -> f32 {
+        self.common.x()
+    }
+    fn y(&self) -> f32 {
+        self.common.y()
+    }
+    fn position(&self) -> &Vector2D<f32> {
+        self.common.position()
+    }
+    fn set_position(&mut self, position: Vector2D<f32>) {
+        self.common.set_position(position)
+    }
+    fn width(&self) -> f32 {
+        self.common.width()
+    }
+    fn height(&self) -> f32 {
+        self.common.height()
+    }
+    fn size(&mut self) -> &Vector2D<f32> {
+        self.common.size()
+    }
+}
+
+impl PrivatePrimitiveMethods for TreeView {
+    fn update_geometry(&mut self) {
+        self.common.update_geometry()
+    }
+    fn needs_update(&self) -> bool {
+        self.common.needs_update()
+    }
+    fn set_needs_update(&mut self, needs_update: bool) {
+        self.common.set_needs_update(needs_update)
+    }
+    fn needs_translation(&self) -> bool {
+        self.common.needs_translation()
+    }
+    fn set_needs_translation(&mut self, needs_translation: bool) {
+        self.common.set_needs_translation(needs_translation)
+    }
+    fn clone_geometry(&self) -> Geometry {
+        self.common.clone_geometry()
+    }
+    fn set_translated_geometry(&mut self, translated_geometry: Geometry) {
+        self.common.set_translated_geometry(translated_geometry)
+    }
+    fn clone_translated_geometry(&self) -> Geometry {
+        self.common.clone_translated_geometry()
+    }
+}
+
+impl HasEvents for TreeView {
+    fn event_mouse_button_down(&self) -> Arc<MouseButtonDownCallback> {
+        self.common.event_mouse_button_down()
+    }
+    fn set_event_mouse_button_down(&mut self, callback: MouseButtonDownCallback) {
+        self.common.set_event_mouse_button_down(callback)
+    }
+    fn event_key_down(&self) -> Arc<super::events::KeyDownCallback> {
+        self.common.event_key_down()
+    }
+    fn set_event_key_down(&mut self, callback: super::events::KeyDownCallback) {
+        self.common.set_event_key_down(callback);
+    }
+}
+
+impl HasTooltip for TreeView {
+    fn tooltip_mut(&mut self) -> Option<&mut OwnedDynWidget> {
+        self.common.tooltip_mut()
+    }
+    fn set_tooltip(&mut self, tooltip: Option<OwnedDynWidget>) {
+        self.common.set_tooltip(tooltip)
+    }
+}
+
+impl Widget for TreeView {
+    fn set_layout_size(&mut self, size: Option<Vector2D<f32>>) {
+        self.common.set_layout_size(size);
+        self.refresh();
+    }
+    fn size_constraints(&self) -> SizeConstraints {
+        self.common.size_constraints()
+    }
+    fn set_size_constraints(&mut self, constraints: SizeConstraints) {
+        self.common.set_size_constraints(constraints);
+        self.refresh();
+    }
+    fn handle_mouse_button_down(&mut self, x: i32, y: i32) -> DeferredEvents {
+        let (local_x, local_y) = self.common.local(x, y).unpack();
+        let index = (local_y / self.row_height) as usize;
+        let Some(row) = self.rows.get(index) else {
+            return vec![];
+        };
+        let node = row.node;
+        let expander_x = row.depth as f32 * self.indent;
+        let clicked_expander = row.expander != TreeViewExpander::None
+            && expander_x <= local_x
+            && local_x < expander_x + self.row_height;
+        if clicked_expander {
+            let hides_selection = self.selection_is_hidden_under(node);
+            self.toggle(node);
+            if hides_selection {
+                return self.select_by_user(node);
+            }
+            return vec![];
+        }
+        self.select_by_user(node)
+    }
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+    fn handle_key_down(&mut self, key: Keycode, _keymod: Mod) -> DeferredEvents {
+        let Some(selected) = self.selected else {
+            return match self.rows.first() {
+                Some(row) if key == Keycode::Down || key == Keycode::Up => {
+                    self.select_by_user(row.node)
+                }
+                _ => vec![],
+            };
+        };
+        let Some(index) = self.row_index_of(selected) else {
+            return vec![];
+        };
+        let has_children = self.rows[index].expander != TreeViewExpander::None;
+        match key {
+            Keycode::Up if index > 0 => self.select_by_user(self.rows[index - 1].node),
+            Keycode::Down if index + 1 < self.rows.len() => {
+                self.select_by_user(self.rows[index + 1].node)
+            }
+            Keycode::Right if has_children => {
+                if self.is_expanded(selected) {
+                    match self.items[selected].children.first() {
+                        Some(child) => self.select_by_user(*child),
+                        None => vec![],
+                    }
+                } else {
+                    self.expand(selected);
+                    vec![]
+                }
+            }
+            Keycode::Left => {
+                if has_children && self.is_expanded(selected) {
+                    self.collapse(selected);
+                    vec![]
+                } else {
+                    match self.parent(selected) {
+                        Some(parent) => self.select_by_user(parent),
+                        None => vec![],
+                    }
+                }
+            }
+            Keycode::Return | Keycode::Space if has_children => {
+                self.toggle(selected);
+                vec![]
+            }
+            _ => vec![],
+        }
+    }
+}
@@ -1,15 +1,16 @@
 use log::{debug, info};
+use sdl2::keyboard::{Keycode, Mod};
 
-use crate::general::{Rect, Vector2D};
+use crate::general::{Rect, SizeConstraints, Vector2D};
 use crate::widgets::primitives::Primitive;
 use crate::window::Root;
 
-use super::events::HasEvents;
-use super::{BorrowedWidgetT, WidgetEnum};
+use super::events::{DeferredEvents, HasEvents};
+use super::{BorrowedWidgetT, Container, HasTooltip, WidgetEnum};
 
 pub type WidgetId = usize;
 
-pub trait Widget: Primitive + HasEvents {
+pub trait Widget: Primitive + HasEvents + HasTooltip {
     fn get_rect(&mut self) -> Rect<f32> {
         // Maybe upgrade to Primitive?
         let (w, h) = self.size().unpack();
@@ -27,6 +28,65 @@ pub trait Widget: Primitive + HasEvents {
         self.get_rect()
             .contains_point(Vector2D::<f32>::new(x as f32, y as f32))
     }
+    /// Lets the widget react by itself to a click, before the lib-user's callback is called.
+    fn handle_mouse_button_down(&mut self, _x: i32, _y: i32) -> DeferredEvents {
+        vec![]
+    }
+    /// Called for mouse motion while the button that was pressed on this widget is held.
+    fn handle_mouse_drag(&mut self, _x: i32, _y: i32) -> DeferredEvents {
+        vec![]
+    }
+    /// Called when the button that was pressed on this widget is released, wherever it is.
+    fn handle_mouse_button_up(&mut self, _x: i32, _y: i32) -> DeferredEvents {
+        vec![]
+    }
+    /// Called with the widget under the pointer, dy is positive when scrolling away from the user.
+    fn handle_mouse_wheel(&mut self, _x: i32, _y: i32, _dx: i32, _dy: i32) -> DeferredEvents {
+        vec![]
+    }
+    /// Lets the widget react by itself to a key while focused, before the lib-user's callback.
+    fn handle_key_down(&mut self, _key: Keycode, _keymod: Mod) -> DeferredEvents {
+        vec![]
+    }
+    /// Typed text while focused, already composed by SDL, so it may be more than one char.
+    fn handle_text_input(&mut self, _text: &str) -> DeferredEvents {
+        vec![]
+    }
+    /// Called when the widget gets or loses the keyboard focus, e.g. to show a caret.
+    fn handle_focus_changed(&mut self, _focused: bool) -> DeferredEvents {
+        vec![]
+    }
+    /// Whether Tab stops on it, true for the widgets that handle keys or text by themselves.
+    fn accepts_focus(&self) -> bool {
+        false
+    }
+    /// Keys it takes for itself that its container would otherwise use, e.g. Return in a TextArea
+    /// instead of the OK of a Dialog.
+    fn wants_key(&self, _key: Keycode) -> bool {
+        false
+    }
+    /// Drawn above every other widget while it's Some, e.g. the calendar of a DatePicker.
+    /// Clicks on it go to this widget's handle_mouse_button_down().
+    fn popup_mut(&mut self) -> Option<&mut dyn Widget> {
+        None
+    }
+    /// Called when something outside the popup is clicked.
+    fn close_popup(&mut self) -> DeferredEvents {
+        vec![]
+    }
+    /// Asked by layouts that stretch their children, None when the layout no longer sizes it.
+    /// Widgets that can't be resized ignore it.
+    fn set_layout_size(&mut self, _size: Option<Vector2D<f32>>) {}
+    /// Layouts keep the widget inside them when they resize it.
+    fn size_constraints(&self) -> SizeConstraints;
+    /// The widget rebuilds its primitives to fit, what's outside of them is clipped.
+    fn set_size_constraints(&mut self, constraints: SizeConstraints);
+    fn as_container(&self) -> Option<&dyn Container> {
+        None
+    }
+    fn as_container_mut(&mut self) -> Option<&mut dyn Container> {
+        None
+    }
     fn get_by_id<WENUM: WidgetEnum>(
         root: &mut dyn Root,
         wenum: WENUM,
@@ -36,23 +96,20 @@ pub trait Widget: Primitive + HasEvents {
     {
         let wid = wenum.to_wid();
         debug!("Widget:get_by_id wid={}", wid);
-        let option_dw = root.get_down_widget_by_id(wid);
-        if let Some(dw) = option_dw {
-            let option_wt = dw.widget_t::<Self>();
-            match option_wt {
-                Some(wt) => {
-                    debug!("Could convert widget wid={} to {}", wid, Self::class_name());
-                    return Ok(wt);
-                }
-                None => {
-                    return Err(format!(
-                        "get_by_id(): Not a {}. Got a {}",
-                        Self::class_name(),
-                        dw.class()
-                    ))
-                }
+        let dw = root
+            .get_down_widget_by_id(wid)
+            .map_err(|error| format!("widget:Widget:get_by_id({}): {}", wid, error))?;
+        let option_wt = dw.widget_t::<Self>();
+        match option_wt {
+            Some(wt) => {
+                debug!("Could convert widget wid={} to {}", wid, Self::class_name());
+                Ok(wt)
             }
+            None => Err(format!(
+                "get_by_id(): Not a {}. Got a {}",
+                Self::class_name(),
+                dw.class()
+            )),
         }
-        Err(format!("Not found: widget:Widget:get_by_id({})", wid))
     }
 }
@@ -5,3 +5,10 @@ use super::WidgetId;
 pub trait WidgetEnum: Clone + Copy + Debug {
     fn to_wid(self) -> WidgetId;
 }
+
+/// For widgets that are never looked up, like the window's toasts.
+impl WidgetEnum for WidgetId {
+    fn to_wid(self) -> WidgetId {
+        self
+    }
+}
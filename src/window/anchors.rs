@@ -0,0 +1,248 @@
+/// Which sides of the center region a widget keeps its distance to, as it was when added.
+/// Left and right together stretch it, neither keeps it at the same fraction of the width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchors {
+    pub left: bool,
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+}
+
+impl Default for Anchors {
+    fn default() -> Self {
+        Self::TOP_LEFT
+    }
+}
+
+impl Anchors {
+    pub const TOP_LEFT: Anchors = Anchors::new(true, true, false, false);
+    pub const TOP_RIGHT: Anchors = Anchors::new(false, true, true, false);
+    pub const BOTTOM_LEFT: Anchors = Anchors::new(true, false, false, true);
+    pub const BOTTOM_RIGHT: Anchors = Anchors::new(false, false, true, true);
+    /// Stretched along the top.
+    pub const TOP_LEFT_RIGHT: Anchors = Anchors::new(true, true, true, false);
+    /// Stretched along the bottom.
+    pub const BOTTOM_LEFT_RIGHT: Anchors = Anchors::new(true, false, true, true);
+    /// Stretched both ways.
+    pub const ALL: Anchors = Anchors::new(true, true, true, true);
+    /// Keeps its center where it was, relative to the region's size.
+    pub const NONE: Anchors = Anchors::new(false, false, false, false);
+
+    pub const fn new(left: bool, top: bool, right: bool, bottom: bool) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+}
+
+/// A size in pixels or relative to the center region.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    Pixels(f32),
+    /// 0 to 100.
+    Percent(f32),
+}
+
+impl Length {
+    pub fn resolve(&self, parent: f32) -> f32 {
+        match self {
+            Length::Pixels(pixels) => *pixels,
+            Length::Percent(percent) => parent * percent / 100.0,
+        }
+    }
+}
+
+/// How a widget follows the center region when it changes, e.g. when the window is resized.
+/// A width or height given here wins over the anchors stretching it.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Placement {
+    pub anchors: Anchors,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+}
+
+impl Placement {
+    pub fn new(anchors: Anchors) -> Self {
+        Self {
+            anchors,
+            ..Default::default()
+        }
+    }
+    pub fn with_width(mut self, width: Length) -> Self {
+        self.width = Some(width);
+        self
+    }
+    pub fn with_height(mut self, height: Length) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+/// A Placement and the distances to the region's sides it keeps.
+#[derive(Debug, Clone)]
+pub(crate) struct Anchored {
+    placement: Placement,
+    // left, top, right, bottom
+    distances: (f32, f32, f32, f32),
+    // Where the widget's center was, as a fraction of the region's size
+    center_fraction: (f32, f32),
+}
+
+impl Anchored {
+    /// rect is the widget's and region's size is the center region's, both relative to it.
+    pub(crate) fn new(
+        placement: Placement,
+        (x, y, width, height): (f32, f32, f32, f32),
+        (region_width, region_height): (f32, f32),
+    ) -> Self {
+        let fraction = |position: f32, size: f32, region: f32| {
+            if region > 0.0 {
+                (position + size / 2.0) / region
+            } else {
+                0.5
+            }
+        };
+        Self {
+            placement,
+            distances: (x, y, region_width - x - width, region_height - y - height),
+            center_fraction: (
+                fraction(x, width, region_width),
+                fraction(y, height, region_height),
+            ),
+        }
+    }
+    /// Where the widget goes and how big it is, relative to the region.
+    /// size is the widget's own, used when nothing says how big it should be.
+    pub(crate) fn rect(
+        &self,
+        (width, height): (f32, f32),
+        (region_width, region_height): (f32, f32),
+    ) -> (f32, f32, f32, f32) {
+        let (left, top, right, bottom) = self.distances;
+        let anchors = &self.placement.anchors;
+        let (x, width) = Self::along(
+            (anchors.left, anchors.right),
+            (left, right),
+            self.placement.width,
+            width,
+            region_width,
+            self.center_fraction.0,
+        );
+        let (y, height) = Self::along(
+            (anchors.top, anchors.bottom),
+            (top, bottom),
+            self.placement.height,
+            height,
+            region_height,
+            self.center_fraction.1,
+        );
+        (x, y, width, height)
+    }
+    fn along(
+        (start_anchor, end_anchor): (bool, bool),
+        (start, end): (f32, f32),
+        length: Option<Length>,
+        size: f32,
+        region: f32,
+        center_fraction: f32,
+    ) -> (f32, f32) {
+        let size = match length {
+            Some(length) => length.resolve(region),
+            None if start_anchor && end_anchor => region - start - end,
+            None => size,
+        }
+        .max(0.0);
+        let position = match (start_anchor, end_anchor) {
+            (true, _) => start,
+            (false, true) => region - end - size,
+            (false, false) => region * center_fraction - size / 2.0,
+        };
+        (position, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A 20x10 widget at (10, 10) in a 100x100 region
+    fn anchored(placement: Placement) -> Anchored {
+        Anchored::new(placement, (10.0, 10.0, 20.0, 10.0), (100.0, 100.0))
+    }
+
+    #[test]
+    fn resolves_lengths() {
+        assert_eq!(Length::Pixels(12.0).resolve(300.0), 12.0);
+        assert_eq!(Length::Percent(50.0).resolve(300.0), 150.0);
+        assert_eq!(Length::Percent(0.0).resolve(300.0), 0.0);
+    }
+
+    #[test]
+    fn top_left_keeps_its_place_and_size() {
+        let anchored = anchored(Placement::default());
+        assert_eq!(
+            anchored.rect((20.0, 10.0), (200.0, 50.0)),
+            (10.0, 10.0, 20.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn bottom_right_follows_the_far_sides() {
+        let anchored = anchored(Placement::new(Anchors::BOTTOM_RIGHT));
+        assert_eq!(
+            anchored.rect((20.0, 10.0), (200.0, 50.0)),
+            (110.0, -40.0, 20.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn stretches_but_never_below_0() {
+        let anchored = anchored(Placement::new(Anchors::ALL));
+        assert_eq!(
+            anchored.rect((20.0, 10.0), (200.0, 150.0)),
+            (10.0, 10.0, 120.0, 60.0)
+        );
+        let (_, _, width, height) = anchored.rect((20.0, 10.0), (20.0, 20.0));
+        assert_eq!((width, height), (0.0, 0.0));
+    }
+
+    #[test]
+    fn percent_wins_over_stretching() {
+        let placement = Placement::new(Anchors::ALL).with_width(Length::Percent(50.0));
+        let anchored = anchored(placement);
+        assert_eq!(
+            anchored.rect((20.0, 10.0), (200.0, 100.0)),
+            (10.0, 10.0, 100.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn no_anchors_keep_the_center_fraction() {
+        let anchored = Anchored::new(
+            Placement::new(Anchors::NONE),
+            (10.0, 15.0, 20.0, 10.0),
+            (80.0, 80.0),
+        );
+        // Its center was at a quarter of the region both ways
+        assert_eq!(
+            anchored.rect((20.0, 10.0), (160.0, 160.0)),
+            (30.0, 35.0, 20.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn empty_region_centers_it() {
+        let anchored = Anchored::new(
+            Placement::new(Anchors::NONE),
+            (0.0, 0.0, 10.0, 10.0),
+            (0.0, 0.0),
+        );
+        assert_eq!(
+            anchored.rect((10.0, 10.0), (100.0, 100.0)),
+            (45.0, 45.0, 10.0, 10.0)
+        );
+    }
+}
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use log::debug;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::mouse::MouseButton;
+
+use crate::general::{Geometry, Rect, Vector2D};
+use crate::themes::StyleMaster;
+use crate::widgets::events::{DeferredEvents, MenuActivated};
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{ContextMenu, Menu, MenuBar, MenuItem, Widget, WidgetId};
+
+/// Who the open menu belongs to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MenuOwner {
+    MenuBar(usize), // the open title
+    Context(WidgetId),
+}
+
+#[derive(Debug)]
+struct OpenMenu {
+    owner: MenuOwner,
+    popups: Vec<Menu>, // each one is the submenu highlighted in the previous one
+}
+
+/// Opens the menu bar's menus and the context menus, and gets all the input while one is open.
+#[derive(Debug, Default)]
+pub(crate) struct MenuTracker {
+    menu_bar: Option<MenuBar>,
+    context_menus: HashMap<WidgetId, ContextMenu>,
+    open: Option<OpenMenu>,
+    window_size: Vector2D<f32>,
+}
+
+impl MenuTracker {
+    pub(crate) fn new(window_size: Vector2D<f32>) -> MenuTracker {
+        MenuTracker {
+            window_size,
+            ..Default::default()
+        }
+    }
+    pub(crate) fn set_menu_bar(&mut self, mut menu_bar: MenuBar) {
+        self.close();
+        menu_bar.set_position(Vector2D::new(0.0, 0.0));
+        menu_bar.set_width(self.window_size.x());
+        self.menu_bar = Some(menu_bar);
+    }
+    /// When the window is resized, the menu bar keeps its whole width.
+    pub(crate) fn set_window_size(&mut self, window_size: Vector2D<f32>) {
+        self.close();
+        if let Some(menu_bar) = &mut self.menu_bar {
+            menu_bar.set_width(window_size.x());
+        }
+        self.window_size = window_size;
+    }
+    pub(crate) fn menu_bar_mut(&mut self) -> Option<&mut MenuBar> {
+        self.menu_bar.as_mut()
+    }
+    /// Space taken from the top of the window, 0 without a menu bar.
+    pub(crate) fn menu_bar_height(&self) -> f32 {
+        self.menu_bar
+            .as_ref()
+            .map_or(0.0, |menu_bar| menu_bar.height())
+    }
+    pub(crate) fn set_context_menu(&mut self, wid: WidgetId, context_menu: Option<ContextMenu>) {
+        if self
+            .open
+            .as_ref()
+            .is_some_and(|open| open.owner == MenuOwner::Context(wid))
+        {
+            self.close();
+        }
+        match context_menu {
+            Some(context_menu) => self.context_menus.insert(wid, context_menu),
+            None => self.context_menus.remove(&wid),
+        };
+    }
+    pub(crate) fn context_menu_mut(&mut self, wid: WidgetId) -> Option<&mut ContextMenu> {
+        self.context_menus.get_mut(&wid)
+    }
+    pub(crate) fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+    /// Opens the context menu of the first wid that has one, they should go from the innermost
+    /// widget to its containers.
+    pub(crate) fn open_context_menu(&mut self, wids: &[WidgetId], x: i32, y: i32) -> bool {
+        let Some(wid) = wids.iter().find(|wid| self.context_menus.contains_key(wid)) else {
+            return false;
+        };
+        self.close();
+        debug!("MenuTracker: context menu of wid:{}", wid);
+        self.open = Some(OpenMenu {
+            owner: MenuOwner::Context(*wid),
+            popups: vec![],
+        });
+        self.open_popup(Rect::new(x as f32, y as f32, 0.0, 0.0), false);
+        true
+    }
+    pub(crate) fn close(&mut self) {
+        if let Some(menu_bar) = &mut self.menu_bar {
+            menu_bar.set_open(None);
+        }
+        self.open = None;
+    }
+    /// Some when the click was for the menus, even if it only closed them.
+    pub(crate) fn handle_mouse_button_down(
+        &mut self,
+        mouse_btn: MouseButton,
+        x: i32,
+        y: i32,
+    ) -> Option<DeferredEvents> {
+        let title = self.menu_bar.as_ref().and_then(|bar| bar.title_at(x, y));
+        let on_bar = self
+            .menu_bar
+            .as_mut()
+            .is_some_and(|bar| bar.will_accept_mouse_click_event(x, y));
+        let Some(open) = &mut self.open else {
+            if let (MouseButton::Left, Some(title)) = (mouse_btn, title) {
+                self.open_title(title, false);
+            }
+            return on_bar.then(Vec::new);
+        };
+        let level = open
+            .popups
+            .iter_mut()
+            .rposition(|popup| popup.will_accept_mouse_click_event(x, y));
+        if let Some(level) = level {
+            let row = open.popups[level].row_at(x, y);
+            return Some(match row {
+                Some(row) => self.activate(level, row, false),
+                None => vec![],
+            });
+        }
+        // Clicking the open title closes its menu, clicking another one switches to it
+        let owner = open.owner;
+        self.close();
+        if let Some(title) = title.filter(|title| owner != MenuOwner::MenuBar(*title)) {
+            self.open_title(title, false);
+        }
+        Some(vec![])
+    }
+    /// Returns true when the motion was for the menus.
+    pub(crate) fn handle_mouse_motion(&mut self, x: i32, y: i32) -> bool {
+        let Some(open) = &mut self.open else {
+            return false;
+        };
+        let level = open
+            .popups
+            .iter_mut()
+            .rposition(|popup| popup.will_accept_mouse_click_event(x, y));
+        if let Some(level) = level {
+            let popup = &open.popups[level];
+            if let Some(row) = popup.row_at(x, y) {
+                if popup.highlighted() != Some(row) {
+                    self.highlight(level, row, false);
+                }
+            }
+            return true;
+        }
+        // Moving along the bar while a menu is open switches menus
+        let title = self.menu_bar.as_ref().and_then(|bar| bar.title_at(x, y));
+        if let (MenuOwner::MenuBar(open_title), Some(title)) = (open.owner, title) {
+            if open_title != title {
+                self.open_title(title, false);
+            }
+        }
+        true
+    }
+    /// Alt plus a mnemonic opens a menu bar menu, once open every key goes to the menus.
+    pub(crate) fn handle_key_down(&mut self, key: Keycode, keymod: Mod) -> Option<DeferredEvents> {
+        let letter = char::from_u32(key.into_i32() as u32).filter(char::is_ascii_alphanumeric);
+        if keymod.intersects(Mod::LALTMOD | Mod::RALTMOD) {
+            let title =
+                letter.and_then(|letter| self.menu_bar.as_ref()?.title_for_mnemonic(letter));
+            if let Some(title) = title {
+                self.open_title(title, true);
+                return Some(vec![]);
+            }
+        }
+        let open = self.open.as_ref()?;
+        let level = open.popups.len() - 1;
+        let highlighted = open.popups[level].highlighted();
+        let owner = open.owner;
+        let events = match key {
+            Keycode::Escape => {
+                self.close_level(level);
+                vec![]
+            }
+            Keycode::Up | Keycode::Down => {
+                self.last_popup_mut().highlight_next(key == Keycode::Up);
+                vec![]
+            }
+            Keycode::Right => {
+                let submenu = highlighted
+                    .filter(|row| self.item(level, *row).is_some_and(MenuItem::is_submenu));
+                match (submenu, owner) {
+                    (Some(row), _) => self.highlight(level, row, true),
+                    (None, MenuOwner::MenuBar(title)) => self.open_next_title(title, false),
+                    (None, MenuOwner::Context(_)) => {}
+                }
+                vec![]
+            }
+            Keycode::Left => {
+                match (level, owner) {
+                    (0, MenuOwner::MenuBar(title)) => self.open_next_title(title, true),
+                    (0, MenuOwner::Context(_)) => {}
+                    _ => self.close_level(level),
+                }
+                vec![]
+            }
+            Keycode::Return | Keycode::KpEnter | Keycode::Space => match highlighted {
+                Some(row) => self.activate(level, row, true),
+                None => vec![],
+            },
+            _ => {
+                let row = letter.and_then(|letter| {
+                    self.items(level).iter().position(|item| {
+                        !item.is_separator()
+                            && item
+                                .mnemonic()
+                                .is_some_and(|(_, mnemonic)| mnemonic == letter)
+                    })
+                });
+                match row {
+                    Some(row) => self.activate(level, row, true),
+                    None => vec![],
+                }
+            }
+        };
+        Some(events)
+    }
+    pub(crate) fn build_menu_bar_geometry(&mut self) -> Option<Geometry> {
+        Some(self.menu_bar.as_mut()?.build_geometry())
+    }
+    /// The open popups, they go in the window's overlay.
+    pub(crate) fn build_popup_geometries(&mut self) -> Vec<Geometry> {
+        match &mut self.open {
+            Some(open) => open
+                .popups
+                .iter_mut()
+                .map(|popup| popup.build_geometry())
+                .collect(),
+            None => vec![],
+        }
+    }
+    fn open_title(&mut self, title: usize, highlight_first: bool) {
+        self.close();
+        let Some(menu_bar) = &mut self.menu_bar else {
+            return;
+        };
+        if !menu_bar.menus()[title].is_enabled() {
+            return;
+        }
+        menu_bar.set_open(Some(title));
+        let anchor = menu_bar.title_rect(title);
+        self.open = Some(OpenMenu {
+            owner: MenuOwner::MenuBar(title),
+            popups: vec![],
+        });
+        self.open_popup(anchor, false);
+        if highlight_first {
+            self.last_popup_mut().highlight_next(false);
+        }
+    }
+    /// Left and Right on the first level go through the bar, skipping disabled menus.
+    fn open_next_title(&mut self, title: usize, reverse: bool) {
+        let Some(menu_bar) = &self.menu_bar else {
+            return;
+        };
+        let count = menu_bar.menus().len();
+        let next = (1..count)
+            .map(|step| {
+                if reverse {
+                    (title + count - step) % count
+                } else {
+                    (title + step) % count
+                }
+            })
+            .find(|index| menu_bar.menus()[*index].is_enabled());
+        if let Some(next) = next {
+            self.open_title(next, true);
+        }
+    }
+    /// Below the anchor, or beside it for submenus, flipped when it would leave the window.
+    fn open_popup(&mut self, anchor: Rect<f32>, beside: bool) {
+        let Some(open) = &self.open else {
+            return;
+        };
+        let path: Vec<usize> = open
+            .popups
+            .iter()
+            .map_while(|popup| popup.highlighted())
+            .collect();
+        let items = MenuItem::items_at(self.root_items(open.owner), &path);
+        let mut popup = Menu::new(0, items, self.style_master(open.owner))
+            .expect("menu_tracker:MenuTracker:open_popup Menu::new");
+        let (width, height) = popup.size().unpack();
+        let (window_width, window_height) = self.window_size.unpack();
+        let (mut x, mut y) = if beside {
+            (anchor.right(), anchor.y())
+        } else {
+            (anchor.x(), anchor.bottom())
+        };
+        if x + width > window_width {
+            x = if beside {
+                anchor.x() - width
+            } else {
+                window_width - width
+            };
+        }
+        if y + height > window_height {
+            y = if beside {
+                window_height - height
+            } else {
+                anchor.y() - height
+            };
+        }
+        popup.set_position(Vector2D::new(x.max(0.0), y.max(0.0)));
+        if let Some(open) = &mut self.open {
+            open.popups.push(popup);
+        }
+    }
+    /// Highlights a row closing deeper levels, and opens the row's submenu if it has one.
+    fn highlight(&mut self, level: usize, row: usize, highlight_first: bool) {
+        let Some(open) = &mut self.open else {
+            return;
+        };
+        open.popups.truncate(level + 1);
+        open.popups[level].set_highlighted(Some(row));
+        let anchor = open.popups[level].row_rect(row);
+        let opens_submenu = self
+            .item(level, row)
+            .is_some_and(|item| item.is_submenu() && item.is_enabled());
+        if opens_submenu {
+            self.open_popup(anchor, true);
+            if highlight_first {
+                self.last_popup_mut().highlight_next(false);
+            }
+        }
+    }
+    /// Opens submenus, toggles check items and calls the activated event for the rest.
+    fn activate(&mut self, level: usize, row: usize, keyboard: bool) -> DeferredEvents {
+        let Some(item) = self.item(level, row).cloned() else {
+            return vec![];
+        };
+        if item.is_separator() || !item.is_enabled() {
+            return vec![];
+        }
+        if item.is_submenu() {
+            self.highlight(level, row, keyboard);
+            return vec![];
+        }
+        let Some(action) = item.action() else {
+            return vec![];
+        };
+        let Some(open) = &self.open else {
+            return vec![];
+        };
+        let owner = open.owner;
+        let mut path: Vec<usize> = open
+            .popups
+            .iter()
+            .take(level)
+            .filter_map(|popup| popup.highlighted())
+            .collect();
+        path.push(row);
+        if let Some(checked) = item.is_checked() {
+            let (row, path) = path
+                .split_last()
+                .expect("menu_tracker:MenuTracker:activate");
+            MenuItem::items_at_mut(self.root_items_mut(owner), path)[*row].set_checked(!checked);
+        }
+        self.close();
+        debug!("MenuTracker: activated action:{}", action);
+        let (wid, callback) = match owner {
+            MenuOwner::MenuBar(_) => match &self.menu_bar {
+                Some(menu_bar) => (menu_bar.wid(), menu_bar.event_activated()),
+                None => return vec![],
+            },
+            MenuOwner::Context(wid) => match self.context_menus.get(&wid) {
+                Some(context_menu) => (wid, context_menu.event_activated()),
+                None => return vec![],
+            },
+        };
+        vec![MenuActivated { callback }.defer(wid, action)]
+    }
+    fn close_level(&mut self, level: usize) {
+        match &mut self.open {
+            Some(open) if level > 0 => {
+                open.popups.truncate(level);
+            }
+            _ => self.close(),
+        }
+    }
+    /// The items shown at level.
+    fn items(&self, level: usize) -> &[MenuItem] {
+        let Some(open) = &self.open else {
+            return &[];
+        };
+        let path: Vec<usize> = open
+            .popups
+            .iter()
+            .take(level)
+            .filter_map(|popup| popup.highlighted())
+            .collect();
+        MenuItem::items_at(self.root_items(open.owner), &path)
+    }
+    fn item(&self, level: usize, row: usize) -> Option<&MenuItem> {
+        self.items(level).get(row)
+    }
+    fn root_items(&self, owner: MenuOwner) -> &[MenuItem] {
+        match owner {
+            MenuOwner::MenuBar(title) => self
+                .menu_bar
+                .as_ref()
+                .map(|menu_bar| menu_bar.menus()[title].children())
+                .unwrap_or_default(),
+            MenuOwner::Context(wid) => self
+                .context_menus
+                .get(&wid)
+                .map(|context_menu| context_menu.items())
+                .unwrap_or_default(),
+        }
+    }
+    fn root_items_mut(&mut self, owner: MenuOwner) -> &mut [MenuItem] {
+        match owner {
+            MenuOwner::MenuBar(title) => self
+                .menu_bar
+                .as_mut()
+                .map(|menu_bar| menu_bar.submenu_items_mut(title))
+                .unwrap_or_default(),
+            MenuOwner::Context(wid) => self
+                .context_menus
+                .get_mut(&wid)
+                .map(|context_menu| context_menu.items_mut())
+                .unwrap_or_default(),
+        }
+    }
+    fn style_master(&self, owner: MenuOwner) -> Arc<StyleMaster> {
+        match owner {
+            MenuOwner::MenuBar(_) => self
+                .menu_bar
+                .as_ref()
+                .map(|menu_bar| menu_bar.style_master())
+                .expect("menu_tracker:MenuTracker:style_master MenuBar"),
+            MenuOwner::Context(wid) => self
+                .context_menus
+                .get(&wid)
+                .map(|context_menu| context_menu.style_master())
+                .expect("menu_tracker:MenuTracker:style_master ContextMenu"),
+        }
+    }
+    fn last_popup_mut(&mut self) -> &mut Menu {
+        self.open
+            .as_mut()
+            .and_then(|open| open.popups.last_mut())
+            .expect("menu_tracker:MenuTracker:last_popup_mut")
+    }
+}
@@ -1,7 +1,15 @@
+pub use anchors::*;
+pub(crate) use menu_tracker::*;
 pub use root::*;
+pub use toast_manager::*;
 pub use window_builder::WindowBuilder;
+pub use window_region::WindowRegion;
 pub use window_trait::Window;
 
+mod anchors;
+mod menu_tracker;
 mod root;
+mod toast_manager;
 mod window_builder;
+mod window_region;
 mod window_trait;
@@ -1,8 +1,28 @@
 use std::collections::BTreeMap;
+use std::error::Error;
+use std::time::Duration;
 
-use crate::widgets::{DowncastableBorrowedWidget, OwnedDynWidget, WidgetId};
+use crate::widgets::{Dialog, DowncastableBorrowedWidget, OwnedDynWidget, ToastLevel, WidgetId};
 
 pub trait Root {
-    fn get_down_widget_by_id(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget>;
+    /// Err with why when it's not found, e.g. it's inside a container that's borrowed.
+    fn get_down_widget_by_id(
+        &mut self,
+        wid: WidgetId,
+    ) -> Result<DowncastableBorrowedWidget, String>;
     fn children(&self) -> &BTreeMap<WidgetId, OwnedDynWidget>;
+    /// Shows the dialog on top of everything else, until it's closed.
+    /// Err when the root can't show dialogs, same as the other methods.
+    fn show_dialog(&mut self, dialog: Dialog) -> Result<(), Box<dyn Error>>;
+    /// Shows a transient message that doesn't block anything.
+    fn notify(
+        &mut self,
+        text: &str,
+        level: ToastLevel,
+        duration: Duration,
+    ) -> Result<(), Box<dyn Error>>;
+    /// Shows the text in the window's status bar, if it has one.
+    fn set_status_text(&mut self, text: &str) -> Result<(), Box<dyn Error>>;
+    /// For screen readers, e.g. the date a calendar moved to.
+    fn announce(&mut self, text: &str) -> Result<(), Box<dyn Error>>;
 }
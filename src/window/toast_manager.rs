@@ -0,0 +1,162 @@
+use std::error::Error;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::debug;
+
+use crate::general::{Geometry, Vector2D};
+use crate::themes::StyleMaster;
+use crate::widgets::primitives::Primitive;
+use crate::widgets::{Toast, ToastLevel, Widget};
+
+/// How long toasts take to slide in and out.
+const SLIDE_DURATION: Duration = Duration::from_millis(200);
+/// Space between toasts and to the window's border.
+const MARGIN: f32 = 8.0;
+
+/// Where the toasts are stacked, the newest one is the closest to the corner.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ToastCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+#[derive(Debug)]
+struct ActiveToast {
+    toast: Toast,
+    shown_at: Instant,
+    // None when it stays until it's clicked
+    expires_at: Option<Instant>,
+    // Animated towards its place in the stack when toasts above it go away
+    offset: Option<f32>,
+}
+
+impl ActiveToast {
+    /// 0 is hidden outside the window, 1 is fully in.
+    fn visibility(&self, now: Instant) -> f32 {
+        let slide = SLIDE_DURATION.as_secs_f32();
+        let slide_in = (now.duration_since(self.shown_at).as_secs_f32() / slide).min(1.0);
+        let slide_out = self.expires_at.map_or(0.0, |expires_at| {
+            (now.saturating_duration_since(expires_at).as_secs_f32() / slide).min(1.0)
+        });
+        ease(slide_in) * (1.0 - ease(slide_out))
+    }
+    fn is_gone(&self, now: Instant) -> bool {
+        self.expires_at
+            .and_then(|expires_at| expires_at.checked_add(SLIDE_DURATION))
+            .is_some_and(|gone_at| now >= gone_at)
+    }
+}
+
+fn ease(t: f32) -> f32 {
+    t * (2.0 - t)
+}
+
+/// Shows non-blocking messages stacked in a corner of the window, give it to
+/// WindowBuilder::set_toast_manager() and then use notify().
+#[derive(Debug)]
+pub struct ToastManager {
+    style_master: Arc<StyleMaster>,
+    corner: ToastCorner,
+    toasts: Vec<ActiveToast>,
+    last_update: Instant,
+}
+
+impl ToastManager {
+    pub fn new(style_master: Arc<StyleMaster>) -> ToastManager {
+        ToastManager {
+            style_master,
+            corner: Default::default(),
+            toasts: vec![],
+            last_update: Instant::now(),
+        }
+    }
+    pub fn set_corner(&mut self, corner: ToastCorner) {
+        self.corner = corner;
+    }
+    /// The toast goes away by itself after duration, or earlier if it's clicked.
+    /// Duration::MAX keeps it until it's clicked.
+    pub fn notify(
+        &mut self,
+        text: &str,
+        level: ToastLevel,
+        duration: Duration,
+    ) -> Result<(), Box<dyn Error>> {
+        debug!("ToastManager: notify {:?} {}", level, text);
+        let toast = Toast::new(0, text, level, self.style_master.clone())?;
+        let now = Instant::now();
+        self.toasts.push(ActiveToast {
+            toast,
+            shown_at: now,
+            expires_at: now.checked_add(duration),
+            offset: None,
+        });
+        Ok(())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+    /// Starts hiding the toast under the point, returns false if there was none.
+    pub(crate) fn dismiss_at(&mut self, x: i32, y: i32) -> bool {
+        let now = Instant::now();
+        let clicked = self.toasts.iter_mut().rev().find_map(|active| {
+            let shown = active.expires_at.is_none_or(|expires_at| expires_at > now);
+            (shown && active.toast.will_accept_mouse_click_event(x, y)).then_some(active)
+        });
+        match clicked {
+            Some(active) => {
+                active.expires_at = Some(now);
+                true
+            }
+            None => false,
+        }
+    }
+    /// Moves every toast to where its animation is now and returns their geometries.
+    pub(crate) fn build_geometries(&mut self, window_size: &Vector2D<f32>) -> Vec<Geometry> {
+        let now = Instant::now();
+        let delta = now.duration_since(self.last_update).as_secs_f32();
+        self.last_update = now;
+        self.toasts.retain(|active| !active.is_gone(now));
+
+        let (left, top) = match self.corner {
+            ToastCorner::TopLeft => (true, true),
+            ToastCorner::TopRight => (false, true),
+            ToastCorner::BottomLeft => (true, false),
+            ToastCorner::BottomRight => (false, false),
+        };
+        // Distance from the corner, the newest toast is the closest
+        let mut stack_offset = MARGIN;
+        let mut geometries = Vec::with_capacity(self.toasts.len());
+        for active in self.toasts.iter_mut().rev() {
+            let visibility = active.visibility(now);
+            let (width, height) = active.toast.size().unpack();
+            let target = stack_offset;
+            let offset = match active.offset {
+                Some(offset) => offset + (target - offset) * (1.0 - (-delta * 12.0).exp()),
+                None => target,
+            };
+            active.offset = Some(offset);
+            stack_offset += (height + MARGIN) * visibility;
+
+            // Slides in from the closest side of the window
+            let hidden_x = if left { -width } else { window_size.x() };
+            let shown_x = if left {
+                MARGIN
+            } else {
+                window_size.x() - width - MARGIN
+            };
+            let x = hidden_x + (shown_x - hidden_x) * visibility;
+            let y = if top {
+                offset
+            } else {
+                window_size.y() - offset - height
+            };
+            active.toast.set_position(Vector2D::new(x, y));
+            geometries.push(active.toast.build_geometry());
+        }
+        geometries
+    }
+}
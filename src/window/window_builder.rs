@@ -1,22 +1,46 @@
 use std::collections::btree_map::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::error::Error;
 use std::ops::Deref;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use log::{debug, info};
 #[cfg(not(target_family = "wasm"))]
 use rayon::prelude::*;
-use sdl2::keyboard::Keycode;
+use sdl2::keyboard::{Keycode, Mod};
 use sdl2::mouse::MouseButton;
 use sdl2::render::WindowCanvas;
 
-use crate::general::Geometry;
+use crate::general::{Geometry, Rect, Vector2D};
 use crate::texture::TextureManager;
-use crate::utils::Downcast;
+use crate::widgets::events::{DeferredEvents, HasEvents};
 use crate::widgets::primitives::Primitive;
 use crate::widgets::*;
-use crate::window::Root;
+use crate::window::{Anchored, MenuTracker, Placement, Root, ToastManager, WindowRegion};
+
+/// How long the pointer has to rest on a widget before its tooltip is shown.
+const TOOLTIP_DELAY: Duration = Duration::from_millis(600);
+/// Distance between the pointer and the tooltip.
+const TOOLTIP_OFFSET: f32 = 16.0;
+
+/// The widget under the pointer, rid is None for the top dialog, child is only set when a
+/// widget inside the container has a tooltip of its own, with how many containers down it is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HoverTarget {
+    rid: Option<isize>,
+    child: Option<(WidgetId, usize)>,
+}
+
+#[derive(Debug)]
+struct Hover {
+    target: HoverTarget,
+    x: i32,
+    y: i32,
+    since: Instant,
+    tooltip_shown: bool,
+}
 
 pub struct WindowBuilder {
     wid_and_rid: BTreeMap<WidgetId, isize>,
@@ -28,6 +52,21 @@ pub struct WindowBuilder {
     borrowed: HashMap<WidgetId, DowncastableBorrowedWidget>,
     focused_wid: Option<WidgetId>,
     wid_and_cwid: HashMap<WidgetId, WidgetId>, // wid, cwid
+    pressed_rid: Option<isize>,
+    dialogs: Vec<Dialog>, // the last one is on top and gets all the input
+    dialog_geometries: Vec<Geometry>,
+    hover: Option<Hover>,
+    overlay_geometries: Vec<Geometry>, // drawn above everything, even dialogs
+    toast_manager: Option<ToastManager>,
+    menus: MenuTracker,
+    status_bar: Option<StatusBar>,
+    chrome_geometries: Vec<Geometry>,   // menu bar and status bar
+    docked: Vec<(WindowRegion, isize)>, // region, rid, in the order they were added
+    center: Rect<f32>,
+    anchored: HashMap<isize, Anchored>, // rid, how it follows the center region
+    resizable: bool,
+    popup_open: bool, // some widget had its popup open in the last frame
+    announcer: Option<fn(&str)>,
 }
 
 impl WindowBuilder {
@@ -42,38 +81,113 @@ impl WindowBuilder {
             borrowed: Default::default(),
             focused_wid: None,
             wid_and_cwid: Default::default(),
+            pressed_rid: None,
+            dialogs: vec![],
+            dialog_geometries: vec![],
+            hover: None,
+            overlay_geometries: vec![],
+            toast_manager: None,
+            menus: MenuTracker::new(Vector2D::new(width as f32, height as f32)),
+            status_bar: None,
+            chrome_geometries: vec![],
+            docked: vec![],
+            center: Rect::new(0.0, 0.0, width as f32, height as f32),
+            anchored: Default::default(),
+            resizable: false,
+            popup_open: false,
+            announcer: None,
         })
     }
+    /// Adds the widget to the center region, its position is relative to it.
     pub fn add_widget<W: Widget>(&mut self, render_id: isize, widget: W) {
-        // Compound
-        let opt_custom_widget = widget.downcast_ref::<Compound>();
-        if let Some(custom_widget) = opt_custom_widget {
-            for child in custom_widget.children().values() {
-                self.wid_and_cwid.insert(child.wid(), custom_widget.wid());
+        self.add_widget_to_region(WindowRegion::Center, render_id, widget)
+    }
+    /// Docked widgets take their space from the center region, in the order they were added.
+    pub fn add_widget_to_region<W: Widget>(
+        &mut self,
+        region: WindowRegion,
+        render_id: isize,
+        mut widget: W,
+    ) {
+        if let Some(container) = widget.as_container() {
+            for child_wid in container.child_wids() {
+                self.wid_and_cwid.insert(child_wid, widget.wid());
+            }
+        }
+        match region {
+            WindowRegion::Center => {
+                let position =
+                    Vector2D::new(self.center.x() + widget.x(), self.center.y() + widget.y());
+                widget.set_position(position);
             }
+            _ => self.docked.push((region, render_id)),
         }
-        // End Compound
         self.wid_and_rid.insert(widget.wid(), render_id);
         self.widgets.insert(render_id, Box::new(widget));
+        self.layout_regions();
+    }
+    /// Adds the widget to the center region, it then follows the region when it changes size,
+    /// e.g. pinned to the bottom right corner or stretched from left to right.
+    pub fn add_anchored_widget<W: Widget>(
+        &mut self,
+        render_id: isize,
+        mut widget: W,
+        placement: Placement,
+    ) {
+        let region_size = (self.center.width(), self.center.height());
+        let (x, y) = widget.position().unpack();
+        let (width, height) = widget.size().unpack();
+        let anchored = Anchored::new(placement, (x, y, width, height), region_size);
+        self.anchored.insert(render_id, anchored);
+        self.add_widget(render_id, widget);
+    }
+    /// Lets the user resize the window, the layout follows. Set it before adding the window.
+    pub fn set_resizable(&mut self, resizable: bool) {
+        self.resizable = resizable;
+    }
+    pub fn is_resizable(&self) -> bool {
+        self.resizable
+    }
+    /// Called by the engine when the window was resized.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+        let window_size = Vector2D::new(width as f32, height as f32);
+        self.menus.set_window_size(window_size.clone());
+        for dialog in &mut self.dialogs {
+            dialog.center_in(window_size.clone());
+        }
+        self.layout_regions();
+    }
+    /// What is left for the center region after the docked widgets, menu bar and status bar.
+    pub fn center_rect(&self) -> Rect<f32> {
+        self.center
     }
     pub fn build_geometry(&mut self) -> Result<(), Box<dyn Error>> {
         // Check if new widgets are needed based on DSL
         // TODO, make in parallel
         self.return_borrowed_widgets();
-        for cwid in self.wid_and_cwid.values() {
+        // Each container once, it's in there for every one of its children
+        let cwids: BTreeSet<WidgetId> = self.wid_and_cwid.values().copied().collect();
+        for cwid in cwids {
             let crid = self
                 .wid_and_rid
-                .get(cwid)
+                .get(&cwid)
                 .ok_or("build_geometry cwid not in wid_and_rid")?;
             let widget = self
                 .widgets
                 .get_mut(crid)
                 .ok_or("build_geometry expected crid in widgets")?;
-            let cwidget = (**widget)
-                .downcast_mut::<Compound>()
-                .ok_or("Could not downcast Compound")?;
-            cwidget.return_borrowed_widgets();
+            let container = widget
+                .as_container_mut()
+                .ok_or("build_geometry expected a Container")?;
+            container.return_borrowed_widgets();
         }
+        for dialog in &mut self.dialogs {
+            dialog.return_borrowed_widgets();
+        }
+        // Docked widgets may have changed their size in an event
+        self.layout_regions();
 
         self.geometries.clear();
 
@@ -86,6 +200,21 @@ impl WindowBuilder {
         self.geometries = functional_iter
             .map(|(rid, widget)| (*rid, widget.build_geometry()))
             .collect();
+        for (rid, geometry) in &mut self.geometries {
+            if !self.docked.iter().any(|(_, docked_rid)| docked_rid == rid) {
+                geometry.clip(&self.center);
+            }
+        }
+        self.dialog_geometries = self
+            .dialogs
+            .iter_mut()
+            .map(|dialog| dialog.build_geometry())
+            .collect();
+        self.chrome_geometries = self.menus.build_menu_bar_geometry().into_iter().collect();
+        if let Some(status_bar) = &mut self.status_bar {
+            self.chrome_geometries.push(status_bar.build_geometry());
+        }
+        self.overlay_geometries = self.build_overlay();
 
         // Delete not needed widgets
         Ok(())
@@ -95,20 +224,120 @@ impl WindowBuilder {
         for geometry in &mut self.geometries.values_mut() {
             geometry.render(canvas, &tex_creator, &mut self.tex_man)?;
         }
+        for geometry in &mut self.chrome_geometries {
+            geometry.render(canvas, &tex_creator, &mut self.tex_man)?;
+        }
+        for geometry in &mut self.dialog_geometries {
+            geometry.render(canvas, &tex_creator, &mut self.tex_man)?;
+        }
+        for geometry in &mut self.overlay_geometries {
+            geometry.render(canvas, &tex_creator, &mut self.tex_man)?;
+        }
 
         self.tex_man.garbage_collect(tex_creator);
         Ok(())
     }
-    pub fn event_key_down(&mut self, key: Keycode) {
+    /// Shows a modal dialog centered above the content, until the user closes it.
+    pub fn show_dialog(&mut self, mut dialog: Dialog) {
+        dialog.center_in(Vector2D::new(self.width as f32, self.height as f32));
+        self.pressed_rid = None;
+        self.hover = None;
+        self.menus.close();
+        self.dialogs.push(dialog);
+    }
+    /// Docked at the top of the window, above every widget.
+    pub fn set_menu_bar(&mut self, menu_bar: MenuBar) {
+        self.menus.set_menu_bar(menu_bar);
+        self.layout_regions();
+    }
+    pub fn menu_bar_mut(&mut self) -> Option<&mut MenuBar> {
+        self.menus.menu_bar_mut()
+    }
+    /// Docked at the bottom of the window, below every bottom region widget.
+    pub fn set_status_bar(&mut self, status_bar: StatusBar) {
+        self.status_bar = Some(status_bar);
+        self.layout_regions();
+    }
+    pub fn status_bar_mut(&mut self) -> Option<&mut StatusBar> {
+        self.status_bar.as_mut()
+    }
+    /// Does nothing if there is no status bar.
+    pub fn set_status_text(&mut self, text: &str) {
+        match &mut self.status_bar {
+            Some(status_bar) => status_bar.set_text(text),
+            None => debug!("set_status_text without a status bar: {}", text),
+        }
+    }
+    /// Shown when the widget is right clicked, containers' context menus are used for their
+    /// children that don't have one.
+    pub fn set_context_menu<WENUM: WidgetEnum>(&mut self, wid: WENUM, context_menu: ContextMenu) {
+        self.menus
+            .set_context_menu(wid.to_wid(), Some(context_menu));
+    }
+    pub fn remove_context_menu<WENUM: WidgetEnum>(&mut self, wid: WENUM) {
+        self.menus.set_context_menu(wid.to_wid(), None);
+    }
+    pub fn context_menu_mut<WENUM: WidgetEnum>(&mut self, wid: WENUM) -> Option<&mut ContextMenu> {
+        self.menus.context_menu_mut(wid.to_wid())
+    }
+    /// Needed before notify() can be used.
+    pub fn set_toast_manager(&mut self, toast_manager: ToastManager) {
+        self.toast_manager = Some(toast_manager);
+    }
+    pub fn toast_manager_mut(&mut self) -> Option<&mut ToastManager> {
+        self.toast_manager.as_mut()
+    }
+    /// Shows a transient message in a corner, clicking it dismisses it earlier.
+    pub fn notify(
+        &mut self,
+        text: &str,
+        level: ToastLevel,
+        duration: Duration,
+    ) -> Result<(), Box<dyn Error>> {
+        self.toast_manager
+            .as_mut()
+            .ok_or("window_builder:WindowBuilder:notify set_toast_manager() wasn't called")?
+            .notify(text, level, duration)
+    }
+    /// If a dialog or a menu is open, nothing else gets input.
+    pub fn has_modal(&self) -> bool {
+        !self.dialogs.is_empty() || self.menus.is_open() || self.popup_open
+    }
+    /// Gets what widgets announce, to pass it to a screen reader. Without it they are only logged.
+    pub fn set_announcer(&mut self, announcer: fn(&str)) {
+        self.announcer = Some(announcer);
+    }
+    pub fn announce(&mut self, text: &str) {
+        match self.announcer {
+            Some(announcer) => announcer(text),
+            None => info!("announce: {}", text),
+        }
+    }
+    pub fn event_key_down(&mut self, key: Keycode, keymod: Mod) {
         debug!("event_key_down({:?})", key);
+        self.hover = None;
+        if let Some(dialog) = self.dialogs.last_mut() {
+            let deferred_events = dialog.handle_key_down(key, keymod);
+            let event_callback = dialog.event_key_down();
+            (event_callback.deref())(self, key);
+            self.call_deferred_events(deferred_events);
+            self.remove_closed_dialogs();
+            return;
+        }
+        if let Some(deferred_events) = self.menus.handle_key_down(key, keymod) {
+            self.call_deferred_events(deferred_events);
+            return;
+        }
         if let Some(wid) = self.focused_wid {
             if let Some(rid) = self.wid_and_rid.get(&wid) {
                 let widget = self
                     .widgets
-                    .get(rid)
+                    .get_mut(rid)
                     .expect("window_builder:WindowBuilder:event_key_down");
+                let deferred_events = widget.handle_key_down(key, keymod);
                 let event_callback = widget.event_key_down();
                 (event_callback.deref())(self, key);
+                self.call_deferred_events(deferred_events);
             } else {
                 self.focused_wid = None;
             }
@@ -116,45 +345,409 @@ impl WindowBuilder {
             debug!("event_key_down None")
         }
     }
-    pub fn event_mouse_button_down(&mut self, _mouse_btn: MouseButton, x: i32, y: i32) {
+    pub fn event_text_input(&mut self, text: &str) {
+        debug!("event_text_input({})", text);
+        if let Some(dialog) = self.dialogs.last_mut() {
+            let deferred_events = dialog.handle_text_input(text);
+            self.call_deferred_events(deferred_events);
+            return;
+        }
+        if self.menus.is_open() {
+            return;
+        }
+        let Some(rid) = self.focused_wid.and_then(|wid| self.wid_and_rid.get(&wid)) else {
+            return;
+        };
+        if let Some(widget) = self.widgets.get_mut(rid) {
+            let deferred_events = widget.handle_text_input(text);
+            self.call_deferred_events(deferred_events);
+        }
+    }
+    pub fn event_mouse_button_down(&mut self, mouse_btn: MouseButton, x: i32, y: i32) {
         debug!("event_mouse_button_down Clicked");
+        self.hover = None;
+        // Toasts are above everything, even dialogs
+        if let Some(toast_manager) = &mut self.toast_manager {
+            if toast_manager.dismiss_at(x, y) {
+                return;
+            }
+        }
+        if let Some(dialog) = self.dialogs.last_mut() {
+            // Clicks outside a modal dialog are swallowed
+            if !dialog.will_accept_mouse_click_event(x, y) {
+                return;
+            }
+            let deferred_events = dialog.handle_mouse_button_down(x, y);
+            let event_callback = dialog
+                .innermost_child_at(x, y)
+                .map(|child| child.event_mouse_button_down());
+            if let Some(event_callback) = event_callback {
+                (event_callback.deref())(self, x, y);
+            }
+            self.call_deferred_events(deferred_events);
+            self.remove_closed_dialogs();
+            return;
+        }
+        if let Some(deferred_events) = self.menus.handle_mouse_button_down(mouse_btn, x, y) {
+            self.call_deferred_events(deferred_events);
+            return;
+        }
+        let on_status_bar = self
+            .status_bar
+            .as_mut()
+            .is_some_and(|status_bar| status_bar.will_accept_mouse_click_event(x, y));
+        if on_status_bar {
+            return;
+        }
+        if self.handle_popup_click(x, y) {
+            return;
+        }
+        if mouse_btn == MouseButton::Right && self.open_context_menu(x, y) {
+            return;
+        }
         let it = self.widgets.iter_mut().rev();
         // TODO: Cannot use find(), why?
         let mut found = None;
         for (rid, widget) in it {
             if widget.will_accept_mouse_click_event(x, y) {
-                found = Some((rid, widget));
+                found = Some(*rid);
                 debug!("Found rid:{}", rid);
                 break;
             }
         }
 
-        if let Some((rid, widget)) = found {
-            self.focused_wid = self
-                .wid_and_rid
+        let focused_wid = found.and_then(|rid| {
+            self.wid_and_rid
                 .iter()
-                .find(|(_, internal_rid)| *internal_rid == rid)
-                .map(|(wid, _)| *wid);
+                .find(|(_, internal_rid)| **internal_rid == rid)
+                .map(|(wid, _)| *wid)
+        });
+        self.set_focused_wid(focused_wid);
+        if let Some(widget) = found.and_then(|rid| self.widgets.get_mut(&rid)) {
+            self.pressed_rid = found;
             debug!(
                 "event_mouse_button_down Focused_wid: {:?}",
                 self.focused_wid
             );
-            let option_compound = (widget.as_mut() as &mut dyn Widget).downcast_mut::<Compound>();
-            if let Some(compound) = option_compound {
-                let wcid = compound.widget_that_accepts_click(x, y);
-                for (tcwid, child) in compound.children() {
-                    if *tcwid == wcid {
-                        let event_callback = child.event_mouse_button_down();
-                        info!("Clicked component wid:{}", wcid);
-                        (event_callback.deref())(self, x, y);
-                        break;
-                    }
-                }
-            } else {
-                let event_callback = widget.event_mouse_button_down();
+            let deferred_events = widget.handle_mouse_button_down(x, y);
+            let event_callback = match widget.as_container_mut() {
+                Some(container) => container.innermost_child_at(x, y).map(|child| {
+                    info!("Clicked component wid:{}", child.wid());
+                    child.event_mouse_button_down()
+                }),
+                None => Some(widget.event_mouse_button_down()),
+            };
+            if let Some(event_callback) = event_callback {
                 (event_callback.deref())(self, x, y);
             }
+            self.call_deferred_events(deferred_events);
+        }
+    }
+    pub fn event_mouse_motion(&mut self, x: i32, y: i32) {
+        if self.menus.handle_mouse_motion(x, y) {
+            self.hover = None;
+            return;
+        }
+        self.track_hover(x, y);
+        if let Some(dialog) = self.dialogs.last_mut() {
+            let deferred_events = dialog.handle_mouse_drag(x, y);
+            self.call_deferred_events(deferred_events);
+            return;
+        }
+        let Some(rid) = self.pressed_rid else {
+            return;
+        };
+        if let Some(widget) = self.widgets.get_mut(&rid) {
+            let deferred_events = widget.handle_mouse_drag(x, y);
+            self.call_deferred_events(deferred_events);
+        }
+    }
+    pub fn event_mouse_button_up(&mut self, _mouse_btn: MouseButton, x: i32, y: i32) {
+        debug!("event_mouse_button_up");
+        if let Some(dialog) = self.dialogs.last_mut() {
+            let deferred_events = dialog.handle_mouse_button_up(x, y);
+            self.call_deferred_events(deferred_events);
+            return;
+        }
+        let Some(rid) = self.pressed_rid.take() else {
+            return;
+        };
+        if let Some(widget) = self.widgets.get_mut(&rid) {
+            let deferred_events = widget.handle_mouse_button_up(x, y);
+            self.call_deferred_events(deferred_events);
+        }
+    }
+    pub fn event_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32) {
+        debug!("event_mouse_wheel {},{}", dx, dy);
+        self.hover = None;
+        if self.menus.is_open() {
+            return;
+        }
+        if let Some(dialog) = self.dialogs.last_mut() {
+            if dialog.will_accept_mouse_click_event(x, y) {
+                let deferred_events = dialog.handle_mouse_wheel(x, y, dx, dy);
+                self.call_deferred_events(deferred_events);
+            }
+            return;
+        }
+        let found = self
+            .widgets
+            .values_mut()
+            .rev()
+            .find_map(|widget| widget.will_accept_mouse_click_event(x, y).then_some(widget));
+        if let Some(widget) = found {
+            let deferred_events = widget.handle_mouse_wheel(x, y, dx, dy);
+            self.call_deferred_events(deferred_events);
+        }
+    }
+    /// Places the docked widgets around the center region, and moves the center widgets along
+    /// when the center region moves.
+    fn layout_regions(&mut self) {
+        let (width, height) = (self.width as f32, self.height as f32);
+        let mut top = self.menus.menu_bar_height();
+        let mut bottom = height;
+        if let Some(status_bar) = &mut self.status_bar {
+            status_bar.set_width(width);
+            bottom -= status_bar.height();
+            Self::place(status_bar, 0.0, bottom);
+        }
+        // Top and bottom first, so left and right only get what is between them
+        for (region, rid) in &self.docked {
+            let Some(widget) = self.widgets.get_mut(rid) else {
+                continue;
+            };
+            match region {
+                WindowRegion::Top => {
+                    Self::place(widget.as_mut(), 0.0, top);
+                    top += widget.height();
+                }
+                WindowRegion::Bottom => {
+                    bottom -= widget.height();
+                    Self::place(widget.as_mut(), 0.0, bottom);
+                }
+                _ => {}
+            }
+        }
+        let (mut left, mut right) = (0.0, width);
+        for (region, rid) in &self.docked {
+            let Some(widget) = self.widgets.get_mut(rid) else {
+                continue;
+            };
+            match region {
+                WindowRegion::Left => {
+                    Self::place(widget.as_mut(), left, top);
+                    left += widget.width();
+                }
+                WindowRegion::Right => {
+                    right -= widget.width();
+                    Self::place(widget.as_mut(), right, top);
+                }
+                _ => {}
+            }
+        }
+        let center = Rect::new(left, top, (right - left).max(0.0), (bottom - top).max(0.0));
+        let (dx, dy) = (center.x() - self.center.x(), center.y() - self.center.y());
+        if dx != 0.0 || dy != 0.0 {
+            for (rid, widget) in &mut self.widgets {
+                if !self.docked.iter().any(|(_, docked_rid)| docked_rid == rid) {
+                    let (x, y) = (widget.x() + dx, widget.y() + dy);
+                    Self::place(widget.as_mut(), x, y);
+                }
+            }
+        }
+        self.center = center;
+        self.layout_anchored();
+    }
+    fn layout_anchored(&mut self) {
+        let region_size = (self.center.width(), self.center.height());
+        for (rid, anchored) in &self.anchored {
+            let Some(widget) = self.widgets.get_mut(rid) else {
+                continue;
+            };
+            let (x, y, width, height) = anchored.rect(widget.size().unpack(), region_size);
+            if widget.size().unpack() != (width, height) {
+                widget.set_layout_size(Some(Vector2D::new(width, height)));
+            }
+            Self::place(widget.as_mut(), self.center.x() + x, self.center.y() + y);
+        }
+    }
+    /// Only moves it if needed, so it isn't translated again every frame.
+    fn place<P: Primitive + ?Sized>(widget: &mut P, x: f32, y: f32) {
+        if widget.x() != x || widget.y() != y {
+            widget.set_position(Vector2D::new(x, y));
+        }
+    }
+    /// The innermost widget under the pointer goes first, then its container.
+    fn open_context_menu(&mut self, x: i32, y: i32) -> bool {
+        let Some((_, widget)) = self.top_widget_at(x, y) else {
+            return false;
+        };
+        let mut wids = widget
+            .as_container_mut()
+            .map_or(vec![], |container| container.wids_at(x, y));
+        wids.reverse();
+        wids.push(widget.wid());
+        self.menus.open_context_menu(&wids, x, y)
+    }
+    /// Clicks on an open popup go to its widget, clicks anywhere else close it.
+    /// Returns true if the click was for the popup.
+    fn handle_popup_click(&mut self, x: i32, y: i32) -> bool {
+        let owner = self.widgets.iter_mut().find_map(|(rid, widget)| {
+            let on_popup = widget.popup_mut()?.will_accept_mouse_click_event(x, y);
+            Some((*rid, on_popup))
+        });
+        let Some((rid, on_popup)) = owner else {
+            return false;
+        };
+        let widget = self
+            .widgets
+            .get_mut(&rid)
+            .expect("window_builder:WindowBuilder:handle_popup_click");
+        if on_popup {
+            let deferred_events = widget.handle_mouse_button_down(x, y);
+            self.call_deferred_events(deferred_events);
+            return true;
+        }
+        // Clicking the widget itself is left to it, it usually toggles the popup
+        if !widget.will_accept_mouse_click_event(x, y) {
+            let deferred_events = widget.close_popup();
+            self.call_deferred_events(deferred_events);
+        }
+        false
+    }
+    /// Tells both widgets when the focus moves.
+    /// Containers aren't told when they get it, they focus the clicked child themselves when
+    /// the click is routed to them.
+    fn set_focused_wid(&mut self, focused_wid: Option<WidgetId>) {
+        if self.focused_wid == focused_wid {
+            return;
+        }
+        let mut deferred_events = vec![];
+        for (wid, focused) in [(self.focused_wid, false), (focused_wid, true)] {
+            let rid = wid.and_then(|wid| self.wid_and_rid.get(&wid));
+            let Some(widget) = rid.and_then(|rid| self.widgets.get_mut(rid)) else {
+                continue;
+            };
+            if !focused || widget.as_container().is_none() {
+                deferred_events.extend(widget.handle_focus_changed(focused));
+            }
+        }
+        self.focused_wid = focused_wid;
+        self.call_deferred_events(deferred_events);
+    }
+    fn call_deferred_events(&mut self, deferred_events: DeferredEvents) {
+        for deferred_event in deferred_events {
+            deferred_event(self);
+        }
+    }
+    /// Restarts the tooltip delay every time the pointer moves to another widget.
+    fn track_hover(&mut self, x: i32, y: i32) {
+        let pressed = self.pressed_rid.is_some();
+        let target = if pressed {
+            None
+        } else {
+            self.hover_target(x, y)
+        };
+        match (&mut self.hover, target) {
+            (Some(hover), Some(target)) if hover.target == target => {
+                if !hover.tooltip_shown {
+                    hover.x = x;
+                    hover.y = y;
+                }
+            }
+            (_, target) => {
+                self.hover = target.map(|target| Hover {
+                    target,
+                    x,
+                    y,
+                    since: Instant::now(),
+                    tooltip_shown: false,
+                })
+            }
+        }
+    }
+    /// Only the top dialog can be hovered while there is one.
+    fn top_widget_at(&mut self, x: i32, y: i32) -> Option<(Option<isize>, &mut dyn Widget)> {
+        if let Some(dialog) = self.dialogs.last_mut() {
+            let accepts = dialog.will_accept_mouse_click_event(x, y);
+            return accepts.then_some((None, dialog as &mut dyn Widget));
+        }
+        self.widgets.iter_mut().rev().find_map(|(rid, widget)| {
+            let accepts = widget.will_accept_mouse_click_event(x, y);
+            accepts.then_some((Some(*rid), widget.as_mut()))
+        })
+    }
+    fn hover_target(&mut self, x: i32, y: i32) -> Option<HoverTarget> {
+        let (rid, widget) = self.top_widget_at(x, y)?;
+        // The innermost widget with a tooltip, its containers can have one too
+        let child = widget.as_container_mut().and_then(|container| {
+            let wids = container.wids_at(x, y);
+            (0..wids.len()).rev().find_map(|depth| {
+                let child = container.child_at_depth(x, y, depth)?;
+                child.tooltip_mut().is_some().then(|| (wids[depth], depth))
+            })
+        });
+        Some(HoverTarget { rid, child })
+    }
+    fn tooltip_of(&mut self, target: HoverTarget, x: i32, y: i32) -> Option<&mut OwnedDynWidget> {
+        let widget: &mut dyn Widget = match target.rid {
+            None => self.dialogs.last_mut()?,
+            Some(rid) => self.widgets.get_mut(&rid)?.as_mut(),
+        };
+        match target.child {
+            Some((_, depth)) => widget
+                .as_container_mut()?
+                .child_at_depth(x, y, depth)?
+                .tooltip_mut(),
+            None => widget.tooltip_mut(),
+        }
+    }
+    /// Whatever has to be drawn above everything else.
+    fn build_overlay(&mut self) -> Vec<Geometry> {
+        let mut geometries: Vec<Geometry> = self
+            .widgets
+            .values_mut()
+            .filter_map(|widget| widget.popup_mut().map(|popup| popup.build_geometry()))
+            .collect();
+        self.popup_open = !geometries.is_empty();
+        geometries.extend(self.menus.build_popup_geometries());
+        if let Some(toast_manager) = &mut self.toast_manager {
+            let window_size = Vector2D::new(self.width as f32, self.height as f32);
+            geometries.extend(toast_manager.build_geometries(&window_size));
+        }
+        if let Some(geometry) = self.build_tooltip() {
+            geometries.push(geometry);
+        }
+        geometries
+    }
+    fn build_tooltip(&mut self) -> Option<Geometry> {
+        let hover = self.hover.as_ref()?;
+        if !hover.tooltip_shown && hover.since.elapsed() < TOOLTIP_DELAY {
+            return None;
+        }
+        let (target, x, y, shown) = (hover.target, hover.x, hover.y, hover.tooltip_shown);
+        let (width, height) = (self.width as f32, self.height as f32);
+        let tooltip = self.tooltip_of(target, x, y)?;
+        if !shown {
+            // Below and to the right of the pointer, unless it would leave the window
+            let (tooltip_width, tooltip_height) = tooltip.size().unpack();
+            let mut tooltip_x = x as f32 + TOOLTIP_OFFSET;
+            let mut tooltip_y = y as f32 + TOOLTIP_OFFSET;
+            if tooltip_x + tooltip_width > width {
+                tooltip_x = (width - tooltip_width).max(0.0);
+            }
+            if tooltip_y + tooltip_height > height {
+                tooltip_y = (y as f32 - TOOLTIP_OFFSET - tooltip_height).max(0.0);
+            }
+            tooltip.set_position(Vector2D::new(tooltip_x, tooltip_y));
         }
+        let geometry = tooltip.build_geometry();
+        self.hover.as_mut()?.tooltip_shown = true;
+        Some(geometry)
+    }
+    /// Closed dialogs are kept until their closed event was called, so it can still look them up.
+    fn remove_closed_dialogs(&mut self) {
+        self.dialogs.retain(|dialog| !dialog.is_closed());
     }
     pub fn width(&self) -> u32 {
         self.width
@@ -162,7 +755,7 @@ impl WindowBuilder {
     pub fn height(&self) -> u32 {
         self.height
     }
-    fn wid_down_borrow(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+    fn wid_down_borrow(&mut self, wid: WidgetId) -> Result<DowncastableBorrowedWidget, String> {
         info!("down_borrow wid={}", wid);
 
         if let Some(borrowed) = self.borrowed.get(&wid) {
@@ -170,34 +763,79 @@ impl WindowBuilder {
                 "down_borrow strong_count={}",
                 borrowed.get_borrowed_strong_count()
             );
-            return Some(borrowed.clone());
+            return Ok(borrowed.clone());
         }
 
         if let Some(rid) = self.wid_and_rid.get(&wid) {
-            let opt_widget = self.widgets.remove(rid);
-            let widget = opt_widget?;
+            let widget = self
+                .widgets
+                .remove(rid)
+                .ok_or_else(|| format!("Not found: wid={} isn't in the window", wid))?;
             let _class = widget.class();
             let type_id = widget.type_id();
             info!("wid_down_borrow type_id: {:?}", type_id);
             let dyn_widget = Arc::new(Mutex::new(widget));
             let downcastable = DowncastableBorrowedWidget::new(type_id, dyn_widget, _class);
             self.borrowed.insert(wid, downcastable.clone());
-            return Some(downcastable);
+            return Ok(downcastable);
         }
 
         info!("down_borrow widget.keys {:?}", self.widgets.keys());
         info!("down_borrow wid_container {:?}", self.wid_and_cwid);
+        if !self.wid_and_cwid.contains_key(&wid) {
+            // Added to a nested container after the top one was added to the window
+            let cwid = self.widgets.values().find_map(|widget| {
+                let container = widget.as_container()?;
+                container.child_wids().contains(&wid).then(|| widget.wid())
+            });
+            if let Some(cwid) = cwid {
+                self.wid_and_cwid.insert(wid, cwid);
+            } else if let Some(cwid) = self.borrowed_container_with(wid) {
+                return Err(Self::in_borrowed_container(wid, cwid));
+            }
+        }
         if let Some(cwid) = self.wid_and_cwid.get(&wid) {
-            let crid = self.wid_and_rid.get(cwid)?;
-            let cwidget = self.widgets.get_mut(crid)?;
+            if self.borrowed.contains_key(cwid) {
+                return Err(Self::in_borrowed_container(wid, *cwid));
+            }
+            let crid = self
+                .wid_and_rid
+                .get(cwid)
+                .expect("window_builder:WindowBuilder:wid_down_borrow container wid to rid");
+            let cwidget = self
+                .widgets
+                .get_mut(crid)
+                .expect("window_builder:WindowBuilder:wid_down_borrow container");
             let class = cwidget.class();
-            let container = (**cwidget)
-                .downcast_mut::<Compound>()
-                .unwrap_or_else(|| panic!("Wanted Compound found {}", class));
-            return container.get_down_widget_by_id(wid);
+            let container = cwidget
+                .as_container_mut()
+                .unwrap_or_else(|| panic!("Wanted a Container found {}", class));
+            return container
+                .borrow_child(wid)
+                .ok_or_else(|| format!("Not found: wid={} in container wid={}", wid, cwid));
         }
 
-        None
+        self.dialogs
+            .iter_mut()
+            .rev()
+            .find_map(|dialog| dialog.borrow_child(wid))
+            .ok_or_else(|| format!("Not found: wid={} isn't in the window", wid))
+    }
+    /// The borrowed top container the widget was added to, if it can be looked into. One that's
+    /// locked by the caller can't, the widget is reported as missing then.
+    fn borrowed_container_with(&self, wid: WidgetId) -> Option<WidgetId> {
+        self.borrowed.iter().find_map(|(cwid, borrowed)| {
+            let dyn_widget = borrowed.bor_dyn_widget();
+            let widget = dyn_widget.try_lock().ok()?;
+            let container = widget.as_container()?;
+            container.child_wids().contains(&wid).then_some(*cwid)
+        })
+    }
+    fn in_borrowed_container(wid: WidgetId, cwid: WidgetId) -> String {
+        format!(
+            "wid={} is inside container wid={}, which is borrowed. Return it before reaching its widgets",
+            wid, cwid
+        )
     }
     fn return_borrowed_widgets(&mut self) {
         if !self.borrowed.is_empty() {
@@ -233,11 +871,38 @@ impl WindowBuilder {
 }
 
 impl Root for WindowBuilder {
-    fn get_down_widget_by_id(&mut self, wid: WidgetId) -> Option<DowncastableBorrowedWidget> {
+    fn get_down_widget_by_id(
+        &mut self,
+        wid: WidgetId,
+    ) -> Result<DowncastableBorrowedWidget, String> {
         self.wid_down_borrow(wid)
     }
 
     fn children(&self) -> &BTreeMap<WidgetId, OwnedDynWidget> {
         todo!()
     }
+
+    fn show_dialog(&mut self, dialog: Dialog) -> Result<(), Box<dyn Error>> {
+        WindowBuilder::show_dialog(self, dialog);
+        Ok(())
+    }
+
+    fn notify(
+        &mut self,
+        text: &str,
+        level: ToastLevel,
+        duration: Duration,
+    ) -> Result<(), Box<dyn Error>> {
+        WindowBuilder::notify(self, text, level, duration)
+    }
+
+    fn set_status_text(&mut self, text: &str) -> Result<(), Box<dyn Error>> {
+        WindowBuilder::set_status_text(self, text);
+        Ok(())
+    }
+
+    fn announce(&mut self, text: &str) -> Result<(), Box<dyn Error>> {
+        WindowBuilder::announce(self, text);
+        Ok(())
+    }
 }
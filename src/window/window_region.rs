@@ -0,0 +1,12 @@
+/// Where a widget goes in the window. Docked regions are laid out in the order their widgets
+/// were added, top and bottom span the whole width and left and right what is left between them.
+/// Center gets the remaining area, its widgets' positions are relative to it.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum WindowRegion {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    #[default]
+    Center,
+}
@@ -1,4 +1,4 @@
-use sdl2::keyboard::Keycode;
+use sdl2::keyboard::{Keycode, Mod};
 use sdl2::mouse::MouseButton;
 use std::error::Error;
 
@@ -6,8 +6,13 @@ use crate::window::WindowBuilder;
 
 pub trait Window {
     fn get_builder(&self) -> &WindowBuilder;
-    fn event_key_down(&mut self, key: Keycode);
+    fn event_key_down(&mut self, key: Keycode, keymod: Mod);
+    fn event_text_input(&mut self, text: &str);
     fn event_mouse_button_down(&mut self, mouse_btn: MouseButton, x: i32, y: i32);
+    fn event_mouse_button_up(&mut self, mouse_btn: MouseButton, x: i32, y: i32);
+    fn event_mouse_motion(&mut self, x: i32, y: i32);
+    fn event_mouse_wheel(&mut self, x: i32, y: i32, dx: i32, dy: i32);
+    fn event_resized(&mut self, width: u32, height: u32);
     fn build_geometry(&mut self) -> Result<(), Box<dyn Error>>;
     fn render(&mut self) -> Result<(), Box<dyn Error>>;
     fn clear_canvas(&mut self);